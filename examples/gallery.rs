@@ -0,0 +1,341 @@
+//! Every style preset in every state, for each built-in theme.
+//!
+//! Each widget is drawn through a wrapper that answers all of its style sheet's callbacks with
+//! one state, so hovered, pressed and dragging appearances stay visible side by side whatever the
+//! mouse does.
+
+use iced::widget::scrollable::{Direction, Properties, Scrollbar};
+use iced::widget::{button, column, container, scrollable, text, text_input, Column, Row};
+use iced::{Color, Element, Length, Sandbox, Settings, Theme};
+use iced_custom_theme_helpers::style_set::StyleSet;
+use iced_custom_theme_helpers::styles::scrollable::CustomScrollableStyle;
+use iced_custom_theme_helpers::styles::text_input::CustomTextInputStyle;
+
+pub fn main() -> iced::Result {
+    Gallery::run(Settings::default())
+}
+
+fn themes() -> [(&'static str, Theme); 2] {
+    [("Light", Theme::Light), ("Dark", Theme::Dark)]
+}
+
+const LABEL_WIDTH: f32 = 120.0;
+
+struct Gallery {
+    theme: usize,
+    value: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ThemeSelected(usize),
+    InputChanged(String),
+    Pressed,
+}
+
+impl Sandbox for Gallery {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            theme: 0,
+            value: String::from("Value"),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Style gallery")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ThemeSelected(theme) => self.theme = theme,
+            Message::InputChanged(value) => self.value = value,
+            Message::Pressed => {}
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let switcher = Row::with_children(
+            themes()
+                .into_iter()
+                .enumerate()
+                .map(|(index, (name, _))| {
+                    let style = if index == self.theme {
+                        iced::theme::Button::Primary
+                    } else {
+                        iced::theme::Button::Secondary
+                    };
+                    button(text(name))
+                        .style(style)
+                        .on_press(Message::ThemeSelected(index))
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(8);
+
+        let styles = StyleSet::from_theme(&self.theme());
+        let content = column![
+            switcher,
+            section("Buttons", buttons(&styles)),
+            section("Containers", containers(&styles)),
+            section("Text inputs", text_inputs(&styles.text_input, &self.value)),
+            section("Scrollables", scrollables(&styles.scrollable)),
+        ]
+        .spacing(32)
+        .padding(24);
+
+        scrollable(content).into()
+    }
+
+    fn theme(&self) -> Theme {
+        themes()[self.theme].1.clone()
+    }
+}
+
+fn section<'a>(title: &str, content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    column![text(title).size(24), content.into()]
+        .spacing(12)
+        .into()
+}
+
+fn label<'a>(name: &str) -> Element<'a, Message> {
+    text(name).width(Length::Fixed(LABEL_WIDTH)).into()
+}
+
+fn buttons(styles: &StyleSet) -> Element<'static, Message> {
+    Column::with_children(
+        styles
+            .buttons()
+            .into_iter()
+            .map(|(name, style)| {
+                let states = [
+                    ("Active", style.active),
+                    ("Hovered", style.hovered),
+                    ("Pressed", style.pressed),
+                    ("Disabled", style.disabled),
+                ];
+                let buttons = states.into_iter().map(|(state, appearance)| {
+                    let button = button(text(state)).width(Length::Fixed(LABEL_WIDTH)).style(
+                        iced::theme::Button::Custom(Box::new(ForcedButton(appearance))),
+                    );
+                    // Without a message iced asks for the disabled appearance, which is forced
+                    // anyway, but the others should still react to clicks.
+                    if state == "Disabled" {
+                        button.into()
+                    } else {
+                        button.on_press(Message::Pressed).into()
+                    }
+                });
+                Row::with_children(std::iter::once(label(name)).chain(buttons).collect())
+                    .spacing(8)
+                    .into()
+            })
+            .collect(),
+    )
+    .spacing(8)
+    .into()
+}
+
+fn containers(styles: &StyleSet) -> Element<'static, Message> {
+    Row::with_children(
+        styles
+            .containers()
+            .into_iter()
+            .map(|(name, style)| {
+                container(text(name))
+                    .width(Length::Fixed(LABEL_WIDTH * 2.0))
+                    .padding(16)
+                    .style(style.as_custom())
+                    .into()
+            })
+            .collect(),
+    )
+    .spacing(8)
+    .into()
+}
+
+fn text_inputs<'a>(style: &CustomTextInputStyle, value: &'a str) -> Element<'a, Message> {
+    let states = [
+        ("Active", style.active),
+        ("Hovered", style.hovered),
+        ("Focused", style.focused),
+        ("Disabled", style.disabled),
+    ];
+    // One row shows the placeholder and the other the value the user can edit.
+    let inputs = |value: &'a str| {
+        states.into_iter().map(move |(state, appearance)| {
+            let input = text_input(state, value)
+                .width(Length::Fixed(LABEL_WIDTH))
+                .style(iced::theme::TextInput::Custom(Box::new(ForcedTextInput {
+                    style: *style,
+                    appearance,
+                })));
+            if state == "Disabled" {
+                input.into()
+            } else {
+                input.on_input(Message::InputChanged).into()
+            }
+        })
+    };
+    column![
+        Row::with_children(
+            std::iter::once(label("Placeholder"))
+                .chain(inputs(""))
+                .collect()
+        )
+        .spacing(8),
+        Row::with_children(
+            std::iter::once(label("Value"))
+                .chain(inputs(value))
+                .collect()
+        )
+        .spacing(8),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn scrollables(style: &CustomScrollableStyle) -> Element<'static, Message> {
+    let states = [
+        ("Active", style.active, style.active_horizontal),
+        ("Hovered", style.hovered, style.hovered_horizontal),
+        (
+            "Over scrollbar",
+            style.hovered_over_scrollbar,
+            style.hovered_horizontal_over_scrollbar,
+        ),
+        ("Dragging", style.dragging, style.dragging_horizontal),
+    ];
+    Row::with_children(
+        states
+            .into_iter()
+            .map(|(state, vertical, horizontal)| {
+                let lines = Column::with_children(
+                    (1..=20)
+                        .map(|line| {
+                            text(format!("Line {line} is long enough to scroll sideways")).into()
+                        })
+                        .collect(),
+                );
+                let scrollable = scrollable(lines)
+                    .width(Length::Fixed(LABEL_WIDTH + 40.0))
+                    .height(Length::Fixed(120.0))
+                    .direction(Direction::Both {
+                        vertical: Properties::default(),
+                        horizontal: Properties::default(),
+                    })
+                    .style(iced::theme::Scrollable::Custom(Box::new(
+                        ForcedScrollable {
+                            vertical,
+                            horizontal,
+                        },
+                    )));
+                column![text(state), scrollable].spacing(4).into()
+            })
+            .collect(),
+    )
+    .spacing(8)
+    .into()
+}
+
+struct ForcedButton(button::Appearance);
+
+impl button::StyleSheet for ForcedButton {
+    type Style = Theme;
+
+    fn active(&self, _style: &Self::Style) -> button::Appearance {
+        self.0
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> button::Appearance {
+        self.0
+    }
+
+    fn pressed(&self, _style: &Self::Style) -> button::Appearance {
+        self.0
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> button::Appearance {
+        self.0
+    }
+}
+
+struct ForcedTextInput {
+    style: CustomTextInputStyle,
+    appearance: text_input::Appearance,
+}
+
+impl text_input::StyleSheet for ForcedTextInput {
+    type Style = Theme;
+
+    fn active(&self, _style: &Self::Style) -> text_input::Appearance {
+        self.appearance
+    }
+
+    fn focused(&self, _style: &Self::Style) -> text_input::Appearance {
+        self.appearance
+    }
+
+    fn placeholder_color(&self, _style: &Self::Style) -> Color {
+        self.style.placeholder_color
+    }
+
+    fn value_color(&self, _style: &Self::Style) -> Color {
+        self.style.value_color
+    }
+
+    fn disabled_color(&self, _style: &Self::Style) -> Color {
+        self.style.disabled_color
+    }
+
+    fn selection_color(&self, _style: &Self::Style) -> Color {
+        self.style.selection_color
+    }
+
+    fn hovered(&self, _style: &Self::Style) -> text_input::Appearance {
+        self.appearance
+    }
+
+    fn disabled(&self, _style: &Self::Style) -> text_input::Appearance {
+        self.appearance
+    }
+}
+
+struct ForcedScrollable {
+    vertical: Scrollbar,
+    horizontal: Scrollbar,
+}
+
+impl scrollable::StyleSheet for ForcedScrollable {
+    type Style = Theme;
+
+    fn active(&self, _style: &Self::Style) -> Scrollbar {
+        self.vertical
+    }
+
+    fn hovered(&self, _style: &Self::Style, _is_mouse_over_scrollbar: bool) -> Scrollbar {
+        self.vertical
+    }
+
+    fn dragging(&self, _style: &Self::Style) -> Scrollbar {
+        self.vertical
+    }
+
+    fn active_horizontal(&self, _style: &Self::Style) -> Scrollbar {
+        self.horizontal
+    }
+
+    fn hovered_horizontal(
+        &self,
+        _style: &Self::Style,
+        _is_mouse_over_scrollbar: bool,
+    ) -> Scrollbar {
+        self.horizontal
+    }
+
+    fn dragging_horizontal(&self, _style: &Self::Style) -> Scrollbar {
+        self.horizontal
+    }
+}
@@ -0,0 +1,768 @@
+//! Edits a stylesheet in the [`css`] dialect without writing any Rust: pick a widget and a state,
+//! adjust its colors, borders and radii, watch the preview and save.
+//!
+//! Usage: `theme-editor <stylesheet.css> [--dark]`. A missing file is created on save, on top of
+//! the light or dark theme.
+
+use iced::widget::scrollable::{Direction, Properties};
+use iced::widget::{
+    button, column, container, row, scrollable, slider, text, text_input, Column, Row,
+};
+use iced::{executor, Application, Color, Command, Element, Length, Settings, Theme};
+use iced_custom_theme_helpers::color::{background_color, contrast_ratio, over};
+use iced_custom_theme_helpers::css;
+use iced_custom_theme_helpers::css_color;
+use iced_custom_theme_helpers::high_contrast::{effective_background, NON_TEXT_CONTRAST};
+use iced_custom_theme_helpers::lint::TEXT_CONTRAST;
+use iced_custom_theme_helpers::style_set::StyleSet;
+use iced_custom_theme_helpers::styles::button::ButtonState;
+use iced_custom_theme_helpers::styles::container::CustomContainerStyle;
+use iced_custom_theme_helpers::styles::scrollable::ScrollableState;
+use iced_custom_theme_helpers::styles::text_input::TextInputState;
+use std::path::PathBuf;
+
+const USAGE: &str = "usage: theme-editor <stylesheet.css> [--dark]";
+const MAX_BORDER_WIDTH: f32 = 8.0;
+const MAX_BORDER_RADIUS: f32 = 32.0;
+const LABEL_WIDTH: f32 = 160.0;
+
+const BUTTON_STATES: [(&str, ButtonState); 5] = [
+    ("All", ButtonState::all()),
+    ("Active", ButtonState::ACTIVE),
+    ("Hovered", ButtonState::HOVERED),
+    ("Pressed", ButtonState::PRESSED),
+    ("Disabled", ButtonState::DISABLED),
+];
+
+const TEXT_INPUT_STATES: [(&str, TextInputState); 5] = [
+    ("All", TextInputState::all()),
+    ("Active", TextInputState::ACTIVE),
+    ("Hovered", TextInputState::HOVERED),
+    ("Focused", TextInputState::FOCUSED),
+    ("Disabled", TextInputState::DISABLED),
+];
+
+const SCROLLABLE_STATES: [(&str, ScrollableState); 9] = [
+    ("All", ScrollableState::all()),
+    ("Active", ScrollableState::ACTIVE),
+    ("Hovered", ScrollableState::HOVERED),
+    ("Over scrollbar", ScrollableState::HOVERED_OVER_SCROLLBAR),
+    ("Dragging", ScrollableState::DRAGGING),
+    ("Active horizontal", ScrollableState::ACTIVE_HORIZONTAL),
+    ("Hovered horizontal", ScrollableState::HOVERED_HORIZONTAL),
+    (
+        "Over horizontal scrollbar",
+        ScrollableState::HOVERED_HORIZONTAL_OVER_SCROLLBAR,
+    ),
+    ("Dragging horizontal", ScrollableState::DRAGGING_HORIZONTAL),
+];
+
+pub fn main() -> iced::Result {
+    let (mut path, mut dark) = (None, false);
+    for argument in std::env::args_os().skip(1) {
+        match argument.to_str() {
+            Some("--dark") => dark = true,
+            Some("-h" | "--help") => exit(USAGE, 0),
+            _ if path.is_none() => path = Some(PathBuf::from(argument)),
+            _ => exit(USAGE, 2),
+        }
+    }
+    let Some(path) = path else {
+        exit(USAGE, 2);
+    };
+    let theme = if dark { Theme::Dark } else { Theme::Light };
+
+    let (styles, status) = match std::fs::read_to_string(&path) {
+        Ok(source) => match css::parse(&source, &theme) {
+            Ok(imported) => {
+                for warning in &imported.warnings {
+                    let (line, column) = warning.span.line_col(&source);
+                    eprintln!("{}:{line}:{column}: {}", path.display(), warning.message);
+                }
+                let status = match imported.warnings.len() {
+                    0 => format!("Loaded {}", path.display()),
+                    count => format!("Loaded {} with {count} warnings", path.display()),
+                };
+                (imported.styles, status)
+            }
+            Err(error) => {
+                let (line, column) = error.span.line_col(&source);
+                exit(
+                    &format!("{}:{line}:{column}: {}", path.display(), error.message),
+                    1,
+                );
+            }
+        },
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => (
+            StyleSet::from_theme(&theme),
+            format!("{} will be created on save", path.display()),
+        ),
+        Err(error) => exit(&format!("{}: {error}", path.display()), 1),
+    };
+
+    Editor::run(Settings::with_flags(Flags {
+        path,
+        theme,
+        styles,
+        status,
+    }))
+}
+
+fn exit(message: &str, code: i32) -> ! {
+    eprintln!("{message}");
+    std::process::exit(code)
+}
+
+struct Flags {
+    path: PathBuf,
+    theme: Theme,
+    styles: StyleSet,
+    status: String,
+}
+
+struct Editor {
+    path: PathBuf,
+    theme: Theme,
+    styles: StyleSet,
+    widget: Widget,
+    /// Index into the states of `widget`.
+    state: usize,
+    /// What was typed into a color field, kept until another edit so partial input survives.
+    draft: Option<(Field, String)>,
+    preview_value: String,
+    status: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Widget {
+    Button(usize),
+    Container(usize),
+    TextInput,
+    Scrollable,
+    Text,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Background,
+    Text,
+    BorderColor,
+    BorderWidth,
+    BorderRadius,
+    Value,
+    Placeholder,
+    DisabledText,
+    Selection,
+    Scroller,
+    ScrollerBorderColor,
+    ScrollerBorderWidth,
+    ScrollerBorderRadius,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Color(Color),
+    Length(f32),
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    SelectWidget(Widget),
+    SelectState(usize),
+    Set(Field, Value),
+    Typed(Field, String),
+    PreviewInput(String),
+    Pressed,
+    Save,
+}
+
+impl Widget {
+    fn states(self) -> Vec<&'static str> {
+        match self {
+            Widget::Button(_) => BUTTON_STATES.iter().map(|(name, _)| *name).collect(),
+            Widget::TextInput => TEXT_INPUT_STATES.iter().map(|(name, _)| *name).collect(),
+            Widget::Scrollable => SCROLLABLE_STATES.iter().map(|(name, _)| *name).collect(),
+            Widget::Container(_) | Widget::Text => Vec::new(),
+        }
+    }
+
+    fn fields(self) -> &'static [(&'static str, Field)] {
+        match self {
+            Widget::Button(_) | Widget::Container(_) => &[
+                ("Background", Field::Background),
+                ("Text", Field::Text),
+                ("Border color", Field::BorderColor),
+                ("Border width", Field::BorderWidth),
+                ("Border radius", Field::BorderRadius),
+            ],
+            Widget::TextInput => &[
+                ("Background", Field::Background),
+                ("Border color", Field::BorderColor),
+                ("Border width", Field::BorderWidth),
+                ("Border radius", Field::BorderRadius),
+                ("Value (all states)", Field::Value),
+                ("Placeholder (all states)", Field::Placeholder),
+                ("Disabled text (all states)", Field::DisabledText),
+                ("Selection (all states)", Field::Selection),
+            ],
+            Widget::Scrollable => &[
+                ("Background", Field::Background),
+                ("Border color", Field::BorderColor),
+                ("Border width", Field::BorderWidth),
+                ("Border radius", Field::BorderRadius),
+                ("Scroller", Field::Scroller),
+                ("Scroller border color", Field::ScrollerBorderColor),
+                ("Scroller border width", Field::ScrollerBorderWidth),
+                ("Scroller border radius", Field::ScrollerBorderRadius),
+            ],
+            Widget::Text => &[("Color", Field::Text)],
+        }
+    }
+}
+
+impl Application for Editor {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = Flags;
+
+    fn new(flags: Flags) -> (Self, Command<Message>) {
+        let editor = Self {
+            path: flags.path,
+            theme: flags.theme,
+            styles: flags.styles,
+            widget: Widget::Button(0),
+            state: 0,
+            draft: None,
+            preview_value: String::new(),
+            status: flags.status,
+        };
+        (editor, Command::none())
+    }
+
+    fn title(&self) -> String {
+        format!("{} - Theme editor", self.path.display())
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::SelectWidget(widget) => {
+                self.widget = widget;
+                self.state = 0;
+                self.draft = None;
+            }
+            Message::SelectState(state) => {
+                self.state = state;
+                self.draft = None;
+            }
+            Message::Set(field, value) => {
+                self.draft = None;
+                self.set(field, value);
+            }
+            Message::Typed(field, typed) => {
+                if let Ok(color) = css_color::parse(&typed) {
+                    self.set(field, Value::Color(color));
+                }
+                self.draft = Some((field, typed));
+            }
+            Message::PreviewInput(value) => self.preview_value = value,
+            Message::Pressed => {}
+            Message::Save => {
+                self.status = match std::fs::write(&self.path, css::write(&self.styles)) {
+                    Ok(()) => format!("Saved {}", self.path.display()),
+                    Err(error) => format!("Could not save {}: {error}", self.path.display()),
+                };
+            }
+        }
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let mut widgets = Column::new().spacing(4).width(Length::Fixed(LABEL_WIDTH));
+        for (index, (name, _)) in self.styles.buttons().into_iter().enumerate() {
+            widgets = widgets.push(self.selector(&format!("Button {name}"), Widget::Button(index)));
+        }
+        for (index, (name, _)) in self.styles.containers().into_iter().enumerate() {
+            widgets =
+                widgets.push(self.selector(&format!("Container {name}"), Widget::Container(index)));
+        }
+        widgets = widgets
+            .push(self.selector("Text input", Widget::TextInput))
+            .push(self.selector("Scrollable", Widget::Scrollable))
+            .push(self.selector("Text", Widget::Text));
+
+        let states = Row::with_children(
+            self.widget
+                .states()
+                .into_iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    let style = if index == self.state {
+                        iced::theme::Button::Primary
+                    } else {
+                        iced::theme::Button::Secondary
+                    };
+                    button(text(name))
+                        .style(style)
+                        .on_press(Message::SelectState(index))
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(4);
+
+        let fields = Column::with_children(
+            self.widget
+                .fields()
+                .iter()
+                .map(|(label, field)| self.field(label, *field))
+                .collect(),
+        )
+        .spacing(12);
+
+        let controls = column![
+            states,
+            fields,
+            row![
+                button(text("Save")).on_press(Message::Save),
+                text(&self.status)
+            ]
+            .spacing(12),
+        ]
+        .spacing(24)
+        .width(Length::Fill);
+
+        row![
+            widgets,
+            scrollable(controls).width(Length::FillPortion(3)),
+            scrollable(self.preview()).width(Length::FillPortion(2)),
+        ]
+        .spacing(24)
+        .padding(24)
+        .into()
+    }
+
+    fn theme(&self) -> Theme {
+        self.theme.clone()
+    }
+}
+
+impl Editor {
+    fn selector(&self, label: &str, widget: Widget) -> Element<'_, Message> {
+        let style = if widget == self.widget {
+            iced::theme::Button::Primary
+        } else {
+            iced::theme::Button::Text
+        };
+        button(text(label))
+            .width(Length::Fill)
+            .style(style)
+            .on_press(Message::SelectWidget(widget))
+            .into()
+    }
+
+    fn field(&self, label: &str, field: Field) -> Element<'_, Message> {
+        let label = text(label).width(Length::Fixed(LABEL_WIDTH));
+        let mut notes = Vec::new();
+
+        let editor: Element<'_, Message> = match self.value(field) {
+            Value::Color(color) => {
+                let typed = match &self.draft {
+                    Some((draft, typed)) if *draft == field => {
+                        if let Err(error) = css_color::parse(typed) {
+                            notes.push(error.message);
+                        }
+                        typed.clone()
+                    }
+                    _ => css_color::format(color),
+                };
+                let channel = |name: &str, value: f32, set: fn(Color, f32) -> Color| {
+                    row![
+                        text(name),
+                        slider(0.0..=1.0, value, move |value| {
+                            Message::Set(field, Value::Color(set(color, value)))
+                        })
+                        .step(1.0 / 255.0)
+                        .width(Length::Fixed(96.0)),
+                    ]
+                    .spacing(4)
+                };
+                row![
+                    container(text(""))
+                        .width(Length::Fixed(24.0))
+                        .height(Length::Fixed(24.0))
+                        .style(
+                            CustomContainerStyle::new()
+                                .background_color(color)
+                                .border_width(1.0)
+                                .border_color(self.theme.palette().text)
+                                .as_custom()
+                        ),
+                    text_input("#rrggbb", &typed)
+                        .on_input(move |typed| Message::Typed(field, typed))
+                        .width(Length::Fixed(200.0)),
+                    channel("R", color.r, |color, r| Color { r, ..color }),
+                    channel("G", color.g, |color, g| Color { g, ..color }),
+                    channel("B", color.b, |color, b| Color { b, ..color }),
+                    channel("A", color.a, |color, a| Color { a, ..color }),
+                ]
+                .spacing(8)
+                .into()
+            }
+            Value::Length(length) => {
+                let max = match field {
+                    Field::BorderRadius | Field::ScrollerBorderRadius => MAX_BORDER_RADIUS,
+                    _ => MAX_BORDER_WIDTH,
+                };
+                row![
+                    slider(0.0..=max, length, move |length| {
+                        Message::Set(field, Value::Length(length))
+                    })
+                    .step(0.5)
+                    .width(Length::Fixed(240.0)),
+                    text(format!("{length}px")),
+                ]
+                .spacing(8)
+                .into()
+            }
+        };
+        notes.extend(self.contrast_warning(field));
+
+        let mut column = Column::new()
+            .spacing(4)
+            .push(row![label, editor].spacing(8));
+        for note in notes {
+            column = column.push(
+                text(format!("⚠ {note}"))
+                    .style(iced::theme::Text::Color(self.theme.palette().danger)),
+            );
+        }
+        column.into()
+    }
+
+    fn preview(&self) -> Element<'_, Message> {
+        let styles = &self.styles;
+        let buttons = |enabled: bool| {
+            Column::with_children(
+                styles
+                    .buttons()
+                    .into_iter()
+                    .map(|(name, style)| {
+                        let button = button(text(name))
+                            .width(Length::Fixed(120.0))
+                            .style(style.as_custom());
+                        if enabled {
+                            button.on_press(Message::Pressed).into()
+                        } else {
+                            button.into()
+                        }
+                    })
+                    .collect(),
+            )
+            .spacing(8)
+        };
+        let containers = Column::with_children(
+            styles
+                .containers()
+                .into_iter()
+                .map(|(name, style)| {
+                    container(text(format!("Container {name}")))
+                        .width(Length::Fill)
+                        .padding(16)
+                        .style(style.as_custom())
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(8);
+        let lines = Column::with_children(
+            (1..=30)
+                .map(|line| text(format!("Line {line} of content wide enough to scroll")).into())
+                .collect(),
+        );
+
+        column![
+            text("Preview").size(24).style(styles.text.as_custom()),
+            row![buttons(true), buttons(false)].spacing(8),
+            text_input("Placeholder", &self.preview_value)
+                .on_input(Message::PreviewInput)
+                .style(styles.text_input.as_custom()),
+            text_input("Disabled", &self.preview_value).style(styles.text_input.as_custom()),
+            containers,
+            scrollable(lines)
+                .height(Length::Fixed(160.0))
+                .direction(Direction::Both {
+                    vertical: Properties::default(),
+                    horizontal: Properties::default(),
+                })
+                .style(styles.scrollable.as_custom()),
+        ]
+        .spacing(16)
+        .into()
+    }
+
+    /// Reads a field of the first selected state, i.e. of the active state when all are selected.
+    fn value(&self, field: Field) -> Value {
+        let styles = &self.styles;
+        let color = |background: Option<iced::Background>| {
+            background.map_or(Color::TRANSPARENT, background_color)
+        };
+        match self.widget {
+            Widget::Button(index) => {
+                let button = styles.buttons()[index].1;
+                let appearance = first_selected(
+                    BUTTON_STATES[self.state].1,
+                    [
+                        (ButtonState::ACTIVE, button.active),
+                        (ButtonState::HOVERED, button.hovered),
+                        (ButtonState::PRESSED, button.pressed),
+                        (ButtonState::DISABLED, button.disabled),
+                    ],
+                );
+                match field {
+                    Field::Background => Value::Color(color(appearance.background)),
+                    Field::BorderColor => Value::Color(appearance.border_color),
+                    Field::BorderWidth => Value::Length(appearance.border_width),
+                    Field::BorderRadius => Value::Length(radius(appearance.border_radius)),
+                    _ => Value::Color(appearance.text_color),
+                }
+            }
+            Widget::Container(index) => {
+                let appearance = styles.containers()[index].1.appearance;
+                match field {
+                    Field::Background => Value::Color(color(appearance.background)),
+                    Field::BorderColor => Value::Color(appearance.border_color),
+                    Field::BorderWidth => Value::Length(appearance.border_width),
+                    Field::BorderRadius => Value::Length(radius(appearance.border_radius)),
+                    _ => Value::Color(appearance.text_color.unwrap_or(self.theme.palette().text)),
+                }
+            }
+            Widget::TextInput => {
+                let input = &styles.text_input;
+                let appearance = first_selected(
+                    TEXT_INPUT_STATES[self.state].1,
+                    [
+                        (TextInputState::ACTIVE, input.active),
+                        (TextInputState::HOVERED, input.hovered),
+                        (TextInputState::FOCUSED, input.focused),
+                        (TextInputState::DISABLED, input.disabled),
+                    ],
+                );
+                match field {
+                    Field::Background => Value::Color(background_color(appearance.background)),
+                    Field::BorderColor => Value::Color(appearance.border_color),
+                    Field::BorderWidth => Value::Length(appearance.border_width),
+                    Field::BorderRadius => Value::Length(radius(appearance.border_radius)),
+                    Field::Placeholder => Value::Color(input.placeholder_color),
+                    Field::DisabledText => Value::Color(input.disabled_color),
+                    Field::Selection => Value::Color(input.selection_color),
+                    _ => Value::Color(input.value_color),
+                }
+            }
+            Widget::Scrollable => {
+                let scrollbar = self.scrollbar();
+                match field {
+                    Field::Background => Value::Color(color(scrollbar.background)),
+                    Field::BorderColor => Value::Color(scrollbar.border_color),
+                    Field::BorderWidth => Value::Length(scrollbar.border_width),
+                    Field::BorderRadius => Value::Length(radius(scrollbar.border_radius)),
+                    Field::ScrollerBorderColor => Value::Color(scrollbar.scroller.border_color),
+                    Field::ScrollerBorderWidth => Value::Length(scrollbar.scroller.border_width),
+                    Field::ScrollerBorderRadius => {
+                        Value::Length(radius(scrollbar.scroller.border_radius))
+                    }
+                    _ => Value::Color(scrollbar.scroller.color),
+                }
+            }
+            Widget::Text => Value::Color(
+                styles
+                    .text
+                    .appearance
+                    .color
+                    .unwrap_or(self.theme.palette().text),
+            ),
+        }
+    }
+
+    fn scrollbar(&self) -> iced::widget::scrollable::Scrollbar {
+        let scrollable = &self.styles.scrollable;
+        first_selected(
+            SCROLLABLE_STATES[self.state].1,
+            [
+                (ScrollableState::ACTIVE, scrollable.active),
+                (ScrollableState::HOVERED, scrollable.hovered),
+                (
+                    ScrollableState::HOVERED_OVER_SCROLLBAR,
+                    scrollable.hovered_over_scrollbar,
+                ),
+                (ScrollableState::DRAGGING, scrollable.dragging),
+                (
+                    ScrollableState::ACTIVE_HORIZONTAL,
+                    scrollable.active_horizontal,
+                ),
+                (
+                    ScrollableState::HOVERED_HORIZONTAL,
+                    scrollable.hovered_horizontal,
+                ),
+                (
+                    ScrollableState::HOVERED_HORIZONTAL_OVER_SCROLLBAR,
+                    scrollable.hovered_horizontal_over_scrollbar,
+                ),
+                (
+                    ScrollableState::DRAGGING_HORIZONTAL,
+                    scrollable.dragging_horizontal,
+                ),
+            ],
+        )
+    }
+
+    /// Writes a field through the builder methods of the style, in the selected state.
+    fn set(&mut self, field: Field, value: Value) {
+        let styles = &mut self.styles;
+        match self.widget {
+            Widget::Button(index) => {
+                let mut buttons = styles.buttons_mut();
+                let button = &mut buttons[index].1;
+                let mut style = **button;
+                style.current_state = BUTTON_STATES[self.state].1;
+                style = match (field, value) {
+                    (Field::Background, Value::Color(color)) => style.background_color(color),
+                    (Field::Text, Value::Color(color)) => style.text_color(color),
+                    (Field::BorderColor, Value::Color(color)) => style.border_color(color),
+                    (Field::BorderWidth, Value::Length(width)) => style.border_width(width),
+                    (Field::BorderRadius, Value::Length(radius)) => style.border_radius(radius),
+                    _ => style,
+                };
+                **button = style.active();
+            }
+            Widget::Container(index) => {
+                let mut containers = styles.containers_mut();
+                let container = &mut containers[index].1;
+                **container = match (field, value) {
+                    (Field::Background, Value::Color(color)) => container.background_color(color),
+                    (Field::Text, Value::Color(color)) => container.text_color(Some(color)),
+                    (Field::BorderColor, Value::Color(color)) => container.border_color(color),
+                    (Field::BorderWidth, Value::Length(width)) => container.border_width(width),
+                    (Field::BorderRadius, Value::Length(radius)) => container.border_radius(radius),
+                    _ => **container,
+                };
+            }
+            Widget::TextInput => {
+                let mut style = styles.text_input;
+                style.current_state = TEXT_INPUT_STATES[self.state].1;
+                style = match (field, value) {
+                    (Field::Background, Value::Color(color)) => style.background_color(color),
+                    (Field::BorderColor, Value::Color(color)) => style.border_color(color),
+                    (Field::BorderWidth, Value::Length(width)) => style.border_width(width),
+                    (Field::BorderRadius, Value::Length(radius)) => style.border_radius(radius),
+                    (Field::Value, Value::Color(color)) => style.value_color(color),
+                    (Field::Placeholder, Value::Color(color)) => style.placeholder_color(color),
+                    (Field::DisabledText, Value::Color(color)) => style.disabled_color(color),
+                    (Field::Selection, Value::Color(color)) => style.selection_color(color),
+                    _ => style,
+                };
+                styles.text_input = style.active();
+            }
+            Widget::Scrollable => {
+                let mut style = styles.scrollable;
+                style.current_state = SCROLLABLE_STATES[self.state].1;
+                style = match (field, value) {
+                    (Field::Background, Value::Color(color)) => style.background_color(color),
+                    (Field::BorderColor, Value::Color(color)) => style.border_color(color),
+                    (Field::BorderWidth, Value::Length(width)) => style.border_width(width),
+                    (Field::BorderRadius, Value::Length(radius)) => style.border_radius(radius),
+                    (Field::Scroller, Value::Color(color)) => style.scroller_color(color),
+                    (Field::ScrollerBorderColor, Value::Color(color)) => {
+                        style.scroller_border_color(color)
+                    }
+                    (Field::ScrollerBorderWidth, Value::Length(width)) => {
+                        style.scroller_border_width(width)
+                    }
+                    (Field::ScrollerBorderRadius, Value::Length(radius)) => {
+                        style.scroller_border_radius(radius)
+                    }
+                    _ => style,
+                };
+                styles.scrollable = style.active();
+            }
+            Widget::Text => {
+                if let (Field::Text, Value::Color(color)) = (field, value) {
+                    styles.text = styles.text.color(color);
+                }
+            }
+        }
+    }
+
+    /// Checks text against what it is drawn on and indicators against the window. Disabled
+    /// states are exempt, as in WCAG.
+    fn contrast_warning(&self, field: Field) -> Option<String> {
+        let canvas = self.theme.palette().background;
+        let color = |field| match self.value(field) {
+            Value::Color(color) => color,
+            Value::Length(_) => Color::TRANSPARENT,
+        };
+        let length = |field| match self.value(field) {
+            Value::Length(length) => length,
+            Value::Color(_) => 0.0,
+        };
+        let disabled = match self.widget {
+            Widget::Button(_) => BUTTON_STATES[self.state].1 == ButtonState::DISABLED,
+            Widget::TextInput => TEXT_INPUT_STATES[self.state].1 == TextInputState::DISABLED,
+            _ => false,
+        };
+
+        let (foreground, background, minimum, what) = match (self.widget, field) {
+            (Widget::Text, Field::Text) => (color(Field::Text), canvas, TEXT_CONTRAST, "window"),
+            (_, Field::Text) if !disabled => (
+                color(Field::Text),
+                effective_background(
+                    Some(iced::Background::Color(color(Field::Background))),
+                    canvas,
+                ),
+                TEXT_CONTRAST,
+                "background",
+            ),
+            (Widget::TextInput, Field::Value | Field::Placeholder) => (
+                color(field),
+                over(color(Field::Background), canvas),
+                TEXT_CONTRAST,
+                "background",
+            ),
+            (Widget::Scrollable, Field::Scroller) => (
+                color(Field::Scroller),
+                over(color(Field::Background), canvas),
+                NON_TEXT_CONTRAST,
+                "scrollbar",
+            ),
+            (_, Field::BorderColor) if !disabled && length(Field::BorderWidth) > 0.0 => (
+                color(Field::BorderColor),
+                canvas,
+                NON_TEXT_CONTRAST,
+                "window",
+            ),
+            _ => return None,
+        };
+
+        let ratio = contrast_ratio(over(foreground, background), background);
+        (ratio < minimum)
+            .then(|| format!("contrast against the {what} is {ratio:.1}:1, below {minimum}:1"))
+    }
+}
+
+/// The editor sets uniform radii and shows the top left one of others.
+fn radius(radius: iced::BorderRadius) -> f32 {
+    let radii: [f32; 4] = radius.into();
+    radii[0]
+}
+
+/// The appearance of the first state in `states`, or of the first entry when none is.
+fn first_selected<S: bitflags::Flags + Copy, T: Copy, const N: usize>(
+    states: S,
+    appearances: [(S, T); N],
+) -> T {
+    appearances
+        .iter()
+        .find(|(state, _)| states.contains(*state))
+        .unwrap_or(&appearances[0])
+        .1
+}
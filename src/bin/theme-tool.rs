@@ -0,0 +1,275 @@
+//! Validates, lints, converts and previews theme files. Every command exits non-zero when it finds
+//! a problem, so it can gate theme changes in CI.
+
+use iced_custom_theme_helpers::css;
+use iced_custom_theme_helpers::diagnostic::Diagnostic;
+use iced_custom_theme_helpers::export;
+use iced_custom_theme_helpers::import::terminal::{self, Format};
+use iced_custom_theme_helpers::import::{base16, gtk, kde, vscode, Imported};
+use iced_custom_theme_helpers::lint;
+use iced_custom_theme_helpers::snapshot;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+usage: theme-tool <command> <file> [options]
+
+commands:
+  validate  report syntax errors and unsupported declarations with their locations
+  lint      report contrast failures, invisible text and states too similar to give feedback
+  convert   write the styles in another format
+  preview   render PNG swatches of every button, text input and scrollable state
+
+options:
+  --from <format>  stylesheet, base16, gtk, kde, alacritty, kitty, xresources or vscode,
+                   guessed from the file name by default
+  --to <format>    stylesheet, css-variables or design-tokens, for convert (default stylesheet)
+  --dark           build on the dark theme (stylesheet) or prefer the dark variant (gtk)
+  -o <path>        output file for convert (default stdout), directory for preview (default .)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Validate,
+    Lint,
+    Convert,
+    Preview,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Input {
+    Stylesheet,
+    Base16,
+    Gtk,
+    Kde,
+    Terminal(Format),
+    Vscode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Stylesheet,
+    CssVariables,
+    DesignTokens,
+}
+
+struct Options {
+    command: Command,
+    path: PathBuf,
+    input: Input,
+    output: Output,
+    dark: bool,
+    destination: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_arguments(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let source = match std::fs::read_to_string(&options.path) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("{}: {error}", options.path.display());
+            return ExitCode::from(2);
+        }
+    };
+    let report = |severity: &str, diagnostic: &Diagnostic| {
+        let (line, column) = diagnostic.span.line_col(&source);
+        eprintln!(
+            "{}:{line}:{column}: {severity}: {}",
+            options.path.display(),
+            diagnostic.message
+        );
+    };
+
+    let imported = match import(&source, options.input, options.dark) {
+        Ok(imported) => imported,
+        Err(error) => {
+            report("error", &error);
+            return ExitCode::FAILURE;
+        }
+    };
+    for warning in &imported.warnings {
+        report("warning", warning);
+    }
+
+    let success = match options.command {
+        Command::Validate => imported.warnings.is_empty(),
+        Command::Lint => {
+            let findings = lint::lint(&imported.styles, imported.palette);
+            for finding in &findings {
+                println!("{}: {finding}", options.path.display());
+            }
+            findings.is_empty()
+        }
+        Command::Convert => convert(&imported, options.output, options.destination.as_deref()),
+        Command::Preview => preview(&imported, options.destination.as_deref()),
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn parse_arguments(mut arguments: impl Iterator<Item = String>) -> Result<Options, String> {
+    let command = match arguments.next().as_deref() {
+        Some("validate") => Command::Validate,
+        Some("lint") => Command::Lint,
+        Some("convert") => Command::Convert,
+        Some("preview") => Command::Preview,
+        Some(other) => return Err(format!("unknown command `{other}`")),
+        None => return Err(String::from("missing command")),
+    };
+
+    let (mut path, mut from, mut to, mut dark, mut destination) = (None, None, None, false, None);
+    while let Some(argument) = arguments.next() {
+        let mut value = || {
+            arguments
+                .next()
+                .ok_or_else(|| format!("missing value for `{argument}`"))
+        };
+        match argument.as_str() {
+            "--from" => from = Some(value()?),
+            "--to" => to = Some(value()?),
+            "-o" => destination = Some(PathBuf::from(value()?)),
+            "--dark" => dark = true,
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`")),
+            _ if path.is_none() => path = Some(PathBuf::from(argument)),
+            _ => return Err(format!("unexpected argument `{argument}`")),
+        }
+    }
+    let path = path.ok_or("missing file")?;
+
+    let input = match from.as_deref() {
+        Some("stylesheet") => Input::Stylesheet,
+        Some("base16") => Input::Base16,
+        Some("gtk") => Input::Gtk,
+        Some("kde") => Input::Kde,
+        Some("alacritty") => match Format::from_path(&path) {
+            Some(Format::AlacrittyYaml) => Input::Terminal(Format::AlacrittyYaml),
+            _ => Input::Terminal(Format::AlacrittyToml),
+        },
+        Some("kitty") => Input::Terminal(Format::Kitty),
+        Some("xresources") => Input::Terminal(Format::Xresources),
+        Some("vscode") => Input::Vscode,
+        Some(other) => return Err(format!("unknown input format `{other}`")),
+        None => guess_input(&path)
+            .ok_or_else(|| format!("cannot tell the format of {}, pass --from", path.display()))?,
+    };
+    let output = match to.as_deref() {
+        None | Some("stylesheet") => Output::Stylesheet,
+        Some("css-variables") => Output::CssVariables,
+        Some("design-tokens") => Output::DesignTokens,
+        Some(other) => return Err(format!("unknown output format `{other}`")),
+    };
+
+    Ok(Options {
+        command,
+        path,
+        input,
+        output,
+        dark,
+        destination,
+    })
+}
+
+/// Terminal themes are recognized first, though YAML files are only taken as Alacritty themes
+/// when their name says so and as Base16 schemes otherwise. GTK themes are recognized by their
+/// `gtk` prefix.
+fn guess_input(path: &Path) -> Option<Input> {
+    let name = path.file_name()?.to_str()?;
+    match Format::from_path(path) {
+        Some(Format::AlacrittyYaml) if !name.contains("alacritty") => return Some(Input::Base16),
+        Some(format) => return Some(Input::Terminal(format)),
+        None => {}
+    }
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("css") if name.starts_with("gtk") => Some(Input::Gtk),
+        Some("css") => Some(Input::Stylesheet),
+        Some("colors") => Some(Input::Kde),
+        Some("json") => Some(Input::Vscode),
+        _ if name == "kdeglobals" => Some(Input::Kde),
+        _ => None,
+    }
+}
+
+fn import(source: &str, input: Input, dark: bool) -> Result<Imported, Diagnostic> {
+    let theme = if dark {
+        iced::Theme::Dark
+    } else {
+        iced::Theme::Light
+    };
+    match input {
+        Input::Stylesheet => css::parse(source, &theme),
+        Input::Base16 => base16::parse(source),
+        Input::Gtk => gtk::parse(source, dark),
+        Input::Kde => kde::parse(source),
+        Input::Terminal(format) => terminal::parse(source, format),
+        Input::Vscode => vscode::parse(source),
+    }
+}
+
+fn convert(imported: &Imported, output: Output, destination: Option<&Path>) -> bool {
+    let converted = match output {
+        Output::Stylesheet => css::write(&imported.styles),
+        Output::CssVariables => export::to_css_variables(&imported.styles),
+        Output::DesignTokens => export::to_design_tokens(&imported.styles),
+    };
+    match destination {
+        None => {
+            print!("{converted}");
+            true
+        }
+        Some(destination) => match std::fs::write(destination, converted) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("{}: {error}", destination.display());
+                false
+            }
+        },
+    }
+}
+
+/// Writes `button-<preset>-<state>.png`, `text-input-<state>.png` and
+/// `scrollable-<state>.png`, drawn on the window background of the theme.
+fn preview(imported: &Imported, destination: Option<&Path>) -> bool {
+    let directory = destination.unwrap_or(Path::new("."));
+    if let Err(error) = std::fs::create_dir_all(directory) {
+        eprintln!("{}: {error}", directory.display());
+        return false;
+    }
+
+    let styles = &imported.styles;
+    let canvas = imported.palette.background;
+    let mut swatches = Vec::new();
+    for (name, button) in styles.buttons() {
+        for (state, pixmap) in snapshot::button(button, canvas) {
+            swatches.push((format!("button-{name}-{state}"), pixmap));
+        }
+    }
+    for (state, pixmap) in snapshot::text_input(&styles.text_input, canvas) {
+        swatches.push((format!("text-input-{state}"), pixmap));
+    }
+    for (state, pixmap) in snapshot::scrollable(&styles.scrollable, canvas) {
+        swatches.push((format!("scrollable-{state}"), pixmap));
+    }
+
+    let mut success = true;
+    for (name, pixmap) in swatches {
+        let path = directory.join(format!("{name}.png"));
+        match pixmap.save_png(&path) {
+            Ok(()) => println!("{}", path.display()),
+            Err(error) => {
+                eprintln!("{}: {error}", path.display());
+                success = false;
+            }
+        }
+    }
+    success
+}
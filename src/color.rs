@@ -0,0 +1,276 @@
+use iced::{Background, Color};
+
+/// A color from its `0xrrggbb` hex code.
+pub(crate) const fn rgb(hex: u32) -> Color {
+    let [_, r, g, b] = hex.to_be_bytes();
+    Color::from_rgb(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0)
+}
+
+pub fn to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn from_linear(channel: f32) -> f32 {
+    let channel = channel.clamp(0.0, 1.0);
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// WCAG 2.x relative luminance of the color, ignoring alpha.
+pub fn relative_luminance(color: Color) -> f32 {
+    0.2126 * to_linear(color.r) + 0.7152 * to_linear(color.g) + 0.0722 * to_linear(color.b)
+}
+
+/// WCAG 2.x contrast ratio between two opaque colors, from 1.0 to 21.0.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Linear interpolation in sRGB space, `t = 0.0` yields `a`.
+pub fn mix(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
+
+/// Composites `top` over `bottom`, returning an opaque color.
+pub fn over(top: Color, bottom: Color) -> Color {
+    let bottom = opaque(bottom);
+    Color {
+        r: top.r * top.a + bottom.r * (1.0 - top.a),
+        g: top.g * top.a + bottom.g * (1.0 - top.a),
+        b: top.b * top.a + bottom.b * (1.0 - top.a),
+        a: 1.0,
+    }
+}
+
+pub const fn opaque(color: Color) -> Color {
+    Color { a: 1.0, ..color }
+}
+
+/// The color a background is perceived as, using the first stop for gradients.
+pub fn background_color(background: Background) -> Color {
+    match background {
+        Background::Color(color) => color,
+        Background::Gradient(iced::Gradient::Linear(linear)) => linear
+            .stops
+            .iter()
+            .flatten()
+            .map(|stop| stop.color)
+            .next()
+            .unwrap_or(Color::TRANSPARENT),
+    }
+}
+
+/// Converts to OKLab `[l, a, b]`, ignoring alpha.
+pub fn to_oklab(color: Color) -> [f32; 3] {
+    let (r, g, b) = (to_linear(color.r), to_linear(color.g), to_linear(color.b));
+
+    let l = (0.41222147 * r + 0.53633254 * g + 0.051445993 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171884 * g + 0.6299787 * b).cbrt();
+
+    [
+        0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+    ]
+}
+
+/// Converts OKLab to linear sRGB channels, which fall outside `0.0..=1.0` when out of gamut.
+fn oklab_to_linear([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = (l + 0.39633778 * a + 0.21580376 * b).powi(3);
+    let m_ = (l - 0.105561346 * a - 0.06385417 * b).powi(3);
+    let s_ = (l - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+    [
+        4.0767417 * l_ - 3.3077116 * m_ + 0.23096994 * s_,
+        -1.268438 * l_ + 2.6097574 * m_ - 0.34131938 * s_,
+        -0.0041960864 * l_ - 0.7034186 * m_ + 1.7076147 * s_,
+    ]
+}
+
+/// Converts from OKLab, clamping colors outside of sRGB.
+pub fn from_oklab(lab: [f32; 3], alpha: f32) -> Color {
+    let [r, g, b] = oklab_to_linear(lab);
+    Color::from_rgba(from_linear(r), from_linear(g), from_linear(b), alpha)
+}
+
+/// Converts to OKLCH `[l, c, hue in degrees]`, ignoring alpha.
+pub fn to_oklch(color: Color) -> [f32; 3] {
+    let [l, a, b] = to_oklab(color);
+    [l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0)]
+}
+
+/// Converts from OKLCH, clamping colors outside of sRGB.
+pub fn from_oklch(lch: [f32; 3], alpha: f32) -> Color {
+    from_oklab(oklch_to_oklab(lch), alpha)
+}
+
+pub fn oklch_in_gamut(lch: [f32; 3]) -> bool {
+    const EPSILON: f32 = 1e-4;
+    oklab_to_linear(oklch_to_oklab(lch))
+        .iter()
+        .all(|channel| (-EPSILON..=1.0 + EPSILON).contains(channel))
+}
+
+fn oklch_to_oklab([l, c, hue]: [f32; 3]) -> [f32; 3] {
+    let hue = hue.to_radians();
+    [l, c * hue.cos(), c * hue.sin()]
+}
+
+/// Euclidean distance in OKLab. Around 0.02 is a just-noticeable difference.
+pub fn oklab_distance(a: Color, b: Color) -> f32 {
+    let (a, b) = (to_oklab(a), to_oklab(b));
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// Converts to CIELAB `[l, a, b]` under a D65 white point, ignoring alpha.
+pub fn to_lab(color: Color) -> [f32; 3] {
+    let (r, g, b) = (to_linear(color.r), to_linear(color.g), to_linear(color.b));
+    let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.072175 * b;
+    let z = (0.0193339 * r + 0.119192 * g + 0.9503041 * b) / 1.08883;
+
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// CIEDE2000 color difference, ignoring alpha. Around 1.0 is a just-noticeable difference.
+pub fn delta_e2000(a: Color, b: Color) -> f32 {
+    ciede2000(to_lab(a), to_lab(b))
+}
+
+/// Follows Sharma, Wu and Dalal (2005), with unit weighting factors.
+fn ciede2000([l1, a1, b1]: [f32; 3], [l2, a2, b2]: [f32; 3]) -> f32 {
+    let mean_c = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - (mean_c.powi(7) / (mean_c.powi(7) + 25f32.powi(7))).sqrt());
+    let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |a: f32, b: f32| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 <= h1 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1 - 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let mean_l = (l1 + l2) / 2.0;
+    let mean_c = (c1 + c2) / 2.0;
+    let mean_h = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (mean_h - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * mean_h).to_radians().cos()
+        + 0.32 * (3.0 * mean_h + 6.0).to_radians().cos()
+        - 0.2 * (4.0 * mean_h - 63.0).to_radians().cos();
+    let s_l = 1.0 + 0.015 * (mean_l - 50.0).powi(2) / (20.0 + (mean_l - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * mean_c;
+    let s_h = 1.0 + 0.015 * mean_c * t;
+    let r_t = -2.0
+        * (mean_c.powi(7) / (mean_c.powi(7) + 25f32.powi(7))).sqrt()
+        * (60.0 * (-((mean_h - 275.0) / 25.0).powi(2)).exp())
+            .to_radians()
+            .sin();
+
+    let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// Converts to `[hue in degrees, saturation, lightness]`, ignoring alpha.
+pub fn to_hsl(color: Color) -> [f32; 3] {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+    if delta == 0.0 {
+        return [0.0, 0.0, lightness];
+    }
+
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == color.r {
+        ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        (color.b - color.r) / delta + 2.0
+    } else {
+        (color.r - color.g) / delta + 4.0
+    };
+    [hue * 60.0, saturation, lightness]
+}
+
+pub fn from_hsl([hue, saturation, lightness]: [f32; 3], alpha: f32) -> Color {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |c: f32| (c + m).clamp(0.0, 1.0);
+    Color::from_rgba(channel(r), channel(g), channel(b), alpha)
+}
+
+/// Parses `rgb`, `rgba`, `rrggbb` or `rrggbbaa` hex digits, with or without a leading `#`.
+pub fn parse_hex(digits: &str) -> Option<Color> {
+    let digits = digits.strip_prefix('#').unwrap_or(digits);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    let short = |i: usize| channel(&digits[i..i + 1].repeat(2));
+    let long = |i: usize| channel(&digits[i * 2..i * 2 + 2]);
+
+    let [r, g, b, a] = match digits.len() {
+        3 => [short(0)?, short(1)?, short(2)?, 255],
+        4 => [short(0)?, short(1)?, short(2)?, short(3)?],
+        6 => [long(0)?, long(1)?, long(2)?, 255],
+        8 => [long(0)?, long(1)?, long(2)?, long(3)?],
+        _ => return None,
+    };
+    Some(Color::from_rgba8(r, g, b, a as f32 / 255.0))
+}
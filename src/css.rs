@@ -0,0 +1,973 @@
+//! A restricted CSS dialect for describing a [`StyleSet`].
+//!
+//! Selectors name a widget, an optional preset class, an optional pseudo-element and any number
+//! of pseudo-classes, e.g. `button.primary:hover`, `text-input:focus`,
+//! `scrollable::scroller:active` or `scrollable:horizontal:hover`. Rules without a class apply to
+//! every preset of that widget, rules without a pseudo-class apply to every state. As in CSS,
+//! more specific rules win regardless of their order.
+
+use crate::color::background_color;
+use crate::css_color;
+use crate::diagnostic::{Diagnostic, Span};
+use crate::import::Imported;
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use iced::{Background, BorderRadius, Color, Vector};
+use std::fmt::Write;
+
+/// Parses `source` on top of the presets of `theme`. Syntax errors fail the whole parse,
+/// unsupported selectors, properties and values are skipped and reported as warnings.
+pub fn parse(source: &str, theme: &iced::Theme) -> Result<Imported, Diagnostic> {
+    let mut warnings = Vec::new();
+    let mut rules = Vec::new();
+
+    for (order, rule) in parse_rules(source)?.into_iter().enumerate() {
+        let properties: Vec<_> = rule
+            .declarations
+            .iter()
+            .filter_map(|declaration| match parse_declaration(declaration) {
+                Ok(properties) => Some(properties),
+                Err(warning) => {
+                    warnings.push(warning);
+                    None
+                }
+            })
+            .flatten()
+            .collect();
+
+        for (selector, span) in &rule.selectors {
+            match parse_selector(selector, *span) {
+                Ok(selector) => {
+                    rules.push((selector.specificity(), order, selector, properties.clone()))
+                }
+                Err(warning) => warnings.push(warning),
+            }
+        }
+    }
+    rules.sort_by_key(|(specificity, order, _, _)| (*specificity, *order));
+
+    let mut styles = StyleSet::from_theme(theme);
+    for (_, _, selector, properties) in rules {
+        apply(&mut styles, &selector, &properties, &mut warnings);
+    }
+
+    Ok(Imported {
+        styles,
+        palette: theme.palette(),
+        warnings,
+    })
+}
+
+/// Writes `styles` as one rule per preset and state. Parsing the output restores them, except
+/// that gradients are reduced to their first color and text input icon colors, which the
+/// dialect cannot express, fall back to the theme.
+pub fn write(styles: &StyleSet) -> String {
+    let mut css = String::new();
+
+    for (name, button) in styles.buttons() {
+        for (state, appearance) in [
+            ("", button.active),
+            (":hover", button.hovered),
+            (":active", button.pressed),
+            (":disabled", button.disabled),
+        ] {
+            let shadow = appearance.shadow_offset;
+            write_rule(
+                &mut css,
+                &format!("button.{name}{state}"),
+                &[
+                    ("background", format_background(appearance.background)),
+                    ("color", css_color::format(appearance.text_color)),
+                    (
+                        "border",
+                        format_border(appearance.border_width, appearance.border_color),
+                    ),
+                    ("border-radius", format_radius(appearance.border_radius)),
+                    ("box-shadow", format!("{}px {}px", shadow.x, shadow.y)),
+                ],
+            );
+        }
+    }
+
+    for (name, container) in styles.containers() {
+        let appearance = container.appearance;
+        let mut declarations = vec![("background", format_background(appearance.background))];
+        declarations.extend(
+            appearance
+                .text_color
+                .map(|color| ("color", css_color::format(color))),
+        );
+        declarations.push((
+            "border",
+            format_border(appearance.border_width, appearance.border_color),
+        ));
+        declarations.push(("border-radius", format_radius(appearance.border_radius)));
+        write_rule(&mut css, &format!("container.{name}"), &declarations);
+    }
+
+    let input = &styles.text_input;
+    for (state, appearance, color) in [
+        ("", input.active, Some(input.value_color)),
+        (":hover", input.hovered, None),
+        (":focus", input.focused, None),
+        (":disabled", input.disabled, Some(input.disabled_color)),
+    ] {
+        let mut declarations = vec![(
+            "background",
+            css_color::format(background_color(appearance.background)),
+        )];
+        declarations.extend(color.map(|color| ("color", css_color::format(color))));
+        declarations.push((
+            "border",
+            format_border(appearance.border_width, appearance.border_color),
+        ));
+        declarations.push(("border-radius", format_radius(appearance.border_radius)));
+        write_rule(&mut css, &format!("text-input{state}"), &declarations);
+    }
+    write_rule(
+        &mut css,
+        "text-input::placeholder",
+        &[("color", css_color::format(input.placeholder_color))],
+    );
+    write_rule(
+        &mut css,
+        "text-input::selection",
+        &[("color", css_color::format(input.selection_color))],
+    );
+
+    let scrollable = &styles.scrollable;
+    for (state, scrollbar) in [
+        (":vertical", scrollable.active),
+        (":hover", scrollable.hovered),
+        (":hover-scrollbar", scrollable.hovered_over_scrollbar),
+        (":active", scrollable.dragging),
+        (":horizontal", scrollable.active_horizontal),
+        (":horizontal:hover", scrollable.hovered_horizontal),
+        (
+            ":horizontal:hover-scrollbar",
+            scrollable.hovered_horizontal_over_scrollbar,
+        ),
+        (":horizontal:active", scrollable.dragging_horizontal),
+    ] {
+        write_rule(
+            &mut css,
+            &format!("scrollable{state}"),
+            &[
+                ("background", format_background(scrollbar.background)),
+                (
+                    "border",
+                    format_border(scrollbar.border_width, scrollbar.border_color),
+                ),
+                ("border-radius", format_radius(scrollbar.border_radius)),
+            ],
+        );
+        let scroller = scrollbar.scroller;
+        write_rule(
+            &mut css,
+            &format!("scrollable{state}::scroller"),
+            &[
+                ("color", css_color::format(scroller.color)),
+                (
+                    "border",
+                    format_border(scroller.border_width, scroller.border_color),
+                ),
+                ("border-radius", format_radius(scroller.border_radius)),
+            ],
+        );
+    }
+
+    if let Some(color) = styles.text.appearance.color {
+        write_rule(&mut css, "text", &[("color", css_color::format(color))]);
+    }
+    #[cfg(feature = "svg")]
+    if let Some(color) = styles.svg.appearance.color {
+        write_rule(&mut css, "svg", &[("color", css_color::format(color))]);
+    }
+
+    css
+}
+
+fn write_rule(css: &mut String, selector: &str, declarations: &[(&str, String)]) {
+    if !css.is_empty() {
+        css.push('\n');
+    }
+    let _ = writeln!(css, "{selector} {{");
+    for (property, value) in declarations {
+        let _ = writeln!(css, "  {property}: {value};");
+    }
+    css.push_str("}\n");
+}
+
+fn format_background(background: Option<Background>) -> String {
+    match background {
+        Some(background) => css_color::format(background_color(background)),
+        None => String::from("none"),
+    }
+}
+
+fn format_border(width: f32, color: Color) -> String {
+    format!("{width}px solid {}", css_color::format(color))
+}
+
+fn format_radius(radius: BorderRadius) -> String {
+    let radii: [f32; 4] = radius.into();
+    if radii.iter().all(|radius| *radius == radii[0]) {
+        format!("{}px", radii[0])
+    } else {
+        let radii: Vec<_> = radii.iter().map(|radius| format!("{radius}px")).collect();
+        radii.join(" ")
+    }
+}
+
+struct Rule {
+    selectors: Vec<(String, Span)>,
+    declarations: Vec<Declaration>,
+}
+
+struct Declaration {
+    property: String,
+    value: String,
+    span: Span,
+}
+
+pub(crate) fn strip_comments(source: &str) -> Result<String, Diagnostic> {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        let end = rest[start + 2..].find("*/").ok_or_else(|| {
+            let offset = source.len() - rest.len() + start;
+            Diagnostic::new("unterminated comment", Span::new(offset, source.len()))
+        })?;
+        let comment = &rest[start..start + 2 + end + 2];
+        for c in comment.chars() {
+            match c {
+                '\n' => stripped.push('\n'),
+                c => stripped.push_str(&" ".repeat(c.len_utf8())),
+            }
+        }
+        rest = &rest[start + comment.len()..];
+    }
+    stripped.push_str(rest);
+    Ok(stripped)
+}
+
+/// Splits `source` at `separator`, yielding trimmed non-empty pieces with their spans.
+fn split_trimmed(source: &str, offset: usize, separator: char) -> Vec<(&str, Span)> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for piece in source.split(separator) {
+        let trimmed = piece.trim();
+        if !trimmed.is_empty() {
+            let leading = piece.len() - piece.trim_start().len();
+            let begin = offset + start + leading;
+            pieces.push((trimmed, Span::new(begin, begin + trimmed.len())));
+        }
+        start += piece.len() + separator.len_utf8();
+    }
+    pieces
+}
+
+fn parse_rules(source: &str) -> Result<Vec<Rule>, Diagnostic> {
+    // Comments are blanked out byte for byte, so offsets into `source` stay valid.
+    let source = strip_comments(source)?;
+    let mut rules = Vec::new();
+    let mut offset = 0;
+
+    while let Some(open) = source[offset..].find('{') {
+        let open = offset + open;
+        if let Some(close) = source[offset..open].find('}') {
+            let close = offset + close;
+            return Err(Diagnostic::new(
+                "unexpected `}`",
+                Span::new(close, close + 1),
+            ));
+        }
+        let close = source[open + 1..]
+            .find('}')
+            .map(|close| open + 1 + close)
+            .ok_or_else(|| Diagnostic::new("unclosed `{`", Span::new(open, source.len())))?;
+        if let Some(nested) = source[open + 1..close].find('{') {
+            let nested = open + 1 + nested;
+            return Err(Diagnostic::new(
+                "nested blocks are not supported",
+                Span::new(nested, nested + 1),
+            ));
+        }
+
+        let selectors = split_trimmed(&source[offset..open], offset, ',');
+        if selectors.is_empty() {
+            return Err(Diagnostic::new(
+                "missing selector",
+                Span::new(open, open + 1),
+            ));
+        }
+
+        let mut declarations = Vec::new();
+        for (declaration, span) in split_trimmed(&source[open + 1..close], open + 1, ';') {
+            let (property, value) = declaration
+                .split_once(':')
+                .ok_or_else(|| Diagnostic::new("expected `property: value`", span))?;
+            declarations.push(Declaration {
+                property: property.trim().to_string(),
+                value: value.trim().to_string(),
+                span,
+            });
+        }
+
+        rules.push(Rule {
+            selectors: selectors
+                .into_iter()
+                .map(|(selector, span)| (selector.to_string(), span))
+                .collect(),
+            declarations,
+        });
+        offset = close + 1;
+    }
+
+    if let Some(stray) = source[offset..].find(|c: char| !c.is_whitespace()) {
+        let start = offset + stray;
+        return Err(Diagnostic::new(
+            "expected `{` after selector",
+            Span::new(start, source.len()),
+        ));
+    }
+
+    Ok(rules)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Widget {
+    Button,
+    Container,
+    TextInput,
+    Scrollable,
+    Text,
+    Svg,
+}
+
+struct Selector {
+    widget: Widget,
+    class: Option<String>,
+    element: Option<String>,
+    states: Vec<String>,
+    span: Span,
+}
+
+impl Selector {
+    fn specificity(&self) -> (usize, usize) {
+        (
+            self.class.is_some() as usize + self.states.len(),
+            self.element.is_some() as usize,
+        )
+    }
+}
+
+fn parse_selector(selector: &str, span: Span) -> Result<Selector, Diagnostic> {
+    let ident_end = |s: &str| {
+        s.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(s.len())
+    };
+
+    let end = ident_end(selector);
+    let widget = match &selector[..end] {
+        "button" => Widget::Button,
+        "container" => Widget::Container,
+        "text-input" => Widget::TextInput,
+        "scrollable" => Widget::Scrollable,
+        "text" => Widget::Text,
+        "svg" => Widget::Svg,
+        other => {
+            return Err(Diagnostic::new(
+                format!("unsupported selector `{other}`"),
+                span,
+            ))
+        }
+    };
+
+    let mut parsed = Selector {
+        widget,
+        class: None,
+        element: None,
+        states: Vec::new(),
+        span,
+    };
+    let mut rest = &selector[end..];
+    while !rest.is_empty() {
+        let (kind, after) = if let Some(after) = rest.strip_prefix("::") {
+            ("::", after)
+        } else if let Some(after) = rest.strip_prefix(':') {
+            (":", after)
+        } else if let Some(after) = rest.strip_prefix('.') {
+            (".", after)
+        } else {
+            return Err(Diagnostic::new(
+                format!("unsupported selector syntax `{rest}`"),
+                span,
+            ));
+        };
+        let end = ident_end(after);
+        if end == 0 {
+            return Err(Diagnostic::new(
+                format!("expected a name after `{kind}`"),
+                span,
+            ));
+        }
+        let name = after[..end].to_string();
+        match kind {
+            "." if parsed.class.is_none() => parsed.class = Some(name),
+            "::" if parsed.element.is_none() => parsed.element = Some(name),
+            ":" => parsed.states.push(name),
+            _ => {
+                return Err(Diagnostic::new(
+                    format!("only one `{kind}` is supported per selector"),
+                    span,
+                ))
+            }
+        }
+        rest = &after[end..];
+    }
+    Ok(parsed)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Property {
+    Background(Option<Background>),
+    Color(Color),
+    BorderWidth(f32),
+    BorderColor(Color),
+    BorderRadius(BorderRadius),
+    ShadowOffset(Vector),
+    SelectionColor(Color),
+}
+
+fn parse_declaration(declaration: &Declaration) -> Result<Vec<(Property, Span)>, Diagnostic> {
+    let span = declaration.span;
+    let invalid = |what: &str| {
+        Diagnostic::new(
+            format!(
+                "invalid {what} `{}` for `{}`",
+                declaration.value, declaration.property
+            ),
+            span,
+        )
+    };
+    let value = declaration.value.as_str();
+    let color = |value: &str| {
+        css_color::parse(value).map_err(|error| {
+            Diagnostic::new(
+                format!(
+                    "invalid color `{value}` for `{}`: {}",
+                    declaration.property, error.message
+                ),
+                span,
+            )
+        })
+    };
+
+    let properties = match declaration.property.to_ascii_lowercase().as_str() {
+        "background" | "background-color" => {
+            let background = match value.to_ascii_lowercase().as_str() {
+                "none" | "transparent" => None,
+                _ => Some(Background::Color(color(value)?)),
+            };
+            vec![Property::Background(background)]
+        }
+        "color" => vec![Property::Color(color(value)?)],
+        "border" => {
+            let (width, border_color) = parse_border(value).ok_or_else(|| invalid("border"))?;
+            let mut properties = Vec::new();
+            properties.extend(width.map(Property::BorderWidth));
+            if let Some(border_color) = border_color {
+                properties.push(Property::BorderColor(color(border_color)?));
+            }
+            properties
+        }
+        "border-width" => vec![Property::BorderWidth(
+            parse_length(value).ok_or_else(|| invalid("length"))?,
+        )],
+        "border-color" => vec![Property::BorderColor(color(value)?)],
+        "border-radius" => vec![Property::BorderRadius(
+            parse_radius(value).ok_or_else(|| invalid("radius"))?,
+        )],
+        "box-shadow" => {
+            let (offset, complete) = parse_shadow(value).ok_or_else(|| invalid("shadow"))?;
+            if !complete {
+                return Err(Diagnostic::new(
+                    "only the offset of `box-shadow` is supported",
+                    span,
+                ));
+            }
+            vec![Property::ShadowOffset(offset)]
+        }
+        "selection-color" => vec![Property::SelectionColor(color(value)?)],
+        "caret-color" => {
+            return Err(Diagnostic::new(
+                "`caret-color` is not supported, the caret uses the text color",
+                span,
+            ))
+        }
+        property => {
+            return Err(Diagnostic::new(
+                format!("unsupported property `{property}`"),
+                span,
+            ))
+        }
+    };
+
+    Ok(properties
+        .into_iter()
+        .map(|property| (property, span))
+        .collect())
+}
+
+/// Splits at whitespace outside of parentheses, so `1px rgb(0 0 0)` is two words.
+fn words(value: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let (mut depth, mut start) = (0usize, None);
+    for (index, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    words.push(&value[start..index]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(index);
+    }
+    words.extend(start.map(|start| &value[start..]));
+    words
+}
+
+fn parse_length(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let number = value.strip_suffix("px").unwrap_or(value);
+    number
+        .parse::<f32>()
+        .ok()
+        .filter(|length| length.is_finite())
+}
+
+/// Returns the width and the text of the color, if any.
+fn parse_border(value: &str) -> Option<(Option<f32>, Option<&str>)> {
+    let (mut width, mut color) = (None, None);
+    for word in words(value) {
+        match word.to_ascii_lowercase().as_str() {
+            "none" | "hidden" => width = Some(0.0),
+            "solid" => {}
+            _ => match parse_length(word) {
+                Some(length) => width = Some(length),
+                None if color.is_none() => color = Some(word),
+                None => return None,
+            },
+        }
+    }
+    Some((width, color))
+}
+
+fn parse_radius(value: &str) -> Option<BorderRadius> {
+    let radii = value
+        .split_whitespace()
+        .map(parse_length)
+        .collect::<Option<Vec<_>>>()?;
+    match radii.as_slice() {
+        [all] => Some(BorderRadius::from(*all)),
+        [a, b] => Some(BorderRadius::from([*a, *b, *a, *b])),
+        [a, b, c] => Some(BorderRadius::from([*a, *b, *c, *b])),
+        [a, b, c, d] => Some(BorderRadius::from([*a, *b, *c, *d])),
+        _ => None,
+    }
+}
+
+/// Returns the offset, and whether the shadow had nothing else (blur, spread, color) set.
+fn parse_shadow(value: &str) -> Option<(Vector, bool)> {
+    let parts: Vec<_> = value.split_whitespace().collect();
+    let (x, y) = (parse_length(parts.first()?)?, parse_length(parts.get(1)?)?);
+    Some((Vector::new(x, y), parts.len() == 2))
+}
+
+fn apply(
+    styles: &mut StyleSet,
+    selector: &Selector,
+    properties: &[(Property, Span)],
+    warnings: &mut Vec<Diagnostic>,
+) {
+    let unsupported_state = || {
+        Diagnostic::new(
+            format!("unsupported pseudo-class `:{}`", selector.states.join(":")),
+            selector.span,
+        )
+    };
+    let unsupported_class =
+        |class: &str| Diagnostic::new(format!("unknown class `.{class}`"), selector.span);
+    let mut unsupported = Vec::new();
+
+    match selector.widget {
+        Widget::Button => {
+            if let Some(element) = &selector.element {
+                return warnings.push(unsupported_element(element, selector.span));
+            }
+            let states: Vec<_> = selector.states.iter().map(String::as_str).collect();
+            let select: fn(CustomButtonStyle) -> CustomButtonStyle = match states.as_slice() {
+                [] => CustomButtonStyle::all,
+                ["hover"] => CustomButtonStyle::hovered,
+                ["active"] => CustomButtonStyle::pressed,
+                ["disabled"] => CustomButtonStyle::disabled,
+                _ => return warnings.push(unsupported_state()),
+            };
+            let mut matched = false;
+            for (name, button) in styles.buttons_mut() {
+                if selector.class.as_deref().is_some_and(|class| class != name) {
+                    continue;
+                }
+                unsupported.clear();
+                let mut style = select(*button);
+                for (property, span) in properties {
+                    style = match *property {
+                        Property::Background(background) => style.background(background),
+                        Property::Color(color) => style.text_color(color),
+                        Property::BorderWidth(width) => style.border_width(width),
+                        Property::BorderColor(color) => style.border_color(color),
+                        Property::BorderRadius(radius) => style.border_radius(radius),
+                        Property::ShadowOffset(offset) => style.shadow_offset(offset),
+                        Property::SelectionColor(_) => {
+                            unsupported.push(*span);
+                            style
+                        }
+                    };
+                }
+                *button = style.active();
+                matched = true;
+            }
+            if !matched {
+                return warnings.push(unsupported_class(selector.class.as_deref().unwrap_or("")));
+            }
+        }
+        Widget::Container => {
+            if let Some(element) = &selector.element {
+                return warnings.push(unsupported_element(element, selector.span));
+            }
+            if !selector.states.is_empty() {
+                return warnings.push(unsupported_state());
+            }
+            let mut matched = false;
+            for (name, container) in styles.containers_mut() {
+                if selector.class.as_deref().is_some_and(|class| class != name) {
+                    continue;
+                }
+                unsupported.clear();
+                let mut style: CustomContainerStyle = *container;
+                for (property, span) in properties {
+                    style = match *property {
+                        Property::Background(background) => style.background(background),
+                        Property::Color(color) => style.text_color(Some(color)),
+                        Property::BorderWidth(width) => style.border_width(width),
+                        Property::BorderColor(color) => style.border_color(color),
+                        Property::BorderRadius(radius) => style.border_radius(radius),
+                        Property::ShadowOffset(_) | Property::SelectionColor(_) => {
+                            unsupported.push(*span);
+                            style
+                        }
+                    };
+                }
+                *container = style;
+                matched = true;
+            }
+            if !matched {
+                return warnings.push(unsupported_class(selector.class.as_deref().unwrap_or("")));
+            }
+        }
+        Widget::TextInput => {
+            if let Some(class) = &selector.class {
+                return warnings.push(unsupported_class(class));
+            }
+            let states: Vec<_> = selector.states.iter().map(String::as_str).collect();
+            let mut style = styles.text_input;
+            match selector.element.as_deref() {
+                None => {
+                    let select: fn(CustomTextInputStyle) -> CustomTextInputStyle =
+                        match states.as_slice() {
+                            [] => CustomTextInputStyle::all,
+                            ["hover"] => CustomTextInputStyle::hovered,
+                            ["focus"] => CustomTextInputStyle::focused,
+                            ["disabled"] => CustomTextInputStyle::disabled,
+                            _ => return warnings.push(unsupported_state()),
+                        };
+                    style = select(style);
+                    for (property, span) in properties {
+                        style = match (*property, states.as_slice()) {
+                            (Property::Background(background), _) => style.background(
+                                background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+                            ),
+                            (Property::Color(color), []) => style.value_color(color),
+                            (Property::Color(color), ["disabled"]) => style.disabled_color(color),
+                            (Property::BorderWidth(width), _) => style.border_width(width),
+                            (Property::BorderColor(color), _) => style.border_color(color),
+                            (Property::BorderRadius(radius), _) => style.border_radius(radius),
+                            (Property::SelectionColor(color), _) => style.selection_color(color),
+                            (Property::Color(_), _) | (Property::ShadowOffset(_), _) => {
+                                unsupported.push(*span);
+                                style
+                            }
+                        };
+                    }
+                    style = style.active();
+                }
+                Some(element @ ("placeholder" | "selection")) => {
+                    if !states.is_empty() {
+                        return warnings.push(unsupported_state());
+                    }
+                    for (property, span) in properties {
+                        style = match (*property, element) {
+                            (Property::Color(color), "placeholder") => {
+                                style.placeholder_color(color)
+                            }
+                            (Property::Background(Some(Background::Color(color))), "selection")
+                            | (Property::Color(color), "selection") => style.selection_color(color),
+                            _ => {
+                                unsupported.push(*span);
+                                style
+                            }
+                        };
+                    }
+                }
+                Some(element) => return warnings.push(unsupported_element(element, selector.span)),
+            }
+            styles.text_input = style;
+        }
+        Widget::Scrollable => {
+            if let Some(class) = &selector.class {
+                return warnings.push(unsupported_class(class));
+            }
+            let mut states: Vec<_> = selector.states.iter().map(String::as_str).collect();
+            let horizontal = states.contains(&"horizontal");
+            states.retain(|state| *state != "horizontal" && *state != "vertical");
+            let select: fn(CustomScrollableStyle) -> CustomScrollableStyle =
+                match (horizontal, states.as_slice()) {
+                    (false, []) if selector.states.is_empty() => CustomScrollableStyle::all,
+                    (false, []) => CustomScrollableStyle::active,
+                    (false, ["hover"]) => CustomScrollableStyle::hovered,
+                    (false, ["hover-scrollbar"]) => CustomScrollableStyle::hovered_over_scrollbar,
+                    (false, ["active"]) => CustomScrollableStyle::dragging,
+                    (true, []) => CustomScrollableStyle::active_horizontal,
+                    (true, ["hover"]) => CustomScrollableStyle::hovered_horizontal,
+                    (true, ["hover-scrollbar"]) => {
+                        CustomScrollableStyle::hovered_horizontal_over_scrollbar
+                    }
+                    (true, ["active"]) => CustomScrollableStyle::dragging_horizontal,
+                    _ => return warnings.push(unsupported_state()),
+                };
+            let scroller = match selector.element.as_deref() {
+                None => false,
+                Some("scroller") => true,
+                Some(element) => return warnings.push(unsupported_element(element, selector.span)),
+            };
+
+            let mut style = select(styles.scrollable);
+            for (property, span) in properties {
+                style = match (*property, scroller) {
+                    (Property::Background(background), false) => style.background(background),
+                    (Property::Background(Some(Background::Color(color))), true)
+                    | (Property::Color(color), true) => style.scroller_color(color),
+                    (Property::BorderWidth(width), false) => style.border_width(width),
+                    (Property::BorderWidth(width), true) => style.scroller_border_width(width),
+                    (Property::BorderColor(color), false) => style.border_color(color),
+                    (Property::BorderColor(color), true) => style.scroller_border_color(color),
+                    (Property::BorderRadius(radius), false) => style.border_radius(radius),
+                    (Property::BorderRadius(radius), true) => style.scroller_border_radius(radius),
+                    _ => {
+                        unsupported.push(*span);
+                        style
+                    }
+                };
+            }
+            styles.scrollable = style.active();
+        }
+        Widget::Text | Widget::Svg => {
+            if let Some(class) = &selector.class {
+                return warnings.push(unsupported_class(class));
+            }
+            if let Some(element) = &selector.element {
+                return warnings.push(unsupported_element(element, selector.span));
+            }
+            if !selector.states.is_empty() {
+                return warnings.push(unsupported_state());
+            }
+            for (property, span) in properties {
+                match (*property, selector.widget) {
+                    (Property::Color(color), Widget::Text) => {
+                        styles.text = styles.text.color(color);
+                    }
+                    #[cfg(feature = "svg")]
+                    (Property::Color(color), Widget::Svg) => {
+                        styles.svg = styles.svg.color(Some(color));
+                    }
+                    _ => unsupported.push(*span),
+                }
+            }
+        }
+    }
+
+    warnings.extend(unsupported.into_iter().map(|span| {
+        Diagnostic::new(
+            format!("property is not supported on `{}`", parsedname(selector)),
+            span,
+        )
+    }));
+}
+
+fn unsupported_element(element: &str, span: Span) -> Diagnostic {
+    Diagnostic::new(format!("unsupported pseudo-element `::{element}`"), span)
+}
+
+fn parsedname(selector: &Selector) -> String {
+    let widget = match selector.widget {
+        Widget::Button => "button",
+        Widget::Container => "container",
+        Widget::TextInput => "text-input",
+        Widget::Scrollable => "scrollable",
+        Widget::Text => "text",
+        Widget::Svg => "svg",
+    };
+    let mut name = widget.to_string();
+    if let Some(class) = &selector.class {
+        name.push('.');
+        name.push_str(class);
+    }
+    if let Some(element) = &selector.element {
+        name.push_str("::");
+        name.push_str(element);
+    }
+    for state in &selector.states {
+        name.push(':');
+        name.push_str(state);
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::Theme;
+
+    const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
+    const BLUE: Color = Color::from_rgb(0.0, 0.0, 1.0);
+
+    #[test]
+    fn more_specific_rules_win_regardless_of_order() {
+        let source = "button.primary:hover { color: #f00 }\nbutton { color: #00f }";
+        let imported = parse(source, &Theme::Dark).unwrap();
+        let button = imported.styles.primary_button;
+
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+        assert_eq!(button.hovered.text_color, RED);
+        assert_eq!(button.active.text_color, BLUE);
+        assert_eq!(imported.styles.secondary_button.hovered.text_color, BLUE);
+    }
+
+    #[test]
+    fn later_rules_win_at_equal_specificity() {
+        let source = "button { color: #f00 }\nbutton { color: #00f }";
+        let imported = parse(source, &Theme::Light).unwrap();
+        assert_eq!(imported.styles.primary_button.pressed.text_color, BLUE);
+    }
+
+    #[test]
+    fn unknown_properties_warn_at_their_declaration() {
+        let source = "button {\n  color: #f00;\n  letter-spacing: 2px;\n}\n";
+        let imported = parse(source, &Theme::Dark).unwrap();
+
+        let [warning] = imported.warnings.as_slice() else {
+            panic!("expected one warning, got {:?}", imported.warnings);
+        };
+        assert_eq!(warning.message, "unsupported property `letter-spacing`");
+        assert_eq!(warning.span.line_col(source), (3, 3));
+        assert_eq!(
+            &source[warning.span.start..warning.span.end],
+            "letter-spacing: 2px"
+        );
+        assert_eq!(imported.styles.primary_button.active.text_color, RED);
+    }
+
+    #[test]
+    fn unsupported_selectors_warn_and_are_skipped() {
+        let source = "/* a comment */ slider:hover { color: #f00 }";
+        let imported = parse(source, &Theme::Dark).unwrap();
+
+        let [warning] = imported.warnings.as_slice() else {
+            panic!("expected one warning, got {:?}", imported.warnings);
+        };
+        assert_eq!(warning.message, "unsupported selector `slider`");
+        assert_eq!(warning.span.line_col(source), (1, 17));
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_offending_token() {
+        let error = |source: &str| parse(source, &Theme::Dark).err().unwrap();
+
+        let unclosed = error("button { color: #f00");
+        assert_eq!(unclosed.message, "unclosed `{`");
+        assert_eq!(unclosed.span, Span::new(7, 20));
+
+        let stray = error("button { color: #f00 }\n}");
+        assert_eq!(stray.message, "expected `{` after selector");
+        assert_eq!(stray.span.line_col("button { color: #f00 }\n}"), (2, 1));
+
+        let comment = error("button { color: #f00 } /* open");
+        assert_eq!(comment.message, "unterminated comment");
+        assert_eq!(comment.span, Span::new(23, 30));
+
+        let declaration = error("button { color }");
+        assert_eq!(declaration.message, "expected `property: value`");
+        assert_eq!(declaration.span, Span::new(9, 14));
+    }
+
+    #[test]
+    fn invalid_values_warn_with_the_color_error() {
+        let source = "container { background: #12345 }";
+        let imported = parse(source, &Theme::Dark).unwrap();
+
+        let [warning] = imported.warnings.as_slice() else {
+            panic!("expected one warning, got {:?}", imported.warnings);
+        };
+        assert!(
+            warning
+                .message
+                .starts_with("invalid color `#12345` for `background`"),
+            "{}",
+            warning.message
+        );
+        assert_eq!(warning.span, Span::new(12, 30));
+    }
+
+    #[test]
+    fn written_styles_parse_back_to_the_same_styles() {
+        for theme in [Theme::Light, Theme::Dark] {
+            let mut styles = StyleSet::from_theme(&theme);
+            styles.primary_button = styles
+                .primary_button
+                .hovered()
+                .background_color(RED)
+                .border_width(2.0)
+                .border_radius([1.0, 2.0, 3.0, 4.0])
+                .active();
+            styles.scrollable = styles
+                .scrollable
+                .hovered_horizontal_over_scrollbar()
+                .scroller_color(BLUE)
+                .active();
+
+            let css = write(&styles);
+            let imported = parse(&css, &theme).unwrap();
+            assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+            assert_eq!(write(&imported.styles), css);
+        }
+    }
+}
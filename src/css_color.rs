@@ -0,0 +1,636 @@
+//! CSS color syntax: hex, `rgb()`, `hsl()`, `oklch()`, `color(srgb ...)` and named colors.
+//!
+//! Both the legacy comma-separated and the space-separated forms of the functions are accepted.
+//! As in CSS, components outside their range are clamped. Errors carry spans into the parsed
+//! text. Every color setter of the styles has a `*_str` variant that takes this syntax, e.g.
+//! `.border_color_str("hsl(210 40% 50%)")?`.
+
+use crate::color::{from_hsl, from_oklch, rgb};
+use crate::diagnostic::{Diagnostic, Span};
+use iced::Color;
+
+pub fn parse(text: &str) -> Result<Color, Diagnostic> {
+    let start = text.len() - text.trim_start().len();
+    let trimmed = text.trim();
+    let span = Span::new(start, start + trimmed.len());
+    // Everything is case-insensitive, and lowercasing ASCII keeps byte offsets intact.
+    let value = trimmed.to_ascii_lowercase();
+
+    if value.is_empty() {
+        return Err(Diagnostic::new("expected a color", span));
+    }
+    if let Some(digits) = value.strip_prefix('#') {
+        return parse_hex(digits, start + 1);
+    }
+    if let Some(open) = value.find('(') {
+        let Some(arguments) = value[open + 1..].strip_suffix(')') else {
+            return Err(Diagnostic::new(
+                "missing `)`",
+                Span::new(span.end, span.end),
+            ));
+        };
+        return parse_function(&value[..open], arguments, start + open + 1, span);
+    }
+    match value.as_str() {
+        "transparent" => Ok(Color::TRANSPARENT),
+        "currentcolor" => Err(Diagnostic::new(
+            "`currentcolor` depends on where the color is used",
+            span,
+        )),
+        name => NAMED_COLORS
+            .binary_search_by_key(&name, |(name, _)| name)
+            .map(|index| rgb(NAMED_COLORS[index].1))
+            .map_err(|_| Diagnostic::new(format!("unknown color `{trimmed}`"), span)),
+    }
+}
+
+/// Formats a color so that [`parse`] returns it unchanged: as hex when every channel is a
+/// whole 8-bit value, otherwise as `color(srgb ...)` with the exact components.
+pub fn format(color: Color) -> String {
+    let channels = [color.r, color.g, color.b, color.a];
+    let bytes = channels.map(|channel| (channel * 255.0).round());
+    let exact = channels
+        .iter()
+        .zip(bytes)
+        .all(|(channel, byte)| (0.0..=255.0).contains(&byte) && byte / 255.0 == *channel);
+
+    if exact {
+        let [r, g, b, a] = bytes.map(|byte| byte as u8);
+        if a == 255 {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    } else if color.a == 1.0 {
+        format!("color(srgb {} {} {})", color.r, color.g, color.b)
+    } else {
+        format!(
+            "color(srgb {} {} {} / {})",
+            color.r, color.g, color.b, color.a
+        )
+    }
+}
+
+fn parse_hex(digits: &str, offset: usize) -> Result<Color, Diagnostic> {
+    if let Some((index, c)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        let start = offset + index;
+        return Err(Diagnostic::new(
+            format!("invalid hex digit `{c}`"),
+            Span::new(start, start + c.len_utf8()),
+        ));
+    }
+    crate::color::parse_hex(digits).ok_or_else(|| {
+        Diagnostic::new(
+            format!("expected 3, 4, 6 or 8 hex digits, found {}", digits.len()),
+            Span::new(offset - 1, offset + digits.len()),
+        )
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Component {
+    Number(f32),
+    Percentage(f32),
+    /// In degrees.
+    Angle(f32),
+}
+
+struct Argument<'a> {
+    text: &'a str,
+    span: Span,
+}
+
+impl Argument<'_> {
+    fn parse(&self) -> Result<Component, Diagnostic> {
+        let invalid = || Diagnostic::new(format!("invalid number `{}`", self.text), self.span);
+        let number = |text: &str| {
+            text.parse::<f32>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .ok_or_else(invalid)
+        };
+
+        if self.text == "none" {
+            return Ok(Component::Number(0.0));
+        }
+        if let Some(percentage) = self.text.strip_suffix('%') {
+            return Ok(Component::Percentage(number(percentage)?));
+        }
+        for (unit, degrees) in [
+            ("deg", 1.0),
+            ("grad", 0.9),
+            ("rad", 180.0 / std::f32::consts::PI),
+            ("turn", 360.0),
+        ] {
+            if let Some(angle) = self.text.strip_suffix(unit) {
+                return Ok(Component::Angle(number(angle)? * degrees));
+            }
+        }
+        Ok(Component::Number(number(self.text)?))
+    }
+
+    fn wrong(&self, what: &str) -> Diagnostic {
+        Diagnostic::new(format!("`{}` is not a valid {what}", self.text), self.span)
+    }
+
+    /// A number on the given scale or a percentage of it, as a fraction of the scale.
+    fn fraction(&self, scale: f32, what: &str) -> Result<f32, Diagnostic> {
+        match self.parse()? {
+            Component::Number(number) => Ok((number / scale).clamp(0.0, 1.0)),
+            Component::Percentage(percentage) => Ok((percentage / 100.0).clamp(0.0, 1.0)),
+            Component::Angle(_) => Err(self.wrong(what)),
+        }
+    }
+
+    fn hue(&self) -> Result<f32, Diagnostic> {
+        match self.parse()? {
+            Component::Number(degrees) | Component::Angle(degrees) => Ok(degrees),
+            Component::Percentage(_) => Err(self.wrong("hue")),
+        }
+    }
+}
+
+/// Splits `text`, which starts at `offset`, into trimmed pieces. Without a separator it splits
+/// at whitespace and skips empty pieces.
+fn pieces(text: &str, offset: usize, separator: Option<char>) -> Vec<Argument<'_>> {
+    let split: Vec<&str> = match separator {
+        Some(separator) => text.split(separator).collect(),
+        None => text.split(|c: char| c.is_ascii_whitespace()).collect(),
+    };
+    let mut pieces = Vec::new();
+    let mut start = offset;
+    for piece in split {
+        let trimmed = piece.trim();
+        let begin = start + piece.len() - piece.trim_start().len();
+        start += piece.len() + 1;
+        if separator.is_some() || !trimmed.is_empty() {
+            pieces.push(Argument {
+                text: trimmed,
+                span: Span::new(begin, begin + trimmed.len()),
+            });
+        }
+    }
+    pieces
+}
+
+/// Splits the arguments of a color function into its components and optional alpha.
+fn split_arguments(
+    arguments: &str,
+    offset: usize,
+) -> Result<(Vec<Argument<'_>>, Option<Argument<'_>>), Diagnostic> {
+    if arguments.contains(',') {
+        if let Some(slash) = arguments.find('/') {
+            let start = offset + slash;
+            return Err(Diagnostic::new(
+                "`/` cannot be combined with commas",
+                Span::new(start, start + 1),
+            ));
+        }
+        let mut components = pieces(arguments, offset, Some(','));
+        if let Some(empty) = components.iter().find(|argument| argument.text.is_empty()) {
+            return Err(Diagnostic::new("missing argument", empty.span));
+        }
+        let alpha = (components.len() == 4).then(|| components.remove(3));
+        return Ok((components, alpha));
+    }
+
+    match arguments.split_once('/') {
+        Some((components, alpha)) => {
+            let alpha_offset = offset + components.len() + 1;
+            let mut alphas = pieces(alpha, alpha_offset, None);
+            if alphas.len() != 1 {
+                let end = offset + arguments.len();
+                return Err(Diagnostic::new(
+                    "expected exactly one alpha value after `/`",
+                    Span::new(alpha_offset, end),
+                ));
+            }
+            Ok((pieces(components, offset, None), alphas.pop()))
+        }
+        None => Ok((pieces(arguments, offset, None), None)),
+    }
+}
+
+fn parse_function(
+    name: &str,
+    arguments: &str,
+    offset: usize,
+    span: Span,
+) -> Result<Color, Diagnostic> {
+    let (components, alpha) = split_arguments(arguments, offset)?;
+    let alpha = match &alpha {
+        Some(alpha) => alpha.fraction(1.0, "alpha")?,
+        None => 1.0,
+    };
+    let expect = |count: usize| {
+        if components.len() == count {
+            Ok(())
+        } else {
+            Err(Diagnostic::new(
+                format!(
+                    "`{name}()` takes {count} components and an optional alpha, found {}",
+                    components.len()
+                ),
+                span,
+            ))
+        }
+    };
+
+    match name.trim() {
+        "rgb" | "rgba" => {
+            expect(3)?;
+            let channel = |index: usize| components[index].fraction(255.0, "channel");
+            Ok(Color::from_rgba(
+                channel(0)?,
+                channel(1)?,
+                channel(2)?,
+                alpha,
+            ))
+        }
+        "hsl" | "hsla" => {
+            expect(3)?;
+            Ok(from_hsl(
+                [
+                    components[0].hue()?,
+                    components[1].fraction(100.0, "saturation")?,
+                    components[2].fraction(100.0, "lightness")?,
+                ],
+                alpha,
+            ))
+        }
+        "oklch" => {
+            expect(3)?;
+            // 100% chroma is 0.4, and chroma is not clamped from above.
+            let chroma = match components[1].parse()? {
+                Component::Number(chroma) => chroma.max(0.0),
+                Component::Percentage(percentage) => (percentage / 100.0 * 0.4).max(0.0),
+                Component::Angle(_) => return Err(components[1].wrong("chroma")),
+            };
+            Ok(from_oklch(
+                [
+                    components[0].fraction(1.0, "lightness")?,
+                    chroma,
+                    components[2].hue()?,
+                ],
+                alpha,
+            ))
+        }
+        "color" => {
+            let Some((space, channels)) = components.split_first() else {
+                return Err(Diagnostic::new("missing color space", span));
+            };
+            if space.text != "srgb" {
+                return Err(Diagnostic::new(
+                    format!("unsupported color space `{}`, only `srgb` is", space.text),
+                    space.span,
+                ));
+            }
+            let [r, g, b] = channels else {
+                return Err(Diagnostic::new(
+                    format!(
+                        "`color(srgb)` takes 3 components and an optional alpha, found {}",
+                        channels.len()
+                    ),
+                    span,
+                ));
+            };
+            Ok(Color::from_rgba(
+                r.fraction(1.0, "channel")?,
+                g.fraction(1.0, "channel")?,
+                b.fraction(1.0, "channel")?,
+                alpha,
+            ))
+        }
+        name => Err(Diagnostic::new(
+            format!("unknown color function `{name}()`"),
+            Span::new(span.start, offset - 1),
+        )),
+    }
+}
+
+/// The CSS named colors, sorted by name.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trips(text: &str, expected: Color) {
+        let color = parse(text).unwrap();
+        let close = [
+            (color.r, expected.r),
+            (color.g, expected.g),
+            (color.b, expected.b),
+            (color.a, expected.a),
+        ]
+        .iter()
+        .all(|(a, b)| (a - b).abs() < 0.002);
+        assert!(close, "`{text}` parsed to {color:?}, expected {expected:?}");
+
+        let formatted = format(color);
+        assert_eq!(
+            parse(&formatted).unwrap(),
+            color,
+            "`{text}` formatted as `{formatted}`"
+        );
+    }
+
+    #[test]
+    fn hex_round_trips() {
+        assert_round_trips("#0af", rgb(0x00aaff));
+        assert_round_trips(
+            "#0af8",
+            Color::from_rgba8(0x00, 0xaa, 0xff, 0x88 as f32 / 255.0),
+        );
+        assert_round_trips("#1E66F5", rgb(0x1e66f5));
+        assert_round_trips(
+            "#1e66f580",
+            Color::from_rgba8(0x1e, 0x66, 0xf5, 128.0 / 255.0),
+        );
+        assert_eq!(format(rgb(0x1e66f5)), "#1e66f5");
+        assert_eq!(format(Color::TRANSPARENT), "#00000000");
+    }
+
+    #[test]
+    fn rgb_round_trips() {
+        let expected = Color::from_rgba8(30, 102, 245, 0.5);
+        assert_round_trips("rgb(30 102 245 / 50%)", expected);
+        assert_round_trips("rgba(30, 102, 245, 0.5)", expected);
+        assert_round_trips("RGB(11.76% 40% 96.08% / .5)", expected);
+        assert_round_trips("rgb(300 -5 none)", Color::from_rgb(1.0, 0.0, 0.0));
+        assert_round_trips(
+            "rgb(0.5 0.5 0.5)",
+            Color::from_rgb(0.5 / 255.0, 0.5 / 255.0, 0.5 / 255.0),
+        );
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        assert_round_trips("hsl(120 100% 25%)", Color::from_rgb(0.0, 0.5, 0.0));
+        assert_round_trips(
+            "hsla(0.5turn, 100%, 50%, 0.25)",
+            Color::from_rgba(0.0, 1.0, 1.0, 0.25),
+        );
+        assert_round_trips("hsl(-120deg 100% 50%)", Color::from_rgb(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn oklch_round_trips() {
+        assert_round_trips("oklch(1 0 0)", Color::WHITE);
+        assert_round_trips("oklch(0% 0 0 / 0.5)", Color::from_rgba(0.0, 0.0, 0.0, 0.5));
+        assert_round_trips("oklch(62.8% 0.2577 29.23)", Color::from_rgb(1.0, 0.0, 0.0));
+        assert_round_trips("oklch(45.2% 78.25% 264.05)", Color::from_rgb(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn named_colors_round_trip() {
+        assert_round_trips("rebeccapurple", rgb(0x663399));
+        assert_round_trips("  CornflowerBlue ", rgb(0x6495ed));
+        assert_round_trips("transparent", Color::TRANSPARENT);
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(NAMED_COLORS.len(), 148);
+    }
+
+    #[test]
+    fn formats_inexact_colors_exactly() {
+        let color = Color::from_rgba(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(format(color), "color(srgb 0.1 0.2 0.3 / 0.4)");
+        assert_eq!(parse(&format(color)).unwrap(), color);
+        assert_eq!(
+            format(Color::from_rgb(0.1, 0.2, 0.3)),
+            "color(srgb 0.1 0.2 0.3)"
+        );
+    }
+
+    #[test]
+    fn errors_explain_and_point_at_the_problem() {
+        fn error(text: &str) -> (String, &str) {
+            let error = parse(text).unwrap_err();
+            (error.message, &text[error.span.start..error.span.end])
+        }
+
+        assert_eq!(error("#12g4"), ("invalid hex digit `g`".into(), "g"));
+        assert_eq!(
+            error("#12345"),
+            ("expected 3, 4, 6 or 8 hex digits, found 5".into(), "#12345")
+        );
+        assert_eq!(
+            error(" reddish"),
+            ("unknown color `reddish`".into(), "reddish")
+        );
+        assert_eq!(error(""), ("expected a color".into(), ""));
+        assert_eq!(error("rgb(1 2 3"), ("missing `)`".into(), ""));
+        assert_eq!(
+            error("rgb(1 2)"),
+            (
+                "`rgb()` takes 3 components and an optional alpha, found 2".into(),
+                "rgb(1 2)"
+            )
+        );
+        assert_eq!(
+            error("rgb(1, 2, 3 / 1)"),
+            ("`/` cannot be combined with commas".into(), "/")
+        );
+        assert_eq!(error("rgb(1,,3)"), ("missing argument".into(), ""));
+        assert_eq!(
+            error("rgb(1 2 3 / 1 2)"),
+            ("expected exactly one alpha value after `/`".into(), " 1 2")
+        );
+        assert_eq!(error("rgb(1 2 x)"), ("invalid number `x`".into(), "x"));
+        assert_eq!(
+            error("rgb(1 2 3deg)"),
+            ("`3deg` is not a valid channel".into(), "3deg")
+        );
+        assert_eq!(
+            error("hsl(10% 50% 50%)"),
+            ("`10%` is not a valid hue".into(), "10%")
+        );
+        assert_eq!(
+            error("color(display-p3 1 0 0)"),
+            (
+                "unsupported color space `display-p3`, only `srgb` is".into(),
+                "display-p3"
+            )
+        );
+        assert_eq!(
+            error("lab(50 0 0)"),
+            ("unknown color function `lab()`".into(), "lab")
+        );
+        assert_eq!(
+            error("currentColor"),
+            (
+                "`currentcolor` depends on where the color is used".into(),
+                "currentColor"
+            )
+        );
+    }
+
+    #[test]
+    fn setters_accept_css_colors() {
+        use crate::styles::button::CustomButtonStyle;
+        use crate::styles::container::CustomContainerStyle;
+
+        let button = CustomButtonStyle::new()
+            .hovered()
+            .border_color_str("rgb(255 0 0)")
+            .unwrap();
+        assert_eq!(button.hovered.border_color, Color::from_rgb(1.0, 0.0, 0.0));
+
+        let container = CustomContainerStyle::new().text_color_str("white").unwrap();
+        assert_eq!(container.appearance.text_color, Some(Color::WHITE));
+
+        let error = CustomContainerStyle::new()
+            .background_color_str("#ff")
+            .unwrap_err();
+        assert_eq!(error.message, "expected 3, 4, 6 or 8 hex digits, found 2");
+    }
+}
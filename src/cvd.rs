@@ -0,0 +1,333 @@
+use crate::color::{from_linear, oklab_distance, to_linear};
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use iced::widget::{button, scrollable, text_input};
+use iced::{Background, Color};
+
+/// Colors closer than this in OKLab are treated as the same color.
+pub const INDISTINGUISHABLE_DISTANCE: f32 = 0.02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+    Achromatopsia,
+}
+
+impl Deficiency {
+    pub const ALL: [Deficiency; 4] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+        Deficiency::Achromatopsia,
+    ];
+
+    /// Simulates the deficiency at full severity. Dichromacies use the Machado, Oliveira and
+    /// Fernandes (2009) matrices, achromatopsia keeps only the relative luminance.
+    pub fn simulate(self, color: Color) -> Color {
+        let rgb = [to_linear(color.r), to_linear(color.g), to_linear(color.b)];
+        let matrix = match self {
+            Deficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+            Deficiency::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        };
+        let [r, g, b] = matrix.map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]);
+
+        Color {
+            r: from_linear(r),
+            g: from_linear(g),
+            b: from_linear(b),
+            a: color.a,
+        }
+    }
+}
+
+/// Two states or styles that can be told apart with normal vision but not under `deficiency`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub deficiency: Deficiency,
+    pub first: String,
+    pub second: String,
+}
+
+fn same_color(a: Color, b: Color) -> bool {
+    oklab_distance(a, b) < INDISTINGUISHABLE_DISTANCE && (a.a - b.a).abs() < 0.05
+}
+
+fn same_background(a: Option<Background>, b: Option<Background>) -> bool {
+    let color = |background: Option<Background>| {
+        background.map_or(Color::TRANSPARENT, crate::color::background_color)
+    };
+    same_color(color(a), color(b))
+}
+
+fn same_button(a: &button::Appearance, b: &button::Appearance) -> bool {
+    a.shadow_offset == b.shadow_offset
+        && a.border_radius == b.border_radius
+        && a.border_width == b.border_width
+        && same_background(a.background, b.background)
+        && same_color(a.text_color, b.text_color)
+        && (a.border_width == 0.0 || same_color(a.border_color, b.border_color))
+}
+
+fn same_text_input(a: &text_input::Appearance, b: &text_input::Appearance) -> bool {
+    a.border_radius == b.border_radius
+        && a.border_width == b.border_width
+        && same_background(Some(a.background), Some(b.background))
+        && same_color(a.icon_color, b.icon_color)
+        && (a.border_width == 0.0 || same_color(a.border_color, b.border_color))
+}
+
+fn same_scrollbar(a: &scrollable::Scrollbar, b: &scrollable::Scrollbar) -> bool {
+    a.border_radius == b.border_radius
+        && a.border_width == b.border_width
+        && a.scroller.border_radius == b.scroller.border_radius
+        && a.scroller.border_width == b.scroller.border_width
+        && same_background(a.background, b.background)
+        && same_color(a.scroller.color, b.scroller.color)
+        && (a.border_width == 0.0 || same_color(a.border_color, b.border_color))
+        && (a.scroller.border_width == 0.0
+            || same_color(a.scroller.border_color, b.scroller.border_color))
+}
+
+fn collisions<T>(
+    states: impl Fn(Option<Deficiency>) -> Vec<(String, T)>,
+    pairs: &[(usize, usize)],
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<Collision> {
+    let original = states(None);
+    let simulated = Deficiency::ALL.map(|deficiency| (deficiency, states(Some(deficiency))));
+
+    let mut collisions = Vec::new();
+    for &(first, second) in pairs {
+        if same(&original[first].1, &original[second].1) {
+            continue;
+        }
+        for (deficiency, states) in &simulated {
+            if same(&states[first].1, &states[second].1) {
+                collisions.push(Collision {
+                    deficiency: *deficiency,
+                    first: original[first].0.clone(),
+                    second: original[second].0.clone(),
+                });
+            }
+        }
+    }
+    collisions
+}
+
+fn all_pairs(len: usize) -> Vec<(usize, usize)> {
+    (0..len)
+        .flat_map(|first| (first + 1..len).map(move |second| (first, second)))
+        .collect()
+}
+
+fn simulated<T: Copy>(
+    style: &T,
+    deficiency: Option<Deficiency>,
+    f: impl Fn(T, Deficiency) -> T,
+) -> T {
+    deficiency.map_or(*style, |deficiency| f(*style, deficiency))
+}
+
+/// Checks every pair of states within each button, and the same state across different buttons,
+/// e.g. `positive.active` against `destructive.active`.
+pub fn button_collisions(buttons: &[(&str, &CustomButtonStyle)]) -> Vec<Collision> {
+    let states = |deficiency| {
+        let mut states = Vec::new();
+        for (name, style) in buttons {
+            let style = simulated(*style, deficiency, CustomButtonStyle::simulate_deficiency);
+            for (state, appearance) in [
+                ("active", style.active),
+                ("hovered", style.hovered),
+                ("pressed", style.pressed),
+                ("disabled", style.disabled),
+            ] {
+                states.push((format!("{name}.{state}"), appearance));
+            }
+        }
+        states
+    };
+
+    let mut pairs = Vec::new();
+    for button in 0..buttons.len() {
+        pairs.extend(
+            all_pairs(4)
+                .into_iter()
+                .map(|(a, b)| (button * 4 + a, button * 4 + b)),
+        );
+    }
+    for (a, b) in all_pairs(buttons.len()) {
+        pairs.extend((0..4).map(|state| (a * 4 + state, b * 4 + state)));
+    }
+
+    collisions(states, &pairs, same_button)
+}
+
+pub fn text_input_collisions(style: &CustomTextInputStyle) -> Vec<Collision> {
+    let states = |deficiency| {
+        let style = simulated(style, deficiency, CustomTextInputStyle::simulate_deficiency);
+        [
+            ("active", style.active),
+            ("hovered", style.hovered),
+            ("focused", style.focused),
+            ("disabled", style.disabled),
+        ]
+        .map(|(state, appearance)| (state.to_string(), appearance))
+        .to_vec()
+    };
+
+    collisions(states, &all_pairs(4), same_text_input)
+}
+
+pub fn scrollable_collisions(style: &CustomScrollableStyle) -> Vec<Collision> {
+    let states = |deficiency| {
+        let style = simulated(
+            style,
+            deficiency,
+            CustomScrollableStyle::simulate_deficiency,
+        );
+        [
+            ("active", style.active),
+            ("hovered", style.hovered),
+            ("hovered_over_scrollbar", style.hovered_over_scrollbar),
+            ("dragging", style.dragging),
+            ("active_horizontal", style.active_horizontal),
+            ("hovered_horizontal", style.hovered_horizontal),
+            (
+                "hovered_horizontal_over_scrollbar",
+                style.hovered_horizontal_over_scrollbar,
+            ),
+            ("dragging_horizontal", style.dragging_horizontal),
+        ]
+        .map(|(state, scrollbar)| (state.to_string(), scrollbar))
+        .to_vec()
+    };
+    let pairs: Vec<_> = all_pairs(4)
+        .into_iter()
+        .flat_map(|(a, b)| [(a, b), (a + 4, b + 4)])
+        .collect();
+
+    collisions(states, &pairs, same_scrollbar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::scrollable::ScrollableState;
+    use crate::styles::text_input::TextInputState;
+
+    /// A muted red and an olive green, apart with normal vision, together without red or green
+    /// cones.
+    const RED: Color = Color::from_rgb(168.0 / 255.0, 138.0 / 255.0, 49.0 / 255.0);
+    const GREEN: Color = Color::from_rgb(124.0 / 255.0, 149.0 / 255.0, 37.0 / 255.0);
+    const GRAY: Color = Color::from_rgb(0.5, 0.5, 0.5);
+
+    fn pairs(collisions: &[Collision], deficiency: Deficiency) -> Vec<(&str, &str)> {
+        collisions
+            .iter()
+            .filter(|collision| collision.deficiency == deficiency)
+            .map(|collision| (collision.first.as_str(), collision.second.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn red_and_green_buttons_collide_without_red_or_green_cones() {
+        let destructive = CustomButtonStyle::new().all().background_color(RED);
+        let positive = CustomButtonStyle::new().all().background_color(GREEN);
+        assert!(oklab_distance(RED, GREEN) > INDISTINGUISHABLE_DISTANCE);
+
+        let collisions =
+            button_collisions(&[("destructive", &destructive), ("positive", &positive)]);
+        let across = [
+            ("destructive.active", "positive.active"),
+            ("destructive.hovered", "positive.hovered"),
+            ("destructive.pressed", "positive.pressed"),
+            ("destructive.disabled", "positive.disabled"),
+        ];
+        assert_eq!(pairs(&collisions, Deficiency::Protanopia), across);
+        assert_eq!(pairs(&collisions, Deficiency::Deuteranopia), across);
+        assert!(pairs(&collisions, Deficiency::Tritanopia).is_empty());
+    }
+
+    #[test]
+    fn buttons_that_already_look_alike_do_not_collide() {
+        let style = CustomButtonStyle::new().all().background_color(RED);
+
+        assert!(button_collisions(&[("first", &style), ("second", &style)]).is_empty());
+    }
+
+    #[test]
+    fn achromatopsia_keeps_only_the_luminance() {
+        for color in [RED, GREEN, GRAY, Color::from_rgba(0.1, 0.6, 0.9, 0.5)] {
+            let luminance = 0.2126 * to_linear(color.r)
+                + 0.7152 * to_linear(color.g)
+                + 0.0722 * to_linear(color.b);
+            let gray = from_linear(luminance);
+
+            let simulated = Deficiency::Achromatopsia.simulate(color);
+            for channel in [simulated.r, simulated.g, simulated.b] {
+                assert!((channel - gray).abs() < 1e-5, "{color:?} -> {simulated:?}");
+            }
+            assert_eq!(simulated.a, color.a);
+        }
+    }
+
+    #[test]
+    fn text_input_collisions_name_the_states() {
+        let style = CustomTextInputStyle::new()
+            .all()
+            .border_width(1.0)
+            .border_color(GRAY)
+            .states(TextInputState::HOVERED)
+            .border_color(RED)
+            .states(TextInputState::FOCUSED)
+            .border_color(GREEN);
+        let collisions = text_input_collisions(&style);
+
+        assert_eq!(
+            pairs(&collisions, Deficiency::Deuteranopia),
+            [("hovered", "focused")]
+        );
+        assert_eq!(
+            pairs(&collisions, Deficiency::Protanopia),
+            [("hovered", "focused")]
+        );
+    }
+
+    #[test]
+    fn scrollable_collisions_name_the_states() {
+        let style = CustomScrollableStyle::new()
+            .all()
+            .scroller_color(GRAY)
+            .states(ScrollableState::ACTIVE_HORIZONTAL)
+            .scroller_color(RED)
+            .states(ScrollableState::DRAGGING_HORIZONTAL)
+            .scroller_color(GREEN);
+        let collisions = scrollable_collisions(&style);
+
+        assert_eq!(
+            pairs(&collisions, Deficiency::Deuteranopia),
+            [("active_horizontal", "dragging_horizontal")]
+        );
+        assert_eq!(
+            pairs(&collisions, Deficiency::Protanopia),
+            [("active_horizontal", "dragging_horizontal")]
+        );
+    }
+}
@@ -0,0 +1,524 @@
+//! Serializes a [`StyleSet`] for consumption outside of iced.
+//!
+//! Every exported value has a path such as `button/primary/hover/bg`. CSS custom properties join
+//! it with dashes (`--button-primary-hover-bg`), the design tokens JSON nests one group per
+//! segment, following the W3C Design Tokens community group format.
+
+use crate::css_color;
+use crate::style_set::StyleSet;
+use iced::widget::{button, container, scrollable, text_input};
+use iced::{Background, BorderRadius, Color};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Color(Color),
+    Gradient(iced::Gradient),
+    Dimension(f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub path: Vec<&'static str>,
+    pub value: Value,
+}
+
+pub fn to_css_variables(styles: &StyleSet) -> String {
+    let mut css = String::from(":root {\n");
+    for token in tokens(styles) {
+        let value = match token.value {
+            Value::Color(color) => css_color::format(color),
+            Value::Gradient(iced::Gradient::Linear(linear)) => {
+                let stops: Vec<_> = linear
+                    .stops
+                    .iter()
+                    .flatten()
+                    .map(|stop| {
+                        format!("{} {}%", css_color::format(stop.color), stop.offset * 100.0)
+                    })
+                    .collect();
+                format!(
+                    "linear-gradient({}deg, {})",
+                    linear.angle.0.to_degrees(),
+                    stops.join(", ")
+                )
+            }
+            Value::Dimension(length) => format!("{length}px"),
+        };
+        let _ = writeln!(css, "  --{}: {value};", token.path.join("-"));
+    }
+    css.push_str("}\n");
+    css
+}
+
+pub fn to_design_tokens(styles: &StyleSet) -> String {
+    let mut json = String::from("{");
+    let mut open: Vec<&str> = Vec::new();
+
+    for (index, token) in tokens(styles).into_iter().enumerate() {
+        let (groups, name) = token.path.split_at(token.path.len() - 1);
+        let common = open
+            .iter()
+            .zip(groups)
+            .take_while(|(open, group)| open == group)
+            .count();
+
+        for depth in (common..open.len()).rev() {
+            let _ = write!(json, "\n{}}}", indent(depth + 1));
+        }
+        open.truncate(common);
+        if index > 0 {
+            json.push(',');
+        }
+        for group in &groups[common..] {
+            let _ = write!(json, "\n{}{}: {{", indent(open.len() + 1), string(group));
+            open.push(group);
+        }
+
+        let (kind, value) = match token.value {
+            Value::Color(color) => ("color", string(&format_color(color))),
+            Value::Gradient(iced::Gradient::Linear(linear)) => {
+                let stops: Vec<_> = linear
+                    .stops
+                    .iter()
+                    .flatten()
+                    .map(|stop| {
+                        format!(
+                            "{{ \"color\": {}, \"position\": {} }}",
+                            string(&format_color(stop.color)),
+                            stop.offset
+                        )
+                    })
+                    .collect();
+                ("gradient", format!("[{}]", stops.join(", ")))
+            }
+            Value::Dimension(length) => ("dimension", string(&format!("{length}px"))),
+        };
+        let _ = write!(
+            json,
+            "\n{}{}: {{ \"$type\": \"{kind}\", \"$value\": {value} }}",
+            indent(open.len() + 1),
+            string(name[0])
+        );
+    }
+
+    for depth in (0..open.len()).rev() {
+        let _ = write!(json, "\n{}}}", indent(depth + 1));
+    }
+    json.push_str("\n}\n");
+    json
+}
+
+fn indent(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+/// Quotes `value` as a JSON string.
+fn string(value: &str) -> String {
+    let mut json = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            character if character.is_control() => {
+                let _ = write!(json, "\\u{:04x}", character as u32);
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Rounds to 8-bit hex, the only form design tokens allow. CSS output uses the lossless
+/// [`css_color::format`] instead.
+pub fn format_color(color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+    if a == 255 {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Flattens every state and field of the style set, in a stable order.
+pub fn tokens(styles: &StyleSet) -> Vec<Token> {
+    let mut tokens = Vec::new();
+
+    for (name, style) in styles.buttons() {
+        for (state, appearance) in [
+            ("active", &style.active),
+            ("hover", &style.hovered),
+            ("pressed", &style.pressed),
+            ("disabled", &style.disabled),
+        ] {
+            button_tokens(&mut tokens, &["button", name, state], appearance);
+        }
+    }
+
+    for (state, appearance) in [
+        ("active", &styles.text_input.active),
+        ("hover", &styles.text_input.hovered),
+        ("focus", &styles.text_input.focused),
+        ("disabled", &styles.text_input.disabled),
+    ] {
+        text_input_tokens(&mut tokens, &["text-input", state], appearance);
+    }
+    for (field, color) in [
+        ("placeholder", styles.text_input.placeholder_color),
+        ("value", styles.text_input.value_color),
+        ("disabled-value", styles.text_input.disabled_color),
+        ("selection", styles.text_input.selection_color),
+    ] {
+        push(&mut tokens, &["text-input", field], Value::Color(color));
+    }
+
+    let scrollable = &styles.scrollable;
+    for (state, scrollbar) in [
+        ("active", &scrollable.active),
+        ("hover", &scrollable.hovered),
+        ("hover-scrollbar", &scrollable.hovered_over_scrollbar),
+        ("dragging", &scrollable.dragging),
+        ("horizontal-active", &scrollable.active_horizontal),
+        ("horizontal-hover", &scrollable.hovered_horizontal),
+        (
+            "horizontal-hover-scrollbar",
+            &scrollable.hovered_horizontal_over_scrollbar,
+        ),
+        ("horizontal-dragging", &scrollable.dragging_horizontal),
+    ] {
+        scrollbar_tokens(&mut tokens, &["scrollable", state], scrollbar);
+    }
+
+    for (name, style) in styles.containers() {
+        container_tokens(&mut tokens, &["container", name], &style.appearance);
+    }
+
+    if let Some(color) = styles.text.appearance.color {
+        push(&mut tokens, &["text", "color"], Value::Color(color));
+    }
+    #[cfg(feature = "svg")]
+    if let Some(color) = styles.svg.appearance.color {
+        push(&mut tokens, &["svg", "color"], Value::Color(color));
+    }
+
+    tokens
+}
+
+fn push(tokens: &mut Vec<Token>, path: &[&'static str], value: Value) {
+    tokens.push(Token {
+        path: path.to_vec(),
+        value,
+    });
+}
+
+fn with(path: &[&'static str], field: &'static str) -> Vec<&'static str> {
+    let mut path = path.to_vec();
+    path.push(field);
+    path
+}
+
+fn background_token(
+    tokens: &mut Vec<Token>,
+    path: &[&'static str],
+    background: Option<Background>,
+) {
+    let value = match background {
+        None => Value::Color(Color::TRANSPARENT),
+        Some(Background::Color(color)) => Value::Color(color),
+        Some(Background::Gradient(gradient)) => Value::Gradient(gradient),
+    };
+    push(tokens, path, value);
+}
+
+const RADIUS: [&str; 5] = [
+    "border-radius",
+    "border-radius-top-left",
+    "border-radius-top-right",
+    "border-radius-bottom-right",
+    "border-radius-bottom-left",
+];
+
+const SCROLLER_RADIUS: [&str; 5] = [
+    "scroller-border-radius",
+    "scroller-border-radius-top-left",
+    "scroller-border-radius-top-right",
+    "scroller-border-radius-bottom-right",
+    "scroller-border-radius-bottom-left",
+];
+
+/// Uniform radii export as a single token, others as one token per corner.
+fn radius_tokens(
+    tokens: &mut Vec<Token>,
+    path: &[&'static str],
+    names: [&'static str; 5],
+    radius: BorderRadius,
+) {
+    let radii: [f32; 4] = radius.into();
+    if radii.iter().all(|radius| *radius == radii[0]) {
+        push(tokens, &with(path, names[0]), Value::Dimension(radii[0]));
+    } else {
+        for (name, radius) in names[1..].iter().zip(radii) {
+            push(tokens, &with(path, name), Value::Dimension(radius));
+        }
+    }
+}
+
+fn button_tokens(tokens: &mut Vec<Token>, path: &[&'static str], appearance: &button::Appearance) {
+    background_token(tokens, &with(path, "bg"), appearance.background);
+    push(
+        tokens,
+        &with(path, "text"),
+        Value::Color(appearance.text_color),
+    );
+    push(
+        tokens,
+        &with(path, "border-color"),
+        Value::Color(appearance.border_color),
+    );
+    push(
+        tokens,
+        &with(path, "border-width"),
+        Value::Dimension(appearance.border_width),
+    );
+    radius_tokens(tokens, path, RADIUS, appearance.border_radius);
+    push(
+        tokens,
+        &with(path, "shadow-x"),
+        Value::Dimension(appearance.shadow_offset.x),
+    );
+    push(
+        tokens,
+        &with(path, "shadow-y"),
+        Value::Dimension(appearance.shadow_offset.y),
+    );
+}
+
+fn text_input_tokens(
+    tokens: &mut Vec<Token>,
+    path: &[&'static str],
+    appearance: &text_input::Appearance,
+) {
+    background_token(tokens, &with(path, "bg"), Some(appearance.background));
+    push(
+        tokens,
+        &with(path, "icon"),
+        Value::Color(appearance.icon_color),
+    );
+    push(
+        tokens,
+        &with(path, "border-color"),
+        Value::Color(appearance.border_color),
+    );
+    push(
+        tokens,
+        &with(path, "border-width"),
+        Value::Dimension(appearance.border_width),
+    );
+    radius_tokens(tokens, path, RADIUS, appearance.border_radius);
+}
+
+fn scrollbar_tokens(
+    tokens: &mut Vec<Token>,
+    path: &[&'static str],
+    scrollbar: &scrollable::Scrollbar,
+) {
+    background_token(tokens, &with(path, "bg"), scrollbar.background);
+    push(
+        tokens,
+        &with(path, "border-color"),
+        Value::Color(scrollbar.border_color),
+    );
+    push(
+        tokens,
+        &with(path, "border-width"),
+        Value::Dimension(scrollbar.border_width),
+    );
+    radius_tokens(tokens, path, RADIUS, scrollbar.border_radius);
+
+    let scroller = &scrollbar.scroller;
+    push(
+        tokens,
+        &with(path, "scroller-bg"),
+        Value::Color(scroller.color),
+    );
+    push(
+        tokens,
+        &with(path, "scroller-border-color"),
+        Value::Color(scroller.border_color),
+    );
+    push(
+        tokens,
+        &with(path, "scroller-border-width"),
+        Value::Dimension(scroller.border_width),
+    );
+    radius_tokens(tokens, path, SCROLLER_RADIUS, scroller.border_radius);
+}
+
+fn container_tokens(
+    tokens: &mut Vec<Token>,
+    path: &[&'static str],
+    appearance: &container::Appearance,
+) {
+    background_token(tokens, &with(path, "bg"), appearance.background);
+    if let Some(color) = appearance.text_color {
+        push(tokens, &with(path, "text"), Value::Color(color));
+    }
+    push(
+        tokens,
+        &with(path, "border-color"),
+        Value::Color(appearance.border_color),
+    );
+    push(
+        tokens,
+        &with(path, "border-width"),
+        Value::Dimension(appearance.border_width),
+    );
+    radius_tokens(tokens, path, RADIUS, appearance.border_radius);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::json::{self, Value as Json};
+    use crate::presets::Preset;
+    use iced::gradient::{ColorStop, Linear};
+    use iced::Radians;
+
+    /// The design token at `path`, e.g. `["button", "primary", "hover", "bg"]`.
+    fn token<'a>(json: &'a Json, path: &[&str]) -> &'a Json {
+        path.iter().fold(json, |json, key| {
+            &json
+                .get(key)
+                .unwrap_or_else(|| panic!("no {key} in {path:?}"))
+                .value
+        })
+    }
+
+    fn field<'a>(token: &'a Json, key: &str) -> &'a Json {
+        &token
+            .get(key)
+            .expect("tokens have a type and a value")
+            .value
+    }
+
+    #[test]
+    fn css_variables_join_the_path_with_dashes() {
+        let styles = Preset::Nord.styles();
+        let css = to_css_variables(&styles);
+        let hover = match styles.primary_button.hovered.background {
+            Some(Background::Color(color)) => css_color::format(color),
+            background => panic!("{background:?}"),
+        };
+
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.ends_with("}\n"));
+        assert!(css.contains(&format!("\n  --button-primary-hover-bg: {hover};\n")));
+        assert!(css.contains("\n  --text-input-focus-border-width: "));
+        assert!(css.contains("\n  --scrollable-horizontal-hover-scroller-bg: "));
+        assert_eq!(css.lines().count(), tokens(&styles).len() + 2);
+    }
+
+    #[test]
+    fn design_tokens_carry_a_type_and_a_value() {
+        let styles = Preset::Nord.styles();
+        let json = json::parse(&to_design_tokens(&styles)).unwrap().value;
+
+        let bg = token(&json, &["button", "primary", "hover", "bg"]);
+        let hover = match styles.primary_button.hovered.background {
+            Some(Background::Color(color)) => format_color(color),
+            background => panic!("{background:?}"),
+        };
+        assert_eq!(field(bg, "$type").as_str(), Some("color"));
+        assert_eq!(field(bg, "$value").as_str(), Some(hover.as_str()));
+
+        let width = token(&json, &["text-input", "focus", "border-width"]);
+        let expected = format!("{}px", styles.text_input.focused.border_width);
+        assert_eq!(field(width, "$type").as_str(), Some("dimension"));
+        assert_eq!(field(width, "$value").as_str(), Some(expected.as_str()));
+    }
+
+    #[test]
+    fn gradients_export_their_stops() {
+        let mut stops = [None; 8];
+        stops[0] = Some(ColorStop {
+            offset: 0.0,
+            color: Color::BLACK,
+        });
+        stops[1] = Some(ColorStop {
+            offset: 0.5,
+            color: Color::from_rgba8(255, 0, 0, 0.5),
+        });
+        let gradient = iced::Gradient::Linear(Linear {
+            angle: Radians(std::f32::consts::FRAC_PI_2),
+            stops,
+        });
+        let mut styles = Preset::Nord.styles();
+        styles.primary_button.active.background = Some(Background::Gradient(gradient));
+
+        let css = to_css_variables(&styles);
+        assert!(css.contains(&format!(
+            "--button-primary-active-bg: linear-gradient(90deg, {} 0%, {} 50%);",
+            css_color::format(Color::BLACK),
+            css_color::format(Color::from_rgba8(255, 0, 0, 0.5))
+        )));
+
+        let json = json::parse(&to_design_tokens(&styles)).unwrap().value;
+        let bg = token(&json, &["button", "primary", "active", "bg"]);
+        assert_eq!(field(bg, "$type").as_str(), Some("gradient"));
+        let Json::Array(stops) = field(bg, "$value") else {
+            panic!("gradient values are arrays");
+        };
+        let stops: Vec<_> = stops
+            .iter()
+            .map(|stop| {
+                (
+                    field(&stop.value, "color").as_str().unwrap().to_string(),
+                    field(&stop.value, "position").clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            stops,
+            [
+                ("#000000".to_string(), Json::Number(0.0)),
+                ("#ff000080".to_string(), Json::Number(0.5)),
+            ]
+        );
+    }
+
+    /// The reader also takes comments and trailing commas, which strict JSON does not.
+    #[test]
+    fn design_tokens_are_strict_json() {
+        for preset in Preset::ALL {
+            let tokens = to_design_tokens(&preset.styles());
+            json::parse(&tokens).unwrap_or_else(|error| panic!("{preset:?}: {error:?}"));
+
+            let compact: String = tokens.split_whitespace().collect();
+            assert!(
+                !compact.contains(",}") && !compact.contains(",]"),
+                "{preset:?}"
+            );
+            assert!(
+                !compact.contains("//") && !compact.contains("/*"),
+                "{preset:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn strings_are_escaped() {
+        let value = "a \"quoted\" back\\slash\nand\ta bell\u{7}";
+        let quoted = string(value);
+
+        assert_eq!(
+            quoted,
+            r#""a \"quoted\" back\\slash\nand\u0009a bell\u0007""#
+        );
+        assert_eq!(
+            json::parse(&quoted).unwrap().value,
+            Json::String(value.to_string())
+        );
+    }
+}
@@ -0,0 +1,329 @@
+//! Palettes taken from the dominant colors of an image, such as album art.
+
+use crate::color::{from_oklab, oklab_distance, relative_luminance, to_oklab, to_oklch};
+use crate::high_contrast::{effective_background, enforce_contrast};
+use crate::style_set::StyleSet;
+use iced::theme::Palette;
+use iced::Color;
+
+/// Minimum contrast between text and its background in generated styles (WCAG AA).
+pub const READABLE_CONTRAST: f32 = 4.5;
+
+/// Images are sampled down to about this many pixels before clustering.
+const MAX_SAMPLES: usize = 16_384;
+const MAX_ITERATIONS: usize = 16;
+/// Colors closer than this in OKLab are too similar to serve as background and accent.
+const MIN_ACCENT_DISTANCE: f32 = 0.1;
+
+/// A decoded image with 8-bit RGBA pixels, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,
+}
+
+/// A dominant color and the share of the image's opaque pixels it stands for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Swatch {
+    pub color: Color,
+    pub population: f32,
+}
+
+#[cfg(feature = "png")]
+impl Image {
+    pub fn load_png(path: impl AsRef<std::path::Path>) -> Result<Self, png::DecodingError> {
+        let mut decoder = png::Decoder::new(std::fs::File::open(path)?);
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        let bytes = &buffer[..info.buffer_size()];
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => bytes.to_vec(),
+            png::ColorType::Rgb => bytes
+                .chunks_exact(3)
+                .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => bytes
+                .chunks_exact(2)
+                .flat_map(|ga| [ga[0], ga[0], ga[0], ga[1]])
+                .collect(),
+            // Palettes are expanded by the transformations above.
+            png::ColorType::Grayscale | png::ColorType::Indexed => {
+                bytes.iter().flat_map(|&g| [g, g, g, 255]).collect()
+            }
+        };
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+}
+
+impl Image {
+    pub fn dominant_colors(&self, count: usize) -> Vec<Swatch> {
+        dominant_colors(&self.pixels, count)
+    }
+
+    pub fn styles(&self) -> StyleSet {
+        styles(&self.dominant_colors(5))
+    }
+}
+
+/// Clusters RGBA pixels with k-means in OKLab and returns up to `count` colors, most common
+/// first. Pixels that are mostly transparent are ignored.
+pub fn dominant_colors(pixels: &[u8], count: usize) -> Vec<Swatch> {
+    let opaque = pixels.chunks_exact(4).filter(|pixel| pixel[3] >= 128);
+    let step = (pixels.len() / 4 / MAX_SAMPLES).max(1);
+    let samples: Vec<[f32; 3]> = opaque
+        .step_by(step)
+        .map(|pixel| to_oklab(Color::from_rgb8(pixel[0], pixel[1], pixel[2])))
+        .collect();
+    if samples.is_empty() || count == 0 {
+        return Vec::new();
+    }
+
+    let distance = |a: &[f32; 3], b: &[f32; 3]| {
+        (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+    };
+    let nearest = |centers: &[[f32; 3]], sample: &[f32; 3]| {
+        (0..centers.len())
+            .min_by(|&a, &b| {
+                distance(&centers[a], sample).total_cmp(&distance(&centers[b], sample))
+            })
+            .unwrap_or(0)
+    };
+
+    // Deterministic farthest-point seeding, starting from the sample closest to the mean.
+    let centroid = mean(samples.iter());
+    let mut centers = vec![samples[nearest(&samples, &centroid)]];
+    while centers.len() < count {
+        let farthest = samples.iter().max_by(|a, b| {
+            let a = distance(&centers[nearest(&centers, a)], a);
+            let b = distance(&centers[nearest(&centers, b)], b);
+            a.total_cmp(&b)
+        });
+        match farthest {
+            Some(sample) if !centers.contains(sample) => centers.push(*sample),
+            _ => break,
+        }
+    }
+
+    let mut assignments = vec![usize::MAX; samples.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (sample, assignment) in samples.iter().zip(&mut assignments) {
+            let cluster = nearest(&centers, sample);
+            changed |= *assignment != cluster;
+            *assignment = cluster;
+        }
+        if !changed {
+            break;
+        }
+        for (index, center) in centers.iter_mut().enumerate() {
+            let mut members = samples
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, cluster)| **cluster == index)
+                .map(|(sample, _)| sample)
+                .peekable();
+            // An emptied cluster keeps its center and is dropped from the result.
+            if members.peek().is_some() {
+                *center = mean(members);
+            }
+        }
+    }
+
+    let mut swatches: Vec<Swatch> = centers
+        .iter()
+        .enumerate()
+        .map(|(index, center)| Swatch {
+            color: from_oklab(*center, 1.0),
+            population: assignments
+                .iter()
+                .filter(|cluster| **cluster == index)
+                .count() as f32
+                / samples.len() as f32,
+        })
+        .filter(|swatch| swatch.population > 0.0)
+        .collect();
+    swatches.sort_by(|a, b| b.population.total_cmp(&a.population));
+    swatches
+}
+
+fn mean<'a>(samples: impl Iterator<Item = &'a [f32; 3]>) -> [f32; 3] {
+    let (sum, count) = samples.fold(([0.0; 3], 0), |(sum, count), sample| {
+        (
+            [sum[0] + sample[0], sum[1] + sample[1], sum[2] + sample[2]],
+            count + 1,
+        )
+    });
+    sum.map(|channel| channel / count.max(1) as f32)
+}
+
+/// The most common color becomes the background and the most vivid of the others, weighted
+/// by how much of the image it covers, the primary. Text is black or white, whichever reads
+/// better.
+pub fn palette(swatches: &[Swatch]) -> Palette {
+    let Some(background) = swatches.first().map(|swatch| swatch.color) else {
+        return Palette::DARK;
+    };
+    let dark = relative_luminance(background) < 0.18;
+    let base = if dark { Palette::DARK } else { Palette::LIGHT };
+
+    let primary = swatches[1..]
+        .iter()
+        .filter(|swatch| oklab_distance(swatch.color, background) >= MIN_ACCENT_DISTANCE)
+        .max_by(|a, b| {
+            let vividness = |swatch: &Swatch| to_oklch(swatch.color)[1] * swatch.population.sqrt();
+            vividness(a).total_cmp(&vividness(b))
+        })
+        .map_or(base.primary, |swatch| swatch.color);
+
+    Palette {
+        background,
+        text: enforce_contrast(base.text, &[background], READABLE_CONTRAST),
+        primary,
+        success: base.success,
+        danger: base.danger,
+    }
+}
+
+/// Styles from [`palette`], with the text of buttons and containers adjusted to reach
+/// [`READABLE_CONTRAST`] against whatever it is drawn on.
+///
+/// Disabled buttons are left dimmed. WCAG exempts inactive controls from contrast minimums,
+/// and the low contrast is what tells them apart from enabled ones, so raising it would make
+/// every disabled button look clickable.
+pub fn styles(swatches: &[Swatch]) -> StyleSet {
+    let palette = palette(swatches);
+    let canvas = palette.background;
+    let mut styles = StyleSet::from_palette(palette);
+
+    for (_, button) in styles.buttons_mut() {
+        for appearance in [&mut button.active, &mut button.hovered, &mut button.pressed] {
+            let background = effective_background(appearance.background, canvas);
+            appearance.text_color =
+                enforce_contrast(appearance.text_color, &[background], READABLE_CONTRAST);
+        }
+    }
+    for (_, container) in styles.containers_mut() {
+        let background = effective_background(container.appearance.background, canvas);
+        let text = container.appearance.text_color.unwrap_or(palette.text);
+        container.appearance.text_color =
+            Some(enforce_contrast(text, &[background], READABLE_CONTRAST));
+    }
+    styles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::contrast_ratio;
+
+    /// `[r, g, b]` pixels repeated `count` times, each channel jittered by up to 8 levels.
+    fn cluster(pixels: &mut Vec<u8>, [r, g, b]: [u8; 3], count: usize) {
+        let mut state = 0x2545_f491_u32;
+        let mut jitter = |channel: u8| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            channel
+                .saturating_add((state >> 28) as u8 / 2)
+                .saturating_sub(4)
+        };
+        for _ in 0..count {
+            pixels.extend([jitter(r), jitter(g), jitter(b), 255]);
+        }
+    }
+
+    fn swatches(colors: &[[u8; 3]]) -> Vec<Swatch> {
+        colors
+            .iter()
+            .map(|&[r, g, b]| Swatch {
+                color: Color::from_rgb8(r, g, b),
+                population: 1.0 / colors.len() as f32,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn k_means_recovers_synthetic_clusters() {
+        let clusters = [
+            ([200, 40, 40], 600),
+            ([30, 60, 180], 300),
+            ([230, 210, 60], 100),
+        ];
+        let mut pixels = Vec::new();
+        for (color, count) in clusters {
+            cluster(&mut pixels, color, count);
+        }
+        // Mostly transparent pixels are not part of the image's colors.
+        pixels.extend([0, 255, 0, 0].repeat(500));
+
+        let swatches = dominant_colors(&pixels, 3);
+        assert_eq!(swatches.len(), 3);
+        for (swatch, ([r, g, b], count)) in swatches.iter().zip(clusters) {
+            let expected = Color::from_rgb8(r, g, b);
+            assert!(
+                oklab_distance(swatch.color, expected) < 0.02,
+                "{swatch:?} is not {expected:?}"
+            );
+            assert!((swatch.population - count as f32 / 1000.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn fewer_clusters_than_requested_are_not_padded() {
+        let mut pixels = [120, 120, 120, 255].repeat(300);
+        pixels.extend([255, 255, 255, 255].repeat(100));
+
+        let swatches = dominant_colors(&pixels, 8);
+        let populations: Vec<_> = swatches.iter().map(|swatch| swatch.population).collect();
+        assert_eq!(populations, [0.75, 0.25]);
+        assert!(dominant_colors(&[0, 0, 0, 0], 3).is_empty());
+    }
+
+    #[test]
+    fn generated_text_is_readable() {
+        for colors in [
+            [[20, 20, 30], [200, 60, 60], [90, 160, 220]],
+            [[240, 235, 220], [40, 120, 60], [230, 180, 40]],
+            // Mid tones, where neither black nor white text is comfortably readable.
+            [[118, 118, 118], [128, 100, 160], [100, 140, 120]],
+            [[60, 140, 200], [255, 210, 0], [120, 120, 120]],
+        ] {
+            let swatches = swatches(&colors);
+            let palette = palette(&swatches);
+            let canvas = palette.background;
+            assert!(contrast_ratio(palette.text, canvas) >= READABLE_CONTRAST);
+
+            let styles = styles(&swatches);
+            for (name, button) in styles.buttons() {
+                for appearance in [button.active, button.hovered, button.pressed] {
+                    let background = effective_background(appearance.background, canvas);
+                    let ratio = contrast_ratio(appearance.text_color, background);
+                    assert!(ratio >= READABLE_CONTRAST, "{colors:?} {name}: {ratio}");
+                }
+            }
+            for (name, container) in styles.containers() {
+                let background = effective_background(container.appearance.background, canvas);
+                let text = container.appearance.text_color.unwrap();
+                let ratio = contrast_ratio(text, background);
+                assert!(ratio >= READABLE_CONTRAST, "{colors:?} {name}: {ratio}");
+            }
+        }
+    }
+
+    #[test]
+    fn disabled_buttons_stay_dimmed() {
+        let swatches = swatches(&[[118, 118, 118], [128, 100, 160]]);
+        let generated = styles(&swatches);
+        let plain = StyleSet::from_palette(palette(&swatches));
+
+        for ((_, generated), (_, plain)) in generated.buttons().iter().zip(plain.buttons()) {
+            assert_eq!(generated.disabled.text_color, plain.disabled.text_color);
+        }
+    }
+}
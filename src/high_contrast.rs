@@ -0,0 +1,236 @@
+use crate::color::{background_color, contrast_ratio, mix, opaque, over, relative_luminance};
+use crate::visit::map_background;
+use iced::{Background, Color};
+
+/// Minimum contrast between text and the background it is drawn on (WCAG AAA).
+pub const TEXT_CONTRAST: f32 = 7.0;
+/// Minimum contrast for borders and other non-text indicators (WCAG 1.4.11).
+pub const NON_TEXT_CONTRAST: f32 = 3.0;
+pub const MIN_BORDER_WIDTH: f32 = 2.0;
+pub const FOCUSED_BORDER_WIDTH: f32 = 3.0;
+
+/// Returns the color closest to `color` whose contrast against every background is at least
+/// `ratio`, moving towards black or white. Falls back to the better of the two extremes.
+pub fn enforce_contrast(color: Color, backgrounds: &[Color], ratio: f32) -> Color {
+    let worst = |candidate: Color| {
+        backgrounds
+            .iter()
+            .map(|background| contrast_ratio(candidate, *background))
+            .fold(f32::INFINITY, f32::min)
+    };
+
+    let color = opaque(color);
+    if worst(color) >= ratio {
+        return color;
+    }
+
+    let average = backgrounds
+        .iter()
+        .map(|c| relative_luminance(*c))
+        .sum::<f32>()
+        / backgrounds.len().max(1) as f32;
+    let towards = if relative_luminance(color) >= average {
+        [Color::WHITE, Color::BLACK]
+    } else {
+        [Color::BLACK, Color::WHITE]
+    };
+
+    for target in towards {
+        if worst(target) < ratio {
+            continue;
+        }
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..16 {
+            let middle = (low + high) / 2.0;
+            if worst(mix(color, target, middle)) >= ratio {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        return mix(color, target, high);
+    }
+
+    if worst(Color::WHITE) >= worst(Color::BLACK) {
+        Color::WHITE
+    } else {
+        Color::BLACK
+    }
+}
+
+/// Flattens a translucent background onto `canvas`, gradient stops included. Fully transparent
+/// color backgrounds are dropped.
+pub fn opaque_background(background: Option<Background>, canvas: Color) -> Option<Background> {
+    match background {
+        Some(Background::Color(color)) if color.a <= 0.0 => None,
+        Some(background) => Some(map_background(background, &mut |color| over(color, canvas))),
+        None => None,
+    }
+}
+
+/// The opaque color a widget is drawn on, given its own background and the window canvas.
+pub fn effective_background(background: Option<Background>, canvas: Color) -> Color {
+    match background {
+        Some(background) => over(background_color(background), canvas),
+        None => opaque(canvas),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::button::CustomButtonStyle;
+    use crate::styles::text_input::CustomTextInputStyle;
+    use iced::gradient::{ColorStop, Linear};
+    use iced::Theme;
+
+    const SAMPLES: [Color; 8] = [
+        Color::BLACK,
+        Color::WHITE,
+        Color::from_rgb(0.5, 0.5, 0.5),
+        Color::from_rgb(0.2, 0.25, 0.3),
+        Color::from_rgb(0.95, 0.9, 0.8),
+        Color::from_rgb(0.8, 0.1, 0.1),
+        Color::from_rgb(0.1, 0.6, 0.3),
+        Color::from_rgba(0.3, 0.4, 0.9, 0.5),
+    ];
+
+    #[test]
+    fn enforce_contrast_reaches_the_ratio() {
+        for ratio in [TEXT_CONTRAST, NON_TEXT_CONTRAST] {
+            for background in SAMPLES.map(opaque) {
+                let reachable = contrast_ratio(Color::WHITE, background)
+                    .max(contrast_ratio(Color::BLACK, background))
+                    >= ratio;
+                for color in SAMPLES {
+                    let enforced = enforce_contrast(color, &[background], ratio);
+                    if reachable {
+                        assert!(
+                            contrast_ratio(enforced, background) >= ratio,
+                            "{color:?} on {background:?} at {ratio}"
+                        );
+                    } else {
+                        assert!(enforced == Color::WHITE || enforced == Color::BLACK);
+                    }
+                    assert_eq!(enforced.a, 1.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enforce_contrast_keeps_colors_that_already_contrast() {
+        let color = Color::from_rgb(0.1, 0.2, 0.6);
+        assert_eq!(
+            enforce_contrast(color, &[Color::WHITE], TEXT_CONTRAST),
+            color
+        );
+    }
+
+    #[test]
+    fn enforce_contrast_holds_against_every_background() {
+        let backgrounds = [Color::WHITE, Color::from_rgb(0.85, 0.85, 0.9)];
+        let enforced =
+            enforce_contrast(Color::from_rgb(0.4, 0.5, 0.6), &backgrounds, TEXT_CONTRAST);
+
+        for background in backgrounds {
+            assert!(contrast_ratio(enforced, background) >= TEXT_CONTRAST);
+        }
+    }
+
+    #[test]
+    fn borders_are_widened_to_the_minimum() {
+        let button = CustomButtonStyle::new()
+            .all()
+            .border_width(0.0)
+            .high_contrast(&Theme::Light);
+        for appearance in [
+            button.active,
+            button.hovered,
+            button.pressed,
+            button.disabled,
+        ] {
+            assert_eq!(appearance.border_width, MIN_BORDER_WIDTH);
+        }
+
+        let input = CustomTextInputStyle::new()
+            .all()
+            .border_width(0.5)
+            .high_contrast(&Theme::Light);
+        for appearance in [input.active, input.hovered, input.disabled] {
+            assert_eq!(appearance.border_width, MIN_BORDER_WIDTH);
+        }
+        assert!(input.focused.border_width >= FOCUSED_BORDER_WIDTH);
+    }
+
+    #[test]
+    fn focused_text_inputs_stand_out() {
+        let gray = Color::from_rgb(0.5, 0.5, 0.5);
+        let input = CustomTextInputStyle::new()
+            .all()
+            .background_color(Color::WHITE)
+            .border_width(1.0)
+            .border_color(gray)
+            .high_contrast(&Theme::Light);
+        let canvas = Theme::Light.palette().background;
+
+        assert_eq!(input.focused.border_width, FOCUSED_BORDER_WIDTH);
+        for other in [input.active, input.hovered, input.disabled] {
+            assert!(other.border_width < input.focused.border_width);
+            assert_ne!(other.border_color, input.focused.border_color);
+        }
+        assert!(contrast_ratio(input.focused.border_color, canvas) >= TEXT_CONTRAST);
+
+        // Already thick borders still leave the focused one thicker.
+        let thick = CustomTextInputStyle::new()
+            .all()
+            .border_width(4.0)
+            .high_contrast(&Theme::Light);
+        assert_eq!(thick.focused.border_width, 5.0);
+    }
+
+    #[test]
+    fn opaque_background_flattens_gradient_stops() {
+        let mut stops = [None; 8];
+        stops[0] = Some(ColorStop {
+            offset: 0.0,
+            color: Color::from_rgba(1.0, 0.0, 0.0, 0.5),
+        });
+        stops[1] = Some(ColorStop {
+            offset: 1.0,
+            color: Color::TRANSPARENT,
+        });
+        let gradient = Background::Gradient(iced::Gradient::Linear(Linear {
+            angle: iced::Radians(0.0),
+            stops,
+        }));
+
+        let Some(Background::Gradient(iced::Gradient::Linear(linear))) =
+            opaque_background(Some(gradient), Color::WHITE)
+        else {
+            panic!("gradient backgrounds stay gradients");
+        };
+        let colors: Vec<Color> = linear
+            .stops
+            .iter()
+            .flatten()
+            .map(|stop| stop.color)
+            .collect();
+        assert_eq!(colors, [Color::from_rgb(1.0, 0.5, 0.5), Color::WHITE]);
+    }
+
+    #[test]
+    fn opaque_background_drops_transparent_colors() {
+        assert_eq!(
+            opaque_background(Some(Color::TRANSPARENT.into()), Color::BLACK),
+            None
+        );
+        assert_eq!(
+            opaque_background(
+                Some(Color::from_rgba(0.0, 0.0, 0.0, 0.5).into()),
+                Color::WHITE
+            ),
+            Some(Background::Color(Color::from_rgb(0.5, 0.5, 0.5)))
+        );
+    }
+}
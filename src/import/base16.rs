@@ -0,0 +1,339 @@
+//! Reads Base16 and Base24 schemes, in both the legacy flat layout and the newer layout with a
+//! nested `palette`, and styles widgets following the Base16 styling guidelines.
+
+use super::{yaml, Error, Imported};
+use crate::color::{parse_hex, relative_luminance};
+use crate::diagnostic::{Diagnostic, Span};
+use crate::style_set::StyleSet;
+use crate::tokens::Opacity;
+use iced::theme::Palette;
+use iced::Color;
+use std::path::Path;
+
+const METADATA: &[&str] = &[
+    "scheme",
+    "author",
+    "name",
+    "slug",
+    "system",
+    "variant",
+    "description",
+    "palette",
+];
+
+/// The sixteen Base16 colors, `base00` to `base0F`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scheme {
+    pub base: [Color; 16],
+    /// The eight colors Base24 adds, `base10` to `base17`: two darker backgrounds, then bright
+    /// red, yellow, green, cyan, blue and magenta.
+    pub base24: Option<[Color; 8]>,
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Imported, Error> {
+    Ok(parse(&std::fs::read_to_string(path)?)?)
+}
+
+pub fn parse(source: &str) -> Result<Imported, Diagnostic> {
+    let mut warnings = Vec::new();
+    let mut base = [None; 16];
+    let mut base24 = [None; 8];
+
+    for entry in yaml::parse(source)? {
+        let (key, value, span) = (entry.path.as_str(), entry.value, entry.span);
+        let key = key.strip_prefix("palette.").unwrap_or(key);
+        let index = key
+            .strip_prefix("base")
+            .filter(|digits| digits.len() == 2)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+        match index {
+            Some(index @ 0..=0x0f) => match parse_hex(value) {
+                Some(color) => base[index as usize] = Some(color),
+                None => warnings.push(Diagnostic::new(format!("invalid color for `{key}`"), span)),
+            },
+            Some(index @ 0x10..=0x17) => match parse_hex(value) {
+                Some(color) => base24[index as usize - 0x10] = Some(color),
+                None => warnings.push(Diagnostic::new(format!("invalid color for `{key}`"), span)),
+            },
+            _ if METADATA.contains(&key) => {}
+            _ => warnings.push(Diagnostic::new(format!("unknown key `{key}`"), span)),
+        }
+    }
+
+    let mut colors = [Color::BLACK; 16];
+    for (index, color) in base.iter().enumerate() {
+        colors[index] = color.ok_or_else(|| {
+            Diagnostic::new(
+                format!("missing `base{index:02X}`"),
+                Span::new(0, source.len()),
+            )
+        })?;
+    }
+
+    // Base24 colors are all or nothing, the styles do not mix them with Base16 fallbacks.
+    let base24 = match base24.iter().position(Option::is_none) {
+        Some(index) if base24.iter().any(Option::is_some) => {
+            warnings.push(Diagnostic::new(
+                format!(
+                    "missing `base{:02X}`, the Base24 colors are ignored",
+                    index + 0x10
+                ),
+                Span::new(0, source.len()),
+            ));
+            None
+        }
+        _ => base24
+            .iter()
+            .copied()
+            .collect::<Option<Vec<Color>>>()
+            .and_then(|colors| colors.try_into().ok()),
+    };
+
+    let scheme = Scheme {
+        base: colors,
+        base24,
+    };
+    Ok(Imported {
+        styles: scheme.styles(),
+        palette: scheme.palette(),
+        warnings,
+    })
+}
+
+impl Scheme {
+    pub fn is_dark(&self) -> bool {
+        relative_luminance(self.base[0x00]) < relative_luminance(self.base[0x05])
+    }
+
+    pub fn palette(&self) -> Palette {
+        Palette {
+            background: self.base[0x00],
+            text: self.base[0x05],
+            primary: self.base[0x0D],
+            success: self.base[0x0B],
+            danger: self.base[0x08],
+        }
+    }
+
+    /// Backgrounds use `base00` to `base02`, foregrounds `base03` to `base05` and accents
+    /// `base08`, `base0B` and `base0D`, as the styling guidelines assign them. Base24 schemes
+    /// hover accent buttons with the bright red, green and blue of `base12`, `base14` and
+    /// `base16`.
+    pub fn styles(&self) -> StyleSet {
+        let b = &self.base;
+        let bright = |index: usize| self.base24.map(|base24| base24[index - 0x10]);
+        let mut styles = StyleSet::from_palette(self.palette());
+
+        for (button, background, text, hover) in [
+            (
+                &mut styles.primary_button,
+                Some(b[0x0D]),
+                b[0x00],
+                bright(0x16),
+            ),
+            (&mut styles.secondary_button, Some(b[0x02]), b[0x05], None),
+            (
+                &mut styles.positive_button,
+                Some(b[0x0B]),
+                b[0x00],
+                bright(0x14),
+            ),
+            (
+                &mut styles.destructive_button,
+                Some(b[0x08]),
+                b[0x00],
+                bright(0x12),
+            ),
+            (&mut styles.text_button, None, b[0x0D], None),
+        ] {
+            let mut style = button.all().text_color(text);
+            if let Some(background) = background {
+                let hover = hover.unwrap_or_else(|| Opacity::Hover.overlay(background, b[0x05]));
+                style = style
+                    .background_color(background)
+                    .hovered()
+                    .background_color(hover)
+                    .pressed()
+                    .background_color(Opacity::Pressed.overlay(background, b[0x05]))
+                    .disabled()
+                    .background_color(b[0x01]);
+            }
+            *button = style.disabled().text_color(b[0x03]).active();
+        }
+
+        styles.box_container = styles
+            .box_container
+            .background_color(b[0x01])
+            .text_color(Some(b[0x05]))
+            .border_color(b[0x02])
+            .border_width(1.0);
+        styles.transparent_container = styles.transparent_container.text_color(Some(b[0x05]));
+
+        styles.scrollable = styles
+            .scrollable
+            .all()
+            .background(None)
+            .scroller_color(b[0x02])
+            .hovered()
+            .scroller_color(b[0x03])
+            .hovered_horizontal()
+            .scroller_color(b[0x03])
+            .hovered_over_scrollbar()
+            .scroller_color(b[0x04])
+            .hovered_horizontal_over_scrollbar()
+            .scroller_color(b[0x04])
+            .dragging()
+            .scroller_color(b[0x04])
+            .dragging_horizontal()
+            .scroller_color(b[0x04])
+            .active();
+
+        styles.text_input = styles
+            .text_input
+            .all()
+            .background_color(b[0x00])
+            .border_color(b[0x02])
+            .border_width(1.0)
+            .icon_color(b[0x04])
+            .hovered()
+            .border_color(b[0x03])
+            .focused()
+            .border_color(b[0x0D])
+            .disabled()
+            .background_color(b[0x01])
+            .border_color(b[0x01])
+            .active()
+            .value_color(b[0x05])
+            .placeholder_color(b[0x03])
+            .disabled_color(b[0x03])
+            .selection_color(b[0x02]);
+
+        styles.text = styles.text.color(b[0x05]);
+        styles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::button::CustomButtonStyle;
+
+    const SCHEME: &str = "scheme: \"Test\"
+author: \"Someone\"
+base00: \"000000\"
+base01: \"111111\"
+base02: \"222222\"
+base03: \"333333\"
+base04: \"444444\"
+base05: \"dddddd\"
+base06: \"eeeeee\"
+base07: \"ffffff\"
+base08: \"ff0000\"
+base09: \"ff8800\"
+base0A: \"ffff00\"
+base0B: \"00ff00\"
+base0C: \"00ffff\"
+base0D: \"0000ff\"
+base0E: \"ff00ff\"
+base0F: \"880000\"
+";
+
+    #[test]
+    fn reads_flat_and_nested_layouts_alike() {
+        let nested = SCHEME
+            .replace("base", "  base")
+            .replace("author", "palette:\nauthor");
+        let flat = parse(SCHEME).unwrap();
+        let nested = parse(&nested).unwrap();
+
+        assert!(flat.warnings.is_empty(), "{:?}", flat.warnings);
+        assert!(nested.warnings.is_empty(), "{:?}", nested.warnings);
+        assert_eq!(flat.palette.primary, Color::from_rgb(0.0, 0.0, 1.0));
+        assert_eq!(nested.palette.primary, flat.palette.primary);
+        assert_eq!(flat.palette.danger, Color::from_rgb(1.0, 0.0, 0.0));
+        assert_eq!(
+            flat.styles.text_input.focused.border_color,
+            Color::from_rgb(0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn warns_about_unknown_keys_and_invalid_colors() {
+        let source = SCHEME.replace("base00", "base0E: \"purple\"\nextra: 1\nbase00");
+        let imported = parse(&source).unwrap();
+
+        let [invalid, unknown] = imported.warnings.as_slice() else {
+            panic!("expected two warnings, got {:?}", imported.warnings);
+        };
+        assert_eq!(invalid.message, "invalid color for `base0E`");
+        assert_eq!(
+            &source[invalid.span.start..invalid.span.end],
+            "base0E: \"purple\""
+        );
+        assert_eq!(unknown.message, "unknown key `extra`");
+    }
+
+    const BASE24: &str = "base10: \"0a0a0a\"
+base11: \"050505\"
+base12: \"ff4444\"
+base13: \"ffff44\"
+base14: \"44ff44\"
+base15: \"44ffff\"
+base16: \"4444ff\"
+base17: \"ff44ff\"
+";
+
+    #[test]
+    fn reads_base24_colors() {
+        let imported = parse(&format!("{SCHEME}{BASE24}")).unwrap();
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+
+        let hovered = |style: &CustomButtonStyle| match style.hovered.background {
+            Some(iced::Background::Color(color)) => color,
+            background => panic!("{background:?}"),
+        };
+        let styles = &imported.styles;
+        assert_eq!(
+            hovered(&styles.destructive_button),
+            Color::from_rgb8(0xff, 0x44, 0x44)
+        );
+        assert_eq!(
+            hovered(&styles.positive_button),
+            Color::from_rgb8(0x44, 0xff, 0x44)
+        );
+        assert_eq!(
+            hovered(&styles.primary_button),
+            Color::from_rgb8(0x44, 0x44, 0xff)
+        );
+
+        let base16 = parse(SCHEME).unwrap();
+        assert_ne!(
+            hovered(&base16.styles.primary_button),
+            hovered(&styles.primary_button)
+        );
+    }
+
+    #[test]
+    fn incomplete_base24_colors_are_ignored_with_a_warning() {
+        let source = format!("{SCHEME}{}", BASE24.replace("base15: \"44ffff\"\n", ""));
+        let imported = parse(&source).unwrap();
+
+        let [incomplete] = imported.warnings.as_slice() else {
+            panic!("expected one warning, got {:?}", imported.warnings);
+        };
+        assert_eq!(
+            incomplete.message,
+            "missing `base15`, the Base24 colors are ignored"
+        );
+        assert_eq!(
+            format!("{:?}", imported.styles.primary_button),
+            format!("{:?}", parse(SCHEME).unwrap().styles.primary_button)
+        );
+    }
+
+    #[test]
+    fn missing_colors_are_errors() {
+        let source = SCHEME.replace("base0F: \"880000\"\n", "");
+        assert_eq!(parse(&source).unwrap_err().message, "missing `base0F`");
+    }
+}
@@ -0,0 +1,424 @@
+//! Reads the `@define-color` declarations of a GTK 3 or GTK 4 theme, falling back to the names
+//! libadwaita uses, and the dark preference from `settings.ini`.
+
+use super::{ini, Error, Imported};
+use crate::color::{from_hsl, mix, to_hsl};
+use crate::css;
+use crate::css_color;
+use crate::diagnostic::{Diagnostic, Span};
+use crate::style_set::StyleSet;
+use crate::tokens::Opacity;
+use iced::theme::Palette;
+use iced::Color;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// How deep `@name` references may nest before they are considered a cycle.
+const MAX_DEPTH: usize = 32;
+
+pub fn load(path: impl AsRef<Path>, settings: Option<&Path>) -> Result<Imported, Error> {
+    let prefer_dark = match settings {
+        Some(settings) => prefer_dark(&std::fs::read_to_string(settings)?)?,
+        None => false,
+    };
+    Ok(parse(&std::fs::read_to_string(path)?, prefer_dark)?)
+}
+
+/// Reads `gtk-application-prefer-dark-theme` from the `[Settings]` group of `settings.ini`.
+pub fn prefer_dark(settings: &str) -> Result<bool, Diagnostic> {
+    let entry = ini::parse(settings)?.into_iter().rev().find(|entry| {
+        entry.section == "Settings" && entry.key == "gtk-application-prefer-dark-theme"
+    });
+    match entry.map(|entry| (entry.value, entry.value_span)) {
+        None | Some(("0" | "false", _)) => Ok(false),
+        Some(("1" | "true", _)) => Ok(true),
+        Some((_, span)) => Err(Diagnostic::new("expected a boolean", span)),
+    }
+}
+
+pub fn parse(source: &str, prefer_dark: bool) -> Result<Imported, Diagnostic> {
+    let stripped = css::strip_comments(source)?;
+    let definitions = definitions(&stripped)?;
+    let mut warnings = Vec::new();
+
+    let mut colors = HashMap::new();
+    for (name, (value, span)) in &definitions {
+        match evaluate(value, &definitions, 0) {
+            Some(color) => {
+                colors.insert(*name, color);
+            }
+            None => warnings.push(Diagnostic::new(
+                format!("cannot resolve color `@{name}`"),
+                *span,
+            )),
+        }
+    }
+    warnings.sort_by_key(|warning| warning.span.start);
+    let first = |names: &[&str]| names.iter().find_map(|name| colors.get(name).copied());
+
+    let base = if prefer_dark {
+        Palette::DARK
+    } else {
+        Palette::LIGHT
+    };
+    let palette = Palette {
+        background: first(&["theme_bg_color", "window_bg_color"]).unwrap_or(base.background),
+        text: first(&["theme_fg_color", "window_fg_color"]).unwrap_or(base.text),
+        primary: first(&["theme_selected_bg_color", "accent_bg_color"]).unwrap_or(base.primary),
+        success: first(&["success_color", "success_bg_color"]).unwrap_or(base.success),
+        danger: first(&["error_color", "destructive_bg_color", "error_bg_color"])
+            .unwrap_or(base.danger),
+    };
+
+    let mut styles = StyleSet::from_palette(palette);
+    apply(&mut styles, palette, &first);
+
+    Ok(Imported {
+        styles,
+        palette,
+        warnings,
+    })
+}
+
+fn definitions(source: &str) -> Result<HashMap<&str, (&str, Span)>, Diagnostic> {
+    let mut definitions = HashMap::new();
+    let mut offset = 0;
+    while let Some(start) = source[offset..].find("@define-color") {
+        let start = offset + start;
+        let rest = &source[start + "@define-color".len()..];
+        let end = rest
+            .find(';')
+            .ok_or_else(|| Diagnostic::new("expected `;`", Span::new(start, source.len())))?;
+        let declaration = rest[..end].trim_start();
+        let (name, value) = declaration
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| Diagnostic::new("expected a color", Span::new(start, start + end)))?;
+
+        let value_start = source.len() - rest.len() + end - value.trim_start().len();
+        let value = value.trim();
+        definitions.insert(
+            name,
+            (value, Span::new(value_start, value_start + value.len())),
+        );
+        offset = start + "@define-color".len() + end + 1;
+    }
+    Ok(definitions)
+}
+
+/// Evaluates a GTK color expression: a CSS color, an `@name` reference or one of `alpha`,
+/// `shade`, `mix`, `lighter` and `darker`.
+fn evaluate(value: &str, definitions: &HashMap<&str, (&str, Span)>, depth: usize) -> Option<Color> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let value = value.trim();
+    let evaluate = |value: &str| evaluate(value, definitions, depth + 1);
+
+    if let Some(name) = value.strip_prefix('@') {
+        return evaluate(definitions.get(name)?.0);
+    }
+
+    let Some((function, arguments)) = value
+        .strip_suffix(')')
+        .and_then(|value| value.split_once('('))
+    else {
+        return css_color::parse(value).ok();
+    };
+    let arguments = split_arguments(arguments);
+    let number = |argument: &str| argument.trim().parse::<f32>().ok();
+    match (function.trim(), arguments.as_slice()) {
+        ("alpha", [color, factor]) => {
+            let color = evaluate(color)?;
+            Some(Color {
+                a: (color.a * number(factor)?).clamp(0.0, 1.0),
+                ..color
+            })
+        }
+        ("shade", [color, factor]) => Some(shade(evaluate(color)?, number(factor)?)),
+        ("lighter", [color]) => Some(shade(evaluate(color)?, 1.3)),
+        ("darker", [color]) => Some(shade(evaluate(color)?, 0.7)),
+        ("mix", [a, b, factor]) => Some(mix(evaluate(a)?, evaluate(b)?, number(factor)?)),
+        _ => css_color::parse(value).ok(),
+    }
+}
+
+/// Splits at the commas that are not nested in parentheses.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (index, c) in arguments.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                pieces.push(&arguments[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    pieces.push(&arguments[start..]);
+    pieces
+}
+
+/// Scales lightness and saturation like GTK's `shade()`.
+fn shade(color: Color, factor: f32) -> Color {
+    let [hue, saturation, lightness] = to_hsl(color);
+    from_hsl(
+        [
+            hue,
+            (saturation * factor).clamp(0.0, 1.0),
+            (lightness * factor).clamp(0.0, 1.0),
+        ],
+        color.a,
+    )
+}
+
+fn apply(styles: &mut StyleSet, palette: Palette, first: &impl Fn(&[&str]) -> Option<Color>) {
+    let selected_fg = first(&["theme_selected_fg_color", "accent_fg_color"]);
+    let borders = first(&["borders"]);
+    let insensitive_fg = first(&["insensitive_fg_color"]);
+    let insensitive_bg = first(&["insensitive_bg_color"]);
+
+    for (target, background, foreground) in [
+        (
+            &mut styles.primary_button,
+            Some(palette.primary),
+            selected_fg,
+        ),
+        (
+            &mut styles.secondary_button,
+            Some(mix(palette.background, palette.text, 0.1)),
+            Some(palette.text),
+        ),
+        (
+            &mut styles.positive_button,
+            Some(palette.success),
+            selected_fg,
+        ),
+        (
+            &mut styles.destructive_button,
+            Some(palette.danger),
+            first(&["destructive_fg_color"]).or(selected_fg),
+        ),
+        (
+            &mut styles.text_button,
+            None,
+            first(&["link_color", "accent_color"]),
+        ),
+    ] {
+        let mut style = *target;
+        if let Some(c) = foreground {
+            style = style.all().text_color(c);
+        }
+        if let Some(c) = background {
+            let overlay = foreground.unwrap_or(palette.text);
+            style = style
+                .all()
+                .background_color(c)
+                .hovered()
+                .background_color(Opacity::Hover.overlay(c, overlay))
+                .pressed()
+                .background_color(Opacity::Pressed.overlay(c, overlay));
+            if let Some(c) = insensitive_bg {
+                style = style.disabled().background_color(c);
+            }
+        }
+        if let Some(c) = insensitive_fg {
+            style = style.disabled().text_color(c);
+        }
+        *target = style.active();
+    }
+
+    let mut input = styles.text_input;
+    if let Some(c) = first(&["theme_base_color", "view_bg_color"]) {
+        input = input.all().background_color(c);
+    }
+    if let Some(c) = borders {
+        input = input.all().border_color(c).border_width(1.0);
+    }
+    input = input
+        .focused()
+        .border_color(palette.primary)
+        .border_width(2.0);
+    if let Some(c) = first(&["insensitive_base_color", "insensitive_bg_color"]) {
+        input = input.disabled().background_color(c);
+    }
+    if let Some(c) = first(&["theme_text_color", "view_fg_color"]) {
+        input = input.value_color(c);
+    }
+    if let Some(c) = insensitive_fg {
+        input = input.placeholder_color(c).disabled_color(c);
+    }
+    styles.text_input = input.active().selection_color(palette.primary);
+
+    let mut container = styles.box_container;
+    if let Some(c) = first(&["card_bg_color", "theme_base_color", "view_bg_color"]) {
+        container = container.background_color(c);
+    }
+    if let Some(c) = first(&["card_fg_color", "theme_text_color", "view_fg_color"]) {
+        container = container.text_color(Some(c));
+    }
+    if let Some(c) = borders {
+        container = container.border_color(c).border_width(1.0);
+    }
+    styles.box_container = container;
+    styles.transparent_container = styles.transparent_container.text_color(Some(palette.text));
+    styles.text = styles.text.color(palette.text);
+
+    // Adwaita draws sliders in the foreground color at increasing opacity.
+    let slider = |alpha| Color {
+        a: alpha,
+        ..palette.text
+    };
+    styles.scrollable = styles
+        .scrollable
+        .active()
+        .scroller_color(slider(0.2))
+        .hovered()
+        .scroller_color(slider(0.2))
+        .active_horizontal()
+        .scroller_color(slider(0.2))
+        .hovered_horizontal()
+        .scroller_color(slider(0.2))
+        .hovered_over_scrollbar()
+        .scroller_color(slider(0.4))
+        .hovered_horizontal_over_scrollbar()
+        .scroller_color(slider(0.4))
+        .dragging()
+        .scroller_color(slider(0.6))
+        .dragging_horizontal()
+        .scroller_color(slider(0.6))
+        .active();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "
+@define-color theme_bg_color #202020;
+@define-color theme_fg_color rgb(255, 255, 255);
+@define-color accent_bg_color @blue; /* a reference */
+@define-color blue #3584e4;
+@define-color borders alpha(@theme_fg_color, 0.25);
+@define-color gray #808080;
+@define-color half mix(#000000, #ffffff, 0.5);
+";
+
+    fn evaluate_in(source: &str, value: &str) -> Option<Color> {
+        evaluate(value, &definitions(source).unwrap(), 0)
+    }
+
+    fn assert_close(color: Color, expected: [f32; 4]) {
+        let actual = [color.r, color.g, color.b, color.a];
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| (a - b).abs() < 0.005),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn evaluates_color_expressions() {
+        let gray = 128.0 / 255.0;
+        let evaluate = |value| evaluate_in(SOURCE, value).unwrap();
+
+        assert_close(evaluate("@borders"), [1.0, 1.0, 1.0, 0.25]);
+        assert_close(evaluate("alpha(@borders, 2)"), [1.0, 1.0, 1.0, 0.5]);
+        assert_close(evaluate("@half"), [0.5, 0.5, 0.5, 1.0]);
+        assert_close(
+            evaluate("mix(@gray, rgba(0, 0, 0, 0), 0.5)"),
+            [gray / 2.0, gray / 2.0, gray / 2.0, 0.5],
+        );
+        assert_close(
+            evaluate("shade(@gray, 0.5)"),
+            [gray / 2.0, gray / 2.0, gray / 2.0, 1.0],
+        );
+        assert_close(
+            evaluate("lighter(@gray)"),
+            [gray * 1.3, gray * 1.3, gray * 1.3, 1.0],
+        );
+        assert_close(
+            evaluate("darker(@gray)"),
+            [gray * 0.7, gray * 0.7, gray * 0.7, 1.0],
+        );
+        assert_close(evaluate("shade(@theme_fg_color, 2)"), [1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn shade_scales_saturation_too() {
+        let [_, saturation, lightness] = to_hsl(evaluate_in(SOURCE, "shade(@blue, 0.8)").unwrap());
+        let [_, blue_saturation, blue_lightness] = to_hsl(evaluate_in(SOURCE, "@blue").unwrap());
+        assert!((saturation - blue_saturation * 0.8).abs() < 0.01);
+        assert!((lightness - blue_lightness * 0.8).abs() < 0.01);
+    }
+
+    #[test]
+    fn maps_definitions_onto_the_palette() {
+        let imported = parse(SOURCE, true).unwrap();
+
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+        assert_eq!(
+            imported.palette.background,
+            Color::from_rgb8(0x20, 0x20, 0x20)
+        );
+        assert_eq!(imported.palette.primary, Color::from_rgb8(0x35, 0x84, 0xe4));
+        assert_eq!(imported.palette.danger, Palette::DARK.danger);
+        assert_eq!(
+            imported.styles.text_input.focused.border_color,
+            imported.palette.primary
+        );
+        assert_close(
+            imported.styles.box_container.appearance.border_color,
+            [1.0, 1.0, 1.0, 0.25],
+        );
+    }
+
+    #[test]
+    fn unresolvable_colors_warn_at_their_value() {
+        let source = "@define-color a @b;\n@define-color b @a;\n@define-color c shade(#fff);\n";
+        let imported = parse(source, false).unwrap();
+
+        let messages: Vec<_> = imported
+            .warnings
+            .iter()
+            .map(|warning| {
+                (
+                    warning.message.as_str(),
+                    &source[warning.span.start..warning.span.end],
+                )
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                ("cannot resolve color `@a`", "@b"),
+                ("cannot resolve color `@b`", "@a"),
+                ("cannot resolve color `@c`", "shade(#fff)"),
+            ]
+        );
+        assert_eq!(imported.warnings[2].span.line_col(source), (3, 17));
+    }
+
+    #[test]
+    fn unterminated_definitions_are_errors() {
+        let source = "@define-color a #fff;\n@define-color b #000";
+        let error = parse(source, false).unwrap_err();
+        assert_eq!(error.message, "expected `;`");
+        assert_eq!(error.span, Span::new(22, source.len()));
+    }
+
+    #[test]
+    fn reads_the_dark_preference() {
+        assert!(prefer_dark("[Settings]\ngtk-application-prefer-dark-theme=1\n").unwrap());
+        assert!(!prefer_dark("[Settings]\ngtk-theme-name=Adwaita\n").unwrap());
+
+        let source = "[Settings]\ngtk-application-prefer-dark-theme=yes\n";
+        let error = prefer_dark(source).unwrap_err();
+        assert_eq!(error.message, "expected a boolean");
+        assert_eq!(&source[error.span.start..error.span.end], "yes");
+    }
+}
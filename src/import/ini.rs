@@ -0,0 +1,99 @@
+//! A reader for the INI files KDE and GTK store their settings in.
+
+use crate::diagnostic::{Diagnostic, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub section: &'a str,
+    pub key: &'a str,
+    pub value: &'a str,
+    pub key_span: Span,
+    pub value_span: Span,
+}
+
+pub fn parse(source: &str) -> Result<Vec<Entry<'_>>, Diagnostic> {
+    let mut entries = Vec::new();
+    let mut section = "";
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let content = line.trim_end();
+        let trimmed = content.trim_start();
+        let start = start + (content.len() - trimmed.len());
+        if trimmed.is_empty() || trimmed.starts_with(['#', ';']) {
+            continue;
+        }
+
+        if let Some(header) = trimmed.strip_prefix('[') {
+            section = header.strip_suffix(']').ok_or_else(|| {
+                Diagnostic::new("expected `]`", Span::new(start, start + trimmed.len()))
+            })?;
+            continue;
+        }
+
+        let (key, value) = trimmed.split_once('=').ok_or_else(|| {
+            Diagnostic::new(
+                "expected `key=value`",
+                Span::new(start, start + trimmed.len()),
+            )
+        })?;
+        let value_start = start + key.len() + 1;
+        let value_trimmed = value.trim_start();
+        let value_start = value_start + (value.len() - value_trimmed.len());
+        entries.push(Entry {
+            section,
+            key: key.trim_end(),
+            value: value_trimmed,
+            key_span: Span::new(start, start + key.trim_end().len()),
+            value_span: Span::new(value_start, value_start + value_trimmed.len()),
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_sections_and_spans() {
+        let source = "; comment\n[Colors:View]\n  ForegroundNormal = 252,252,252\n\n[General]\nName=Breeze\n";
+        let entries = parse(source).unwrap();
+
+        let keys: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.section, entry.key, entry.value))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                ("Colors:View", "ForegroundNormal", "252,252,252"),
+                ("General", "Name", "Breeze"),
+            ]
+        );
+        let entry = entries[0];
+        assert_eq!(
+            &source[entry.key_span.start..entry.key_span.end],
+            "ForegroundNormal"
+        );
+        assert_eq!(
+            &source[entry.value_span.start..entry.value_span.end],
+            "252,252,252"
+        );
+        assert_eq!(entry.value_span.line_col(source), (3, 22));
+    }
+
+    #[test]
+    fn malformed_lines_are_errors() {
+        let header = parse("[General\nName=Breeze").unwrap_err();
+        assert_eq!(header.message, "expected `]`");
+        assert_eq!(header.span, Span::new(0, 8));
+
+        let source = "[General]\n  Name Breeze\n";
+        let entry = parse(source).unwrap_err();
+        assert_eq!(entry.message, "expected `key=value`");
+        assert_eq!(&source[entry.span.start..entry.span.end], "Name Breeze");
+    }
+}
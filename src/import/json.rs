@@ -0,0 +1,305 @@
+//! A JSON reader that also accepts comments and trailing commas, as VS Code does.
+
+use crate::diagnostic::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Spanned<Value>>),
+    Object(Vec<(Spanned<String>, Spanned<Value>)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Spanned<Value>> {
+        match self {
+            Value::Object(entries) => entries
+                .iter()
+                .rev()
+                .find(|(name, _)| name.value == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse(source: &str) -> Result<Spanned<Value>, Diagnostic> {
+    let mut parser = Parser { source, offset: 0 };
+    let value = parser.value()?;
+    parser.skip_trivia()?;
+    if parser.offset < source.len() {
+        return Err(parser.error("unexpected trailing characters"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    fn error(&self, message: &str) -> Diagnostic {
+        let end = self.rest().chars().next().map_or(0, char::len_utf8);
+        Diagnostic::new(message, Span::new(self.offset, self.offset + end))
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), Diagnostic> {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.offset += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.offset += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                let end = comment
+                    .find("*/")
+                    .ok_or_else(|| self.error("unterminated comment"))?;
+                self.offset += end + 4;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.rest().starts_with(expected) {
+            self.offset += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Result<Spanned<Value>, Diagnostic> {
+        self.skip_trivia()?;
+        let start = self.offset;
+        let value = match self.rest().chars().next() {
+            Some('{') => self.object()?,
+            Some('[') => self.array()?,
+            Some('"') => Value::String(self.string()?),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number()?,
+            Some(_) if self.rest().starts_with("true") => {
+                self.offset += 4;
+                Value::Bool(true)
+            }
+            Some(_) if self.rest().starts_with("false") => {
+                self.offset += 5;
+                Value::Bool(false)
+            }
+            Some(_) if self.rest().starts_with("null") => {
+                self.offset += 4;
+                Value::Null
+            }
+            _ => return Err(self.error("expected a value")),
+        };
+        Ok(Spanned {
+            value,
+            span: Span::new(start, self.offset),
+        })
+    }
+
+    fn object(&mut self) -> Result<Value, Diagnostic> {
+        self.eat('{');
+        let mut entries = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            if self.eat('}') {
+                return Ok(Value::Object(entries));
+            }
+            let start = self.offset;
+            if !self.rest().starts_with('"') {
+                return Err(self.error("expected a string key"));
+            }
+            let key = Spanned {
+                value: self.string()?,
+                span: Span::new(start, self.offset),
+            };
+            self.skip_trivia()?;
+            if !self.eat(':') {
+                return Err(self.error("expected `:`"));
+            }
+            entries.push((key, self.value()?));
+            self.skip_trivia()?;
+            if !self.eat(',') {
+                self.skip_trivia()?;
+                if !self.eat('}') {
+                    return Err(self.error("expected `,` or `}`"));
+                }
+                return Ok(Value::Object(entries));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Diagnostic> {
+        self.eat('[');
+        let mut items = Vec::new();
+        loop {
+            self.skip_trivia()?;
+            if self.eat(']') {
+                return Ok(Value::Array(items));
+            }
+            items.push(self.value()?);
+            self.skip_trivia()?;
+            if !self.eat(',') {
+                self.skip_trivia()?;
+                if !self.eat(']') {
+                    return Err(self.error("expected `,` or `]`"));
+                }
+                return Ok(Value::Array(items));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Diagnostic> {
+        self.eat('"');
+        let mut string = String::new();
+        loop {
+            let c = self
+                .rest()
+                .chars()
+                .next()
+                .ok_or_else(|| self.error("unterminated string"))?;
+            self.offset += c.len_utf8();
+            match c {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escape = self
+                        .rest()
+                        .chars()
+                        .next()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.offset += escape.len_utf8();
+                    match escape {
+                        '"' | '\\' | '/' => string.push(escape),
+                        'b' => string.push('\u{8}'),
+                        'f' => string.push('\u{c}'),
+                        'n' => string.push('\n'),
+                        'r' => string.push('\r'),
+                        't' => string.push('\t'),
+                        'u' => {
+                            let hex = self
+                                .rest()
+                                .get(..4)
+                                .ok_or_else(|| self.error("bad escape"))?;
+                            let code = u32::from_str_radix(hex, 16)
+                                .map_err(|_| self.error("bad escape"))?;
+                            string.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                            self.offset += 4;
+                        }
+                        _ => return Err(self.error("bad escape")),
+                    }
+                }
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, Diagnostic> {
+        let length = self
+            .rest()
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+            .unwrap_or(self.rest().len());
+        let number = self.rest()[..length]
+            .parse()
+            .map_err(|_| self.error("invalid number"))?;
+        self.offset += length;
+        Ok(Value::Number(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_comments_and_trailing_commas() {
+        let source = r##"{
+            // VS Code themes are JSONC
+            "name": "Test", /* inline */
+            "colors": { "editor.background": "#1e1e1e", },
+            "tokenColors": [1, -2.5e1, true, null,],
+        }"##;
+        let value = parse(source).unwrap().value;
+
+        assert_eq!(value.get("name").unwrap().value.as_str(), Some("Test"));
+        let background = value
+            .get("colors")
+            .and_then(|colors| colors.value.get("editor.background"))
+            .unwrap();
+        assert_eq!(background.value.as_str(), Some("#1e1e1e"));
+        assert_eq!(
+            &source[background.span.start..background.span.end],
+            "\"#1e1e1e\""
+        );
+        let Value::Array(tokens) = &value.get("tokenColors").unwrap().value else {
+            panic!("expected an array");
+        };
+        let tokens: Vec<_> = tokens.iter().map(|token| token.value.clone()).collect();
+        assert_eq!(
+            tokens,
+            [
+                Value::Number(1.0),
+                Value::Number(-25.0),
+                Value::Bool(true),
+                Value::Null
+            ]
+        );
+    }
+
+    #[test]
+    fn later_duplicate_keys_win() {
+        let value = parse(r#"{"a": "first", "a": "second"}"#).unwrap().value;
+        assert_eq!(value.get("a").unwrap().value.as_str(), Some("second"));
+    }
+
+    #[test]
+    fn decodes_escapes() {
+        let value = parse(r#""a\"\\\/\né""#).unwrap().value;
+        assert_eq!(value.as_str(), Some("a\"\\/\né"));
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_character() {
+        let error = |source: &str| parse(source).unwrap_err();
+
+        let missing = error("{\"a\": 1 \"b\": 2}");
+        assert_eq!(missing.message, "expected `,` or `}`");
+        assert_eq!(missing.span, Span::new(8, 9));
+
+        let key = error("{a: 1}");
+        assert_eq!(key.message, "expected a string key");
+        assert_eq!(key.span, Span::new(1, 2));
+
+        let trailing = error("[1] x");
+        assert_eq!(trailing.message, "unexpected trailing characters");
+        assert_eq!(trailing.span, Span::new(4, 5));
+
+        let comment = error("/* open");
+        assert_eq!(comment.message, "unterminated comment");
+        assert_eq!(comment.span, Span::new(0, 1));
+
+        let string = error("\"open");
+        assert_eq!(string.message, "unterminated string");
+        assert_eq!(string.span, Span::new(5, 5));
+    }
+}
@@ -0,0 +1,244 @@
+//! Reads KDE color schemes, either `.colors` files or the `[Colors:*]` groups of `kdeglobals`.
+
+use super::{ini, Error, Imported};
+use crate::color::relative_luminance;
+use crate::css_color;
+use crate::diagnostic::Diagnostic;
+use crate::style_set::StyleSet;
+use iced::theme::Palette;
+use iced::Color;
+use std::collections::HashMap;
+use std::path::Path;
+
+const SECTIONS: &[&str] = &[
+    "Colors:Button",
+    "Colors:View",
+    "Colors:Selection",
+    "Colors:Window",
+];
+
+pub fn load(path: impl AsRef<Path>) -> Result<Imported, Error> {
+    Ok(parse(&std::fs::read_to_string(path)?)?)
+}
+
+pub fn parse(source: &str) -> Result<Imported, Diagnostic> {
+    let mut warnings = Vec::new();
+    let mut colors = HashMap::new();
+
+    for entry in ini::parse(source)? {
+        if !SECTIONS.contains(&entry.section) {
+            continue;
+        }
+        match parse_color(entry.value) {
+            Some(color) => {
+                colors.insert((entry.section, entry.key), color);
+            }
+            None => warnings.push(Diagnostic::new(
+                format!("invalid color for `{}`", entry.key),
+                entry.value_span,
+            )),
+        }
+    }
+
+    let color = |section: &str, key: &str| colors.get(&(section, key)).copied();
+
+    let window = color("Colors:Window", "BackgroundNormal");
+    let base = match window {
+        Some(background) if relative_luminance(background) > 0.5 => Palette::LIGHT,
+        Some(_) => Palette::DARK,
+        None => Palette::LIGHT,
+    };
+    let palette = Palette {
+        background: window.unwrap_or(base.background),
+        text: color("Colors:Window", "ForegroundNormal").unwrap_or(base.text),
+        primary: color("Colors:Selection", "BackgroundNormal").unwrap_or(base.primary),
+        success: color("Colors:View", "ForegroundPositive").unwrap_or(base.success),
+        danger: color("Colors:View", "ForegroundNegative").unwrap_or(base.danger),
+    };
+
+    let mut styles = StyleSet::from_palette(palette);
+    apply(&mut styles, &color);
+
+    Ok(Imported {
+        styles,
+        palette,
+        warnings,
+    })
+}
+
+/// KDE writes colors as `r,g,b` or `r,g,b,a` with 0-255 channels, newer versions also as hex.
+fn parse_color(value: &str) -> Option<Color> {
+    if value.starts_with('#') {
+        return css_color::parse(value).ok();
+    }
+    let channels = value
+        .split(',')
+        .map(|channel| channel.trim().parse::<u8>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    match channels[..] {
+        [r, g, b] => Some(Color::from_rgb8(r, g, b)),
+        [r, g, b, a] => Some(Color::from_rgba8(r, g, b, a as f32 / 255.0)),
+        _ => None,
+    }
+}
+
+fn apply(styles: &mut StyleSet, color: &impl Fn(&str, &str) -> Option<Color>) {
+    let selection = |key| color("Colors:Selection", key);
+    let button = |key| color("Colors:Button", key);
+    let view = |key| color("Colors:View", key);
+    let window = |key| color("Colors:Window", key);
+
+    for (target, background, foreground) in [
+        (
+            &mut styles.primary_button,
+            selection("BackgroundNormal"),
+            selection("ForegroundNormal"),
+        ),
+        (
+            &mut styles.secondary_button,
+            button("BackgroundNormal"),
+            button("ForegroundNormal"),
+        ),
+        (
+            &mut styles.positive_button,
+            view("ForegroundPositive"),
+            selection("ForegroundNormal"),
+        ),
+        (
+            &mut styles.destructive_button,
+            view("ForegroundNegative"),
+            selection("ForegroundNormal"),
+        ),
+        (&mut styles.text_button, None, view("ForegroundLink")),
+    ] {
+        let mut style = *target;
+        if let Some(c) = background {
+            style = style.all().background_color(c);
+        }
+        if let Some(c) = foreground {
+            style = style.all().text_color(c);
+        }
+        // Breeze marks hovered and pressed buttons with an outline rather than a fill.
+        if let Some(c) = button("DecorationHover") {
+            style = style.hovered().border_color(c).border_width(1.0);
+        }
+        // Schemes have no pressed decoration. Pressing a button gives it focus, and Breeze outlines
+        // pressed buttons in the focus color, so DecorationFocus is the closest match.
+        if let Some(c) = button("DecorationFocus") {
+            style = style.pressed().border_color(c).border_width(1.0);
+        }
+        if let Some(c) = button("ForegroundInactive") {
+            style = style.disabled().text_color(c);
+        }
+        if let Some(c) = button("BackgroundAlternate").filter(|_| background.is_some()) {
+            style = style.disabled().background_color(c);
+        }
+        *target = style.active();
+    }
+
+    let mut input = styles.text_input;
+    if let Some(c) = view("BackgroundNormal") {
+        input = input.all().background_color(c);
+    }
+    if let Some(c) = view("ForegroundInactive") {
+        input = input
+            .all()
+            .border_color(c)
+            .border_width(1.0)
+            .icon_color(c)
+            .placeholder_color(c)
+            .disabled_color(c);
+    }
+    if let Some(c) = view("DecorationHover") {
+        input = input.hovered().border_color(c);
+    }
+    if let Some(c) = view("DecorationFocus") {
+        input = input.focused().border_color(c);
+    }
+    if let Some(c) = window("BackgroundAlternate") {
+        input = input.disabled().background_color(c);
+    }
+    if let Some(c) = view("ForegroundNormal") {
+        input = input.value_color(c);
+    }
+    if let Some(c) = selection("BackgroundNormal") {
+        input = input.selection_color(c);
+    }
+    styles.text_input = input.active();
+
+    let mut container = styles.box_container;
+    if let Some(c) = view("BackgroundNormal") {
+        container = container.background_color(c);
+    }
+    if let Some(c) = view("ForegroundNormal") {
+        container = container.text_color(Some(c));
+    }
+    if let Some(c) = window("ForegroundInactive") {
+        container = container.border_color(c).border_width(1.0);
+    }
+    styles.box_container = container;
+    if let Some(c) = window("ForegroundNormal") {
+        styles.transparent_container = styles.transparent_container.text_color(Some(c));
+        styles.text = styles.text.color(c);
+    }
+
+    let mut scrollable = styles.scrollable;
+    if let Some(c) = window("ForegroundInactive") {
+        scrollable = scrollable
+            .active()
+            .scroller_color(c)
+            .hovered()
+            .scroller_color(c)
+            .active_horizontal()
+            .scroller_color(c)
+            .hovered_horizontal()
+            .scroller_color(c);
+    }
+    if let Some(c) = window("DecorationHover") {
+        scrollable = scrollable
+            .hovered_over_scrollbar()
+            .scroller_color(c)
+            .hovered_horizontal_over_scrollbar()
+            .scroller_color(c);
+    }
+    if let Some(c) = window("DecorationFocus") {
+        scrollable = scrollable
+            .dragging()
+            .scroller_color(c)
+            .dragging_horizontal()
+            .scroller_color(c);
+    }
+    styles.scrollable = scrollable.active();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_decimal_and_hex_colors() {
+        assert_eq!(
+            parse_color("61,174,233"),
+            Some(Color::from_rgb8(61, 174, 233))
+        );
+        assert_eq!(
+            parse_color("0, 0, 0, 51"),
+            Some(Color::from_rgba8(0, 0, 0, 0.2))
+        );
+        assert_eq!(parse_color("#3daee9"), Some(Color::from_rgb8(61, 174, 233)));
+        assert_eq!(parse_color("1,2"), None);
+        assert_eq!(parse_color("256,0,0"), None);
+    }
+
+    #[test]
+    fn invalid_colors_warn_at_the_value() {
+        let source = "[Colors:View]\nBackgroundNormal=dark\n[Colors:Header]\nBackgroundNormal=x\n";
+        let imported = parse(source).unwrap();
+
+        let [warning] = imported.warnings.as_slice() else {
+            panic!("expected one warning, got {:?}", imported.warnings);
+        };
+        assert_eq!(warning.message, "invalid color for `BackgroundNormal`");
+        assert_eq!(warning.span.line_col(source), (2, 18));
+    }
+}
@@ -0,0 +1,49 @@
+pub mod base16;
+pub mod gtk;
+pub mod ini;
+pub mod json;
+pub mod kde;
+pub mod terminal;
+pub mod vscode;
+pub mod yaml;
+
+use crate::diagnostic::Diagnostic;
+use crate::style_set::StyleSet;
+use std::fmt;
+
+/// A style set read from another format, with everything that could not be mapped.
+#[derive(Debug, Clone)]
+pub struct Imported {
+    pub styles: StyleSet,
+    pub palette: iced::theme::Palette,
+    pub warnings: Vec<Diagnostic>,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Syntax(Diagnostic),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Syntax(diagnostic) => write!(f, "{diagnostic}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Error::Syntax(diagnostic)
+    }
+}
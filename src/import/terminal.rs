@@ -0,0 +1,507 @@
+//! Reads terminal emulator color themes: Alacritty (TOML or the older YAML), kitty and
+//! Xresources.
+
+use super::base16::Scheme;
+use super::{yaml, Error, Imported};
+use crate::color::{mix, parse_hex};
+use crate::css_color;
+use crate::diagnostic::{Diagnostic, Span};
+use crate::style_set::StyleSet;
+use iced::theme::Palette;
+use iced::Color;
+use std::collections::HashMap;
+use std::path::Path;
+
+const ALACRITTY_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The xterm colors, used for any ANSI color a theme leaves out.
+const XTERM: [Color; 16] = [
+    Color::from_rgb(0.0, 0.0, 0.0),
+    Color::from_rgb(0.804, 0.0, 0.0),
+    Color::from_rgb(0.0, 0.804, 0.0),
+    Color::from_rgb(0.804, 0.804, 0.0),
+    Color::from_rgb(0.0, 0.0, 0.933),
+    Color::from_rgb(0.804, 0.0, 0.804),
+    Color::from_rgb(0.0, 0.804, 0.804),
+    Color::from_rgb(0.898, 0.898, 0.898),
+    Color::from_rgb(0.498, 0.498, 0.498),
+    Color::from_rgb(1.0, 0.0, 0.0),
+    Color::from_rgb(0.0, 1.0, 0.0),
+    Color::from_rgb(1.0, 1.0, 0.0),
+    Color::from_rgb(0.361, 0.361, 1.0),
+    Color::from_rgb(1.0, 0.0, 1.0),
+    Color::from_rgb(0.0, 1.0, 1.0),
+    Color::from_rgb(1.0, 1.0, 1.0),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    AlacrittyToml,
+    AlacrittyYaml,
+    Kitty,
+    Xresources,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Some(Format::AlacrittyToml),
+            Some("yml" | "yaml") => Some(Format::AlacrittyYaml),
+            Some("conf") => Some(Format::Kitty),
+            Some("Xresources" | "xresources" | "Xdefaults") => Some(Format::Xresources),
+            _ if name.starts_with(".Xresources") || name.starts_with(".Xdefaults") => {
+                Some(Format::Xresources)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The colors every supported terminal theme describes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerminalColors {
+    pub ansi: [Color; 16],
+    pub foreground: Color,
+    pub background: Color,
+    pub cursor: Option<Color>,
+    pub selection: Option<Color>,
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Imported, Error> {
+    let path = path.as_ref();
+    let format = Format::from_path(path).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "unknown terminal theme format",
+        )
+    })?;
+    Ok(parse(&std::fs::read_to_string(path)?, format)?)
+}
+
+pub fn parse(source: &str, format: Format) -> Result<Imported, Diagnostic> {
+    let mut collector = Collector::default();
+    match format {
+        Format::AlacrittyToml => alacritty_toml(source, &mut collector),
+        Format::AlacrittyYaml => {
+            for entry in yaml::parse(source)? {
+                collector.alacritty(&entry.path, entry.value, entry.span);
+            }
+        }
+        Format::Kitty => {
+            let mut offset = 0;
+            for line in source.split_inclusive('\n') {
+                let start = offset;
+                offset += line.len();
+                let trimmed = line.trim();
+                if trimmed.starts_with('#') {
+                    continue;
+                }
+                if let Some((key, value)) = trimmed.split_once(char::is_whitespace) {
+                    let key = match key {
+                        "selection_background" => "selection",
+                        key => key,
+                    };
+                    let span = Span::new(start, start + line.trim_end().len());
+                    collector.set(key, value.trim(), span);
+                }
+            }
+        }
+        Format::Xresources => {
+            let mut defines = HashMap::new();
+            let mut offset = 0;
+            for line in source.split_inclusive('\n') {
+                let start = offset;
+                offset += line.len();
+                let trimmed = line.trim();
+                if let Some(define) = trimmed.strip_prefix("#define") {
+                    if let Some((name, value)) = define.trim().split_once(char::is_whitespace) {
+                        defines.insert(name, value.trim());
+                    }
+                    continue;
+                }
+                if trimmed.starts_with(['!', '#']) {
+                    continue;
+                }
+                if let Some((resource, value)) = trimmed.split_once(':') {
+                    let key = match resource.rsplit(['.', '*']).next().unwrap_or("").trim() {
+                        "cursorColor" => "cursor",
+                        "highlightColor" => "selection",
+                        key => key,
+                    };
+                    let value = value.trim();
+                    let value = defines.get(value).copied().unwrap_or(value);
+                    let span = Span::new(start, start + line.trim_end().len());
+                    collector.set(key, value, span);
+                }
+            }
+        }
+    }
+    collector.finish(source)
+}
+
+/// Reads the `key = value` lines of Alacritty's TOML config that can hold colors, those in
+/// `[colors.*]` tables and dotted `colors.*` keys. Other tables, multi-line arrays and anything
+/// else are skipped, since configs hold far more than colors.
+fn alacritty_toml(source: &str, collector: &mut Collector) {
+    let mut table = "";
+    let mut depth = 0;
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let content = line.trim_end();
+        let trimmed = content.trim_start();
+        let start = start + (content.len() - trimmed.len());
+        if depth > 0 {
+            depth = nesting(depth, trimmed);
+            continue;
+        }
+        if trimmed.starts_with('[') {
+            // `[table]` or `[[array.of.tables]]`, maybe followed by a comment.
+            let header = trimmed.split('#').next().unwrap_or("").trim();
+            table = header.trim_matches(['[', ']']).trim();
+            continue;
+        }
+        let Some((key, value)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let value_trimmed = value.trim_start();
+        depth = nesting(0, value_trimmed);
+        if depth > 0 {
+            continue;
+        }
+
+        let path = match table {
+            "" => key.trim().to_owned(),
+            table => format!("{table}.{}", key.trim()),
+        };
+        if path != "colors" && !path.starts_with("colors.") {
+            continue;
+        }
+        let value_start = start + key.len() + 1 + (value.len() - value_trimmed.len());
+        let span = Span::new(value_start, value_start + value_trimmed.len());
+        match value_trimmed.strip_prefix('{') {
+            Some(inline) => {
+                for field in inline.trim_end_matches(['}', ' ']).split(',') {
+                    if let Some((key, value)) = field.split_once('=') {
+                        let path = format!("{path}.{}", key.trim());
+                        collector.alacritty(&path, toml_value(value), span);
+                    }
+                }
+            }
+            None => collector.alacritty(&path, toml_value(value_trimmed), span),
+        }
+    }
+}
+
+/// `depth` after the brackets and braces `text` opens and closes, skipping strings and comments.
+fn nesting(mut depth: usize, text: &str) -> usize {
+    let mut quote = None;
+    let mut escaped = false;
+    for c in text.chars() {
+        match quote {
+            Some(open) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && open == '"' {
+                    escaped = true;
+                } else if c == open {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth = depth.saturating_sub(1),
+                '#' => break,
+                _ => {}
+            },
+        }
+    }
+    depth
+}
+
+/// The string in a TOML value, without its quotes or a trailing comment.
+fn toml_value(value: &str) -> &str {
+    let value = value.trim();
+    match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or(""),
+        _ => value.split('#').next().unwrap_or("").trim(),
+    }
+}
+
+/// Accepts `#rrggbb`, Alacritty's `0xrrggbb` and X11's `rgb:rr/gg/bb`.
+fn parse_color(value: &str) -> Option<Color> {
+    if let Some(digits) = value.strip_prefix("0x") {
+        return parse_hex(digits);
+    }
+    if let Some(channels) = value.strip_prefix("rgb:") {
+        let channels = channels
+            .split('/')
+            .map(|channel| {
+                let maximum = 16u32.checked_pow(channel.len() as u32)?.checked_sub(1)?;
+                let channel = u32::from_str_radix(channel, 16).ok()?;
+                Some(channel as f32 / maximum as f32)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        return match channels[..] {
+            [r, g, b] => Some(Color::from_rgb(r, g, b)),
+            _ => None,
+        };
+    }
+    value
+        .starts_with('#')
+        .then(|| css_color::parse(value).ok())
+        .flatten()
+}
+
+#[derive(Default)]
+struct Collector {
+    ansi: [Option<Color>; 16],
+    foreground: Option<Color>,
+    background: Option<Color>,
+    cursor: Option<Color>,
+    selection: Option<Color>,
+    warnings: Vec<Diagnostic>,
+}
+
+impl Collector {
+    fn alacritty(&mut self, path: &str, value: &str, span: Span) {
+        let Some(path) = path.strip_prefix("colors.") else {
+            return;
+        };
+        let key = match path.split_once('.') {
+            Some(("primary", key @ ("foreground" | "background"))) => key.to_owned(),
+            Some(("cursor", "cursor")) => "cursor".to_owned(),
+            Some(("selection", "background")) => "selection".to_owned(),
+            Some((group @ ("normal" | "bright"), name)) => {
+                match ALACRITTY_NAMES.iter().position(|known| *known == name) {
+                    Some(index) if group == "bright" => format!("color{}", index + 8),
+                    Some(index) => format!("color{index}"),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+        self.set(&key, value, span);
+    }
+
+    /// Stores `foreground`, `background`, `cursor`, `selection` or `color0` to `color15`,
+    /// ignoring every other key since terminal configs hold far more than colors.
+    fn set(&mut self, key: &str, value: &str, span: Span) {
+        let slot = match key {
+            "foreground" => &mut self.foreground,
+            "background" => &mut self.background,
+            "cursor" => &mut self.cursor,
+            "selection" => &mut self.selection,
+            _ => match key
+                .strip_prefix("color")
+                .and_then(|n| n.parse::<usize>().ok())
+            {
+                Some(index) if index < 16 => &mut self.ansi[index],
+                _ => return,
+            },
+        };
+        // Themes use `none` to keep the terminal's default, e.g. for a reversed cursor.
+        if value.eq_ignore_ascii_case("none") || value.eq_ignore_ascii_case("CellForeground") {
+            return;
+        }
+        match parse_color(value) {
+            Some(color) => *slot = Some(color),
+            None => self
+                .warnings
+                .push(Diagnostic::new(format!("invalid color for `{key}`"), span)),
+        }
+    }
+
+    fn finish(mut self, source: &str) -> Result<Imported, Diagnostic> {
+        let whole = Span::new(0, source.len());
+        let missing = |key: &str| Diagnostic::new(format!("missing `{key}`"), whole);
+        let mut ansi = XTERM;
+        for (index, color) in self.ansi.iter().enumerate() {
+            match color {
+                Some(color) => ansi[index] = *color,
+                None => self.warnings.push(Diagnostic::new(
+                    format!("missing `color{index}`, using the xterm color"),
+                    whole,
+                )),
+            }
+        }
+        let colors = TerminalColors {
+            ansi,
+            foreground: self.foreground.ok_or_else(|| missing("foreground"))?,
+            background: self.background.ok_or_else(|| missing("background"))?,
+            cursor: self.cursor,
+            selection: self.selection,
+        };
+        Ok(Imported {
+            styles: colors.styles(),
+            palette: colors.palette(),
+            warnings: self.warnings,
+        })
+    }
+}
+
+impl TerminalColors {
+    /// Maps the ANSI colors onto Base16 roles the way base16-shell assigns them.
+    pub fn scheme(&self) -> Scheme {
+        let (background, foreground, ansi) = (self.background, self.foreground, &self.ansi);
+        Scheme {
+            base: [
+                background,
+                mix(background, foreground, 0.08),
+                self.selection
+                    .unwrap_or_else(|| mix(background, foreground, 0.16)),
+                ansi[8],
+                mix(ansi[8], foreground, 0.5),
+                foreground,
+                mix(foreground, ansi[15], 0.5),
+                ansi[15],
+                ansi[1],
+                mix(ansi[1], ansi[3], 0.5),
+                ansi[3],
+                ansi[2],
+                ansi[6],
+                ansi[4],
+                ansi[5],
+                ansi[9],
+            ],
+            base24: None,
+        }
+    }
+
+    pub fn palette(&self) -> Palette {
+        self.scheme().palette()
+    }
+
+    pub fn styles(&self) -> StyleSet {
+        let mut styles = self.scheme().styles();
+        if let Some(cursor) = self.cursor {
+            styles.text_input = styles.text_input.focused().border_color(cursor).active();
+        }
+        styles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORMAL: [&str; 8] = [
+        "000000", "cc0000", "00cc00", "cccc00", "0000cc", "cc00cc", "00cccc", "cccccc",
+    ];
+    const BRIGHT: [&str; 8] = [
+        "555555", "ff5555", "55ff55", "ffff55", "5555ff", "ff55ff", "55ffff", "ffffff",
+    ];
+
+    fn hex(digits: &str) -> Color {
+        parse_hex(digits).unwrap()
+    }
+
+    /// Checks the Base16 slots the palette and styles read from.
+    fn assert_mapped(imported: &Imported) {
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+        let palette = imported.palette;
+        assert_eq!(palette.background, hex("101010"), "base00");
+        assert_eq!(palette.text, hex("e0e0e0"), "base05");
+        assert_eq!(palette.primary, hex("0000cc"), "base0D");
+        assert_eq!(palette.success, hex("00cc00"), "base0B");
+        assert_eq!(palette.danger, hex("cc0000"), "base08");
+
+        let input = imported.styles.text_input;
+        assert_eq!(input.selection_color, hex("333366"), "base02");
+        assert_eq!(input.focused.border_color, hex("ffcc00"), "cursor");
+        assert_eq!(input.placeholder_color, hex("555555"), "base03");
+    }
+
+    #[test]
+    fn maps_alacritty_toml() {
+        let mut source = String::from(
+            "[colors.primary]\nbackground = \"#101010\"\nforeground = '#e0e0e0' # comment\n\n\
+             [colors]\ncursor = { text = \"CellForeground\", cursor = \"#ffcc00\" }\n\
+             selection = { background = \"#333366\" }\n",
+        );
+        for (group, colors) in [("normal", NORMAL), ("bright", BRIGHT)] {
+            source.push_str(&format!("\n[colors.{group}]\n"));
+            for (name, color) in ALACRITTY_NAMES.iter().zip(colors) {
+                source.push_str(&format!("{name} = \"#{color}\"\n"));
+            }
+        }
+        assert_mapped(&parse(&source, Format::AlacrittyToml).unwrap());
+    }
+
+    #[test]
+    fn maps_alacritty_yaml() {
+        let mut source = String::from(
+            "colors:\n  primary:\n    background: '0x101010'\n    foreground: '0xe0e0e0'\n  \
+             cursor:\n    text: CellForeground\n    cursor: '0xffcc00'\n  selection:\n    \
+             background: '0x333366'\n",
+        );
+        for (group, colors) in [("normal", NORMAL), ("bright", BRIGHT)] {
+            source.push_str(&format!("  {group}:\n"));
+            for (name, color) in ALACRITTY_NAMES.iter().zip(colors) {
+                source.push_str(&format!("    {name}: '0x{color}'\n"));
+            }
+        }
+        assert_mapped(&parse(&source, Format::AlacrittyYaml).unwrap());
+    }
+
+    #[test]
+    fn maps_kitty() {
+        let mut source = String::from(
+            "# vim:ft=kitty\nfont_size 11\nforeground #e0e0e0\nbackground #101010\n\
+             cursor #ffcc00\nselection_background #333366\nselection_foreground none\n",
+        );
+        for (index, color) in NORMAL.iter().chain(&BRIGHT).enumerate() {
+            source.push_str(&format!("color{index} #{color}\n"));
+        }
+        assert_mapped(&parse(&source, Format::Kitty).unwrap());
+    }
+
+    #[test]
+    fn maps_xresources() {
+        let mut source = String::from(
+            "! comment\n#define bg #101010\n*.foreground: rgb:e0/e0/e0\n*.background: bg\n\
+             URxvt*cursorColor: #ffcc00\nURxvt.highlightColor: rgb:3333/3333/6666\n",
+        );
+        for (index, color) in NORMAL.iter().chain(&BRIGHT).enumerate() {
+            source.push_str(&format!("*color{index}: #{color}\n"));
+        }
+        assert_mapped(&parse(&source, Format::Xresources).unwrap());
+    }
+
+    #[test]
+    fn warns_about_invalid_and_missing_colors() {
+        let source = "foreground #e0e0e0\nbackground #101010\ncolor1 red\n";
+        let imported = parse(source, Format::Kitty).unwrap();
+
+        let invalid = &imported.warnings[0];
+        assert_eq!(invalid.message, "invalid color for `color1`");
+        assert_eq!(&source[invalid.span.start..invalid.span.end], "color1 red");
+        assert_eq!(invalid.span.line_col(source), (3, 1));
+        assert_eq!(
+            imported.warnings[1].message,
+            "missing `color0`, using the xterm color"
+        );
+        assert_eq!(imported.warnings.len(), 17);
+        assert_eq!(imported.palette.danger, XTERM[1]);
+
+        let error = parse("background #101010\n", Format::Kitty).unwrap_err();
+        assert_eq!(error.message, "missing `foreground`");
+    }
+
+    #[test]
+    fn detects_the_format_from_the_path() {
+        let format = |path: &str| Format::from_path(Path::new(path));
+        assert_eq!(format("alacritty.toml"), Some(Format::AlacrittyToml));
+        assert_eq!(format("alacritty.yml"), Some(Format::AlacrittyYaml));
+        assert_eq!(format("kitty-theme.conf"), Some(Format::Kitty));
+        assert_eq!(format("/home/me/.Xresources"), Some(Format::Xresources));
+        assert_eq!(format(".Xresources.d-colors"), Some(Format::Xresources));
+        assert_eq!(format("theme.json"), None);
+    }
+}
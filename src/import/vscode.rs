@@ -0,0 +1,289 @@
+//! Reads the `colors` of a VS Code color theme.
+
+use super::json::{self, Value};
+use super::{Error, Imported};
+use crate::css_color;
+use crate::diagnostic::{Diagnostic, Span};
+use crate::style_set::StyleSet;
+use iced::theme::Palette;
+use iced::Color;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Keys that only feed the palette, the rest of the mapping is in [`parse`].
+const PALETTE_KEYS: &[&str] = &[
+    "editor.background",
+    "editor.foreground",
+    "foreground",
+    "terminal.ansiGreen",
+    "gitDecoration.addedResourceForeground",
+    "errorForeground",
+    "editorError.foreground",
+];
+
+const STYLE_KEYS: &[&str] = &[
+    "button.background",
+    "button.foreground",
+    "button.hoverBackground",
+    "button.border",
+    "button.secondaryBackground",
+    "button.secondaryForeground",
+    "button.secondaryHoverBackground",
+    "disabledForeground",
+    "input.background",
+    "input.foreground",
+    "input.border",
+    "input.placeholderForeground",
+    "focusBorder",
+    "scrollbarSlider.background",
+    "scrollbarSlider.hoverBackground",
+    "scrollbarSlider.activeBackground",
+    "editor.selectionBackground",
+    "editorWidget.background",
+    "editorWidget.foreground",
+    "editorWidget.border",
+];
+
+pub fn load(path: impl AsRef<Path>) -> Result<Imported, Error> {
+    Ok(parse(&std::fs::read_to_string(path)?)?)
+}
+
+pub fn parse(source: &str) -> Result<Imported, Diagnostic> {
+    let document = json::parse(source)?;
+    let mut warnings = Vec::new();
+
+    let light = matches!(
+        document
+            .value
+            .get("type")
+            .and_then(|kind| kind.value.as_str()),
+        Some("light" | "hc-light")
+    );
+
+    let mut colors = HashMap::new();
+    match document.value.get("colors") {
+        Some(json::Spanned {
+            value: Value::Object(entries),
+            ..
+        }) => {
+            for (key, value) in entries {
+                let known = PALETTE_KEYS.contains(&key.value.as_str())
+                    || STYLE_KEYS.contains(&key.value.as_str());
+                let color = match value.value.as_str() {
+                    Some(text) => css_color::parse(text).map_err(|error| error.message),
+                    None => Err("expected a string".to_string()),
+                };
+                match color {
+                    Ok(color) if known => {
+                        colors.insert(key.value.as_str(), color);
+                    }
+                    Ok(_) => warnings.push(Diagnostic::new(
+                        format!("unmapped key `{}`", key.value),
+                        key.span,
+                    )),
+                    Err(message) => warnings.push(Diagnostic::new(
+                        format!("invalid color for `{}`: {message}", key.value),
+                        value.span,
+                    )),
+                }
+            }
+        }
+        Some(other) => warnings.push(Diagnostic::new("`colors` is not an object", other.span)),
+        None => warnings.push(Diagnostic::new(
+            "theme has no `colors`",
+            Span::new(0, source.len()),
+        )),
+    }
+
+    let color = |key: &str| colors.get(key).copied();
+    let first = |keys: &[&str]| keys.iter().find_map(|key| color(key));
+
+    let base = if light { Palette::LIGHT } else { Palette::DARK };
+    let palette = Palette {
+        background: first(&["editor.background"]).unwrap_or(base.background),
+        text: first(&["foreground", "editor.foreground"]).unwrap_or(base.text),
+        primary: first(&["button.background", "focusBorder"]).unwrap_or(base.primary),
+        success: first(&[
+            "terminal.ansiGreen",
+            "gitDecoration.addedResourceForeground",
+        ])
+        .unwrap_or(base.success),
+        danger: first(&["errorForeground", "editorError.foreground"]).unwrap_or(base.danger),
+    };
+
+    let mut styles = StyleSet::from_palette(palette);
+    apply(&mut styles, &color);
+
+    Ok(Imported {
+        styles,
+        palette,
+        warnings,
+    })
+}
+
+fn apply(styles: &mut StyleSet, color: &impl Fn(&str) -> Option<Color>) {
+    for (button, [background, foreground, hover]) in [
+        (
+            &mut styles.primary_button,
+            [
+                "button.background",
+                "button.foreground",
+                "button.hoverBackground",
+            ],
+        ),
+        (
+            &mut styles.secondary_button,
+            [
+                "button.secondaryBackground",
+                "button.secondaryForeground",
+                "button.secondaryHoverBackground",
+            ],
+        ),
+    ] {
+        let mut style = *button;
+        if let Some(c) = color(background) {
+            style = style.all().background_color(c);
+        }
+        if let Some(c) = color(foreground) {
+            style = style.all().text_color(c);
+        }
+        if let Some(c) = color("button.border") {
+            style = style.all().border_color(c).border_width(1.0);
+        }
+        if let Some(c) = color(hover) {
+            style = style.hovered().background_color(c);
+            style = style.pressed().background_color(c);
+        }
+        *button = style.active();
+    }
+
+    if let Some(c) = color("disabledForeground") {
+        for (_, button) in styles.buttons_mut() {
+            *button = button.disabled().text_color(c).active();
+        }
+        styles.text_input = styles.text_input.disabled_color(c);
+    }
+
+    let mut input = styles.text_input;
+    if let Some(c) = color("input.background") {
+        input = input.all().background_color(c);
+    }
+    if let Some(c) = color("input.border") {
+        input = input.all().border_color(c).border_width(1.0);
+    }
+    if let Some(c) = color("focusBorder") {
+        input = input.focused().border_color(c).border_width(1.0);
+    }
+    if let Some(c) = color("input.foreground") {
+        input = input.value_color(c);
+    }
+    if let Some(c) = color("input.placeholderForeground") {
+        input = input.placeholder_color(c);
+    }
+    if let Some(c) = color("editor.selectionBackground") {
+        input = input.selection_color(c);
+    }
+    styles.text_input = input.active();
+
+    let mut scrollable = styles.scrollable;
+    if let Some(c) = color("scrollbarSlider.background") {
+        scrollable = scrollable
+            .active()
+            .scroller_color(c)
+            .hovered()
+            .scroller_color(c)
+            .active_horizontal()
+            .scroller_color(c)
+            .hovered_horizontal()
+            .scroller_color(c);
+    }
+    if let Some(c) = color("scrollbarSlider.hoverBackground") {
+        scrollable = scrollable
+            .hovered_over_scrollbar()
+            .scroller_color(c)
+            .hovered_horizontal_over_scrollbar()
+            .scroller_color(c);
+    }
+    if let Some(c) = color("scrollbarSlider.activeBackground") {
+        scrollable = scrollable
+            .dragging()
+            .scroller_color(c)
+            .dragging_horizontal()
+            .scroller_color(c);
+    }
+    styles.scrollable = scrollable.active();
+
+    let mut container = styles.box_container;
+    if let Some(c) = color("editorWidget.background") {
+        container = container.background_color(c);
+    }
+    if let Some(c) = color("editorWidget.foreground") {
+        container = container.text_color(Some(c));
+    }
+    if let Some(c) = color("editorWidget.border") {
+        container = container.border_color(c).border_width(1.0);
+    }
+    styles.box_container = container;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_colors_onto_the_palette_and_styles() {
+        let source = r##"{
+            "type": "light",
+            "colors": {
+                "editor.background": "#fafafa",
+                "button.background": "#0066b8",
+                "button.hoverBackground": "#005ba4",
+                "focusBorder": "#0090f1",
+            },
+        }"##;
+        let imported = parse(source).unwrap();
+
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+        assert_eq!(
+            imported.palette.background,
+            Color::from_rgb8(0xfa, 0xfa, 0xfa)
+        );
+        assert_eq!(imported.palette.primary, Color::from_rgb8(0x00, 0x66, 0xb8));
+        assert_eq!(imported.palette.text, Palette::LIGHT.text);
+
+        let button = imported.styles.primary_button;
+        let hover = Some(iced::Background::Color(Color::from_rgb8(0x00, 0x5b, 0xa4)));
+        assert_eq!(button.hovered.background, hover);
+        assert_eq!(button.pressed.background, hover);
+        assert_eq!(
+            imported.styles.text_input.focused.border_color,
+            Color::from_rgb8(0x00, 0x90, 0xf1)
+        );
+    }
+
+    #[test]
+    fn warns_about_unmapped_keys_and_invalid_colors() {
+        let source = r##"{"colors": {"tab.border": "#000", "focusBorder": "blue-ish"}}"##;
+        let imported = parse(source).unwrap();
+
+        let [unmapped, invalid] = imported.warnings.as_slice() else {
+            panic!("expected two warnings, got {:?}", imported.warnings);
+        };
+        assert_eq!(unmapped.message, "unmapped key `tab.border`");
+        assert_eq!(
+            &source[unmapped.span.start..unmapped.span.end],
+            "\"tab.border\""
+        );
+        assert!(
+            invalid
+                .message
+                .starts_with("invalid color for `focusBorder`"),
+            "{}",
+            invalid.message
+        );
+        assert_eq!(
+            &source[invalid.span.start..invalid.span.end],
+            "\"blue-ish\""
+        );
+    }
+}
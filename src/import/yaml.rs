@@ -0,0 +1,119 @@
+//! A reader for the block-mapping subset of YAML that color schemes are written in.
+
+use crate::diagnostic::{Diagnostic, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry<'a> {
+    /// The keys leading to the value, joined with `.`.
+    pub path: String,
+    pub value: &'a str,
+    /// The span of the whole `key: value` line.
+    pub span: Span,
+}
+
+/// Reads `key: value` lines, nesting keys by indentation. Mapping keys without a value are
+/// reported too, with an empty value.
+pub fn parse(source: &str) -> Result<Vec<Entry<'_>>, Diagnostic> {
+    let mut entries = Vec::new();
+    let mut parents: Vec<(usize, &str)> = Vec::new();
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let content = strip_comment(line).trim_end();
+        let trimmed = content.trim_start();
+        if trimmed.is_empty() || trimmed == "---" {
+            continue;
+        }
+        let indent = content.len() - trimmed.len();
+        let span = Span::new(start + indent, start + content.len());
+        let (key, value) = trimmed
+            .split_once(':')
+            .ok_or_else(|| Diagnostic::new("expected `key: value`", span))?;
+        let key = unquote(key.trim());
+        let value = unquote(value.trim());
+
+        while parents.last().is_some_and(|(level, _)| *level >= indent) {
+            parents.pop();
+        }
+        let path = parents
+            .iter()
+            .map(|(_, parent)| *parent)
+            .chain([key])
+            .collect::<Vec<_>>()
+            .join(".");
+        if value.is_empty() {
+            parents.push((indent, key));
+        }
+        entries.push(Entry { path, value, span });
+    }
+    Ok(entries)
+}
+
+/// Cuts a `#` comment that starts outside of quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (index, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), c) if c == open => quote = None,
+            (None, '#') if previous.is_whitespace() => return &line[..index],
+            _ => {}
+        }
+        previous = c;
+    }
+    line
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nests_keys_by_indentation() {
+        let source = "---\nscheme: \"Test # not a comment\"\npalette: # colors\n  base00: '1d1f21'\n  nested:\n    key: value\nafter: x\n";
+        let entries: Vec<_> = parse(source)
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.path, entry.value))
+            .collect();
+
+        assert_eq!(
+            entries,
+            [
+                ("scheme".to_string(), "Test # not a comment"),
+                ("palette".to_string(), ""),
+                ("palette.base00".to_string(), "1d1f21"),
+                ("palette.nested".to_string(), ""),
+                ("palette.nested.key".to_string(), "value"),
+                ("after".to_string(), "x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_cover_the_line_without_indentation_or_comment() {
+        let source = "colors:\n  primary: '#ffffff' # white\n";
+        let entries = parse(source).unwrap();
+        let span = entries[1].span;
+        assert_eq!(&source[span.start..span.end], "primary: '#ffffff'");
+        assert_eq!(span.line_col(source), (2, 3));
+    }
+
+    #[test]
+    fn lines_without_a_colon_are_errors() {
+        let source = "scheme: Test\n  - list item\n";
+        let error = parse(source).unwrap_err();
+        assert_eq!(error.message, "expected `key: value`");
+        assert_eq!(&source[error.span.start..error.span.end], "- list item");
+    }
+}
@@ -1 +1,19 @@
+pub mod color;
+pub mod css;
+pub mod css_color;
+pub mod cvd;
+pub mod diagnostic;
+pub mod export;
+pub mod extract;
+pub mod high_contrast;
+pub mod import;
+pub mod lint;
+pub mod presets;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+pub mod style_set;
 pub mod styles;
+pub mod tokens;
+pub mod tonal;
+pub mod utility;
+pub mod visit;
@@ -0,0 +1,654 @@
+//! Checks a [`StyleSet`] for problems that parse fine but hurt in use: unreadable text, states
+//! that give no feedback and disabled states that look enabled.
+
+use crate::color::{background_color, contrast_ratio, delta_e2000, over};
+use crate::high_contrast::{effective_background, NON_TEXT_CONTRAST};
+use crate::style_set::StyleSet;
+use iced::theme::Palette;
+use iced::widget::{button, scrollable, text_input};
+use iced::{BorderRadius, Color, Vector};
+use std::fmt;
+
+/// Minimum contrast between text and the background it is drawn on (WCAG AA).
+pub const TEXT_CONTRAST: f32 = 4.5;
+/// States whose colors all differ by less than this CIEDE2000 distance, and whose borders, radii
+/// and shadow move by less than a pixel, are reported as indistinguishable.
+pub const MIN_STATE_DIFFERENCE: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    Contrast,
+    InvisibleText,
+    IndistinguishableStates,
+    IndistinguishableDisabled,
+}
+
+impl Rule {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Rule::Contrast => "contrast",
+            Rule::InvisibleText => "invisible-text",
+            Rule::IndistinguishableStates => "indistinguishable-states",
+            Rule::IndistinguishableDisabled => "indistinguishable-disabled",
+        }
+    }
+}
+
+/// A problem in the style at `path`, e.g. `button.primary.hovered`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub rule: Rule,
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} [{}]", self.path, self.message, self.rule.name())
+    }
+}
+
+/// Lints `styles` as drawn on a window of `palette`. Disabled states are exempt from the
+/// contrast rules, as in WCAG.
+pub fn lint(styles: &StyleSet, palette: Palette) -> Vec<Finding> {
+    let canvas = palette.background;
+    let mut findings = Vec::new();
+    let mut push = |rule, path: String, message: String| {
+        findings.push(Finding {
+            rule,
+            path,
+            message,
+        })
+    };
+
+    for (name, button) in styles.buttons() {
+        for (state, appearance) in [
+            ("active", button.active),
+            ("hovered", button.hovered),
+            ("pressed", button.pressed),
+        ] {
+            let background = effective_background(appearance.background, canvas);
+            check_text(
+                &mut push,
+                format!("button.{name}.{state}"),
+                appearance.text_color,
+                background,
+            );
+        }
+    }
+
+    for (name, container) in styles.containers() {
+        let appearance = container.appearance;
+        check_text(
+            &mut push,
+            format!("container.{name}"),
+            appearance.text_color.unwrap_or(palette.text),
+            effective_background(appearance.background, canvas),
+        );
+    }
+
+    let input = &styles.text_input;
+    for (state, appearance) in [
+        ("active", input.active),
+        ("hovered", input.hovered),
+        ("focused", input.focused),
+    ] {
+        let background = over(background_color(appearance.background), canvas);
+        check_text(
+            &mut push,
+            format!("text_input.{state}"),
+            input.value_color,
+            background,
+        );
+        check_text(
+            &mut push,
+            format!("text_input.{state}.placeholder"),
+            input.placeholder_color,
+            background,
+        );
+    }
+
+    let scrollable = &styles.scrollable;
+    for (names, scrollbars) in [
+        (
+            ["active", "hovered", "hovered_over_scrollbar", "dragging"],
+            [
+                scrollable.active,
+                scrollable.hovered,
+                scrollable.hovered_over_scrollbar,
+                scrollable.dragging,
+            ],
+        ),
+        (
+            [
+                "active_horizontal",
+                "hovered_horizontal",
+                "hovered_horizontal_over_scrollbar",
+                "dragging_horizontal",
+            ],
+            [
+                scrollable.active_horizontal,
+                scrollable.hovered_horizontal,
+                scrollable.hovered_horizontal_over_scrollbar,
+                scrollable.dragging_horizontal,
+            ],
+        ),
+    ] {
+        for (state, scrollbar) in names.iter().zip(scrollbars) {
+            let background = effective_background(scrollbar.background, canvas);
+            let ratio = contrast_ratio(over(scrollbar.scroller.color, background), background);
+            if ratio < NON_TEXT_CONTRAST {
+                push(
+                    Rule::Contrast,
+                    format!("scrollable.{state}.scroller"),
+                    format!("scroller contrast is {ratio:.2}:1, below {NON_TEXT_CONTRAST}:1"),
+                );
+            }
+        }
+    }
+
+    check_text(
+        &mut push,
+        String::from("text"),
+        styles.text.appearance.color.unwrap_or(palette.text),
+        canvas,
+    );
+
+    findings.extend(indistinguishable_states(
+        styles,
+        canvas,
+        MIN_STATE_DIFFERENCE,
+    ));
+    findings
+}
+
+fn check_text(
+    push: &mut impl FnMut(Rule, String, String),
+    path: String,
+    color: Color,
+    background: Color,
+) {
+    if color.a <= 0.0 {
+        return push(
+            Rule::InvisibleText,
+            path,
+            String::from("text is fully transparent"),
+        );
+    }
+    let ratio = contrast_ratio(over(color, background), background);
+    if ratio < TEXT_CONTRAST {
+        push(
+            Rule::Contrast,
+            path,
+            format!("text contrast is {ratio:.2}:1, below {TEXT_CONTRAST}:1"),
+        );
+    }
+}
+
+/// Compares the states a widget moves between on interaction: each button state with the others
+/// and with disabled, text inputs when hovered, focused or disabled, and scrollbars when hovered
+/// or dragged, in both orientations.
+pub fn indistinguishable_states(styles: &StyleSet, canvas: Color, threshold: f32) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut compare = |path: String, other: &str, difference: Difference| {
+        if difference.color < threshold && difference.geometry < 1.0 {
+            let rule = if path.ends_with(".disabled") {
+                Rule::IndistinguishableDisabled
+            } else {
+                Rule::IndistinguishableStates
+            };
+            findings.push(Finding {
+                rule,
+                path,
+                message: format!(
+                    "hardly differs from {other} (ΔE2000 {:.2}, borders and shadow unchanged)",
+                    difference.color
+                ),
+            });
+        }
+    };
+
+    for (name, style) in styles.buttons() {
+        for (state, other, a, b) in [
+            ("hovered", "active", &style.hovered, &style.active),
+            ("pressed", "active", &style.pressed, &style.active),
+            ("pressed", "hovered", &style.pressed, &style.hovered),
+            ("disabled", "active", &style.disabled, &style.active),
+        ] {
+            compare(
+                format!("button.{name}.{state}"),
+                other,
+                button_difference(a, b, canvas),
+            );
+        }
+    }
+
+    let input = &styles.text_input;
+    for (state, appearance, text) in [
+        ("hovered", &input.hovered, input.value_color),
+        ("focused", &input.focused, input.value_color),
+        ("disabled", &input.disabled, input.disabled_color),
+    ] {
+        compare(
+            format!("text_input.{state}"),
+            "active",
+            text_input_difference(
+                (appearance, text),
+                (&input.active, input.value_color),
+                canvas,
+            ),
+        );
+    }
+
+    let scrollable = &styles.scrollable;
+    for (state, other, a, b) in [
+        (
+            "hovered_over_scrollbar",
+            "active",
+            &scrollable.hovered_over_scrollbar,
+            &scrollable.active,
+        ),
+        (
+            "dragging",
+            "active",
+            &scrollable.dragging,
+            &scrollable.active,
+        ),
+        (
+            "hovered_horizontal_over_scrollbar",
+            "active_horizontal",
+            &scrollable.hovered_horizontal_over_scrollbar,
+            &scrollable.active_horizontal,
+        ),
+        (
+            "dragging_horizontal",
+            "active_horizontal",
+            &scrollable.dragging_horizontal,
+            &scrollable.active_horizontal,
+        ),
+    ] {
+        compare(
+            format!("scrollable.{state}"),
+            other,
+            scrollbar_difference(a, b, canvas),
+        );
+    }
+
+    findings
+}
+
+/// How far apart two states look: the largest CIEDE2000 distance between corresponding colors
+/// as drawn, and the largest change of a border width, radius or shadow offset in pixels.
+#[derive(Debug, Clone, Copy, Default)]
+struct Difference {
+    color: f32,
+    geometry: f32,
+}
+
+impl Difference {
+    fn color(mut self, a: Color, b: Color) -> Self {
+        self.color = self.color.max(delta_e2000(a, b));
+        self
+    }
+
+    /// Border colors only count when both states draw a border, a border appearing or
+    /// disappearing shows up in its width. Each border is drawn over the background of its own
+    /// state.
+    fn border(
+        mut self,
+        (width_a, a, background_a): (f32, Color, Color),
+        (width_b, b, background_b): (f32, Color, Color),
+    ) -> Self {
+        self.geometry = self.geometry.max((width_a - width_b).abs());
+        if width_a > 0.0 && width_b > 0.0 {
+            self = self.color(over(a, background_a), over(b, background_b));
+        }
+        self
+    }
+
+    fn radius(mut self, a: BorderRadius, b: BorderRadius) -> Self {
+        let (a, b): ([f32; 4], [f32; 4]) = (a.into(), b.into());
+        for (a, b) in a.iter().zip(b) {
+            self.geometry = self.geometry.max((a - b).abs());
+        }
+        self
+    }
+
+    fn offset(mut self, a: Vector, b: Vector) -> Self {
+        self.geometry = self.geometry.max((a.x - b.x).abs()).max((a.y - b.y).abs());
+        self
+    }
+}
+
+fn button_difference(a: &button::Appearance, b: &button::Appearance, canvas: Color) -> Difference {
+    let (background_a, background_b) = (
+        effective_background(a.background, canvas),
+        effective_background(b.background, canvas),
+    );
+    Difference::default()
+        .color(background_a, background_b)
+        .color(
+            over(a.text_color, background_a),
+            over(b.text_color, background_b),
+        )
+        .border(
+            (a.border_width, a.border_color, background_a),
+            (b.border_width, b.border_color, background_b),
+        )
+        .radius(a.border_radius, b.border_radius)
+        .offset(a.shadow_offset, b.shadow_offset)
+}
+
+fn text_input_difference(
+    (a, text_a): (&text_input::Appearance, Color),
+    (b, text_b): (&text_input::Appearance, Color),
+    canvas: Color,
+) -> Difference {
+    let (background_a, background_b) = (
+        over(background_color(a.background), canvas),
+        over(background_color(b.background), canvas),
+    );
+    Difference::default()
+        .color(background_a, background_b)
+        .color(over(text_a, background_a), over(text_b, background_b))
+        .color(
+            over(a.icon_color, background_a),
+            over(b.icon_color, background_b),
+        )
+        .border(
+            (a.border_width, a.border_color, background_a),
+            (b.border_width, b.border_color, background_b),
+        )
+        .radius(a.border_radius, b.border_radius)
+}
+
+fn scrollbar_difference(
+    a: &scrollable::Scrollbar,
+    b: &scrollable::Scrollbar,
+    canvas: Color,
+) -> Difference {
+    let (background_a, background_b) = (
+        effective_background(a.background, canvas),
+        effective_background(b.background, canvas),
+    );
+    Difference::default()
+        .color(background_a, background_b)
+        .color(
+            over(a.scroller.color, background_a),
+            over(b.scroller.color, background_b),
+        )
+        .border(
+            (a.border_width, a.border_color, background_a),
+            (b.border_width, b.border_color, background_b),
+        )
+        .border(
+            (
+                a.scroller.border_width,
+                a.scroller.border_color,
+                over(a.scroller.color, background_a),
+            ),
+            (
+                b.scroller.border_width,
+                b.scroller.border_color,
+                over(b.scroller.color, background_b),
+            ),
+        )
+        .radius(a.border_radius, b.border_radius)
+        .radius(a.scroller.border_radius, b.scroller.border_radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::button::CustomButtonStyle;
+    use crate::styles::scrollable::{CustomScrollableStyle, ScrollableState};
+    use crate::styles::text_input::CustomTextInputStyle;
+
+    fn gray(value: u8) -> Color {
+        Color::from_rgb8(value, value, value)
+    }
+
+    /// A primary button whose states are all far apart, on a white window.
+    fn distinct() -> CustomButtonStyle {
+        CustomButtonStyle::new()
+            .all()
+            .text_color(Color::BLACK)
+            .active()
+            .background_color(gray(0x80))
+            .hovered()
+            .background_color(gray(0x60))
+            .pressed()
+            .background_color(gray(0x40))
+            .disabled()
+            .background_color(gray(0xc0))
+            .active()
+    }
+
+    /// The findings about the primary button when it is styled with `style`.
+    fn findings(style: CustomButtonStyle) -> Vec<(Rule, String)> {
+        let styles = StyleSet {
+            primary_button: style,
+            ..StyleSet::from_theme(&iced::Theme::Light)
+        };
+        indistinguishable_states(&styles, Color::WHITE, MIN_STATE_DIFFERENCE)
+            .into_iter()
+            .filter(|finding| finding.path.starts_with("button.primary."))
+            .map(|finding| (finding.rule, finding.path))
+            .collect()
+    }
+
+    fn hovered() -> Vec<(Rule, String)> {
+        vec![(
+            Rule::IndistinguishableStates,
+            String::from("button.primary.hovered"),
+        )]
+    }
+
+    #[test]
+    fn distinct_states_pass() {
+        assert_eq!(findings(distinct()), []);
+    }
+
+    #[test]
+    fn pairs_either_side_of_the_threshold() {
+        // ΔE2000 2.95 and 3.30 from the active gray.
+        assert!(delta_e2000(gray(0x80), gray(0x88)) < MIN_STATE_DIFFERENCE);
+        assert!(delta_e2000(gray(0x80), gray(0x89)) > MIN_STATE_DIFFERENCE);
+
+        let below = distinct().hovered().background_color(gray(0x88));
+        assert_eq!(findings(below), hovered());
+        let above = distinct().hovered().background_color(gray(0x89));
+        assert_eq!(findings(above), []);
+    }
+
+    #[test]
+    fn flags_a_hovered_state_that_was_never_set() {
+        let style = CustomButtonStyle::new()
+            .all()
+            .text_color(Color::BLACK)
+            .background_color(gray(0x80))
+            .pressed()
+            .background_color(gray(0x40))
+            .disabled()
+            .background_color(gray(0xc0))
+            .active();
+        assert_eq!(findings(style), hovered());
+    }
+
+    #[test]
+    fn disabled_states_have_their_own_rule() {
+        let style = distinct().disabled().background_color(gray(0x80));
+        assert_eq!(
+            findings(style),
+            [(
+                Rule::IndistinguishableDisabled,
+                String::from("button.primary.disabled"),
+            )]
+        );
+    }
+
+    #[test]
+    fn border_colors_count_only_when_both_states_draw_a_border() {
+        let same = distinct().hovered().background_color(gray(0x80));
+        let colored = |width_a, width_b| {
+            same.active()
+                .border_width(width_a)
+                .border_color(Color::BLACK)
+                .hovered()
+                .border_width(width_b)
+                .border_color(Color::WHITE)
+        };
+
+        assert_eq!(findings(colored(0.0, 0.0)), hovered());
+        assert_eq!(findings(colored(2.0, 2.0)), []);
+        // A border appearing is a change of width.
+        assert_eq!(findings(colored(0.0, 2.0)), []);
+    }
+
+    #[test]
+    fn borders_are_drawn_over_the_button_background() {
+        // Faint borders that differ on the white window but vanish on the navy button.
+        let navy = Color::from_rgb(0.0, 0.0, 0.5);
+        let style = distinct()
+            .active()
+            .background_color(navy)
+            .border_width(1.0)
+            .border_color(Color::from_rgba(0.0, 0.0, 0.0, 0.04))
+            .hovered()
+            .background_color(navy)
+            .border_width(1.0)
+            .border_color(Color::from_rgba(1.0, 0.0, 0.0, 0.04));
+        assert_eq!(findings(style), hovered());
+    }
+
+    #[test]
+    fn geometry_changes_below_a_pixel_are_not_feedback() {
+        let same = distinct().hovered().background_color(gray(0x80));
+        let radius = |radius: f32| same.hovered().border_radius(radius);
+        let offset = |y| same.hovered().shadow_offset(Vector::new(0.0, y));
+
+        assert_eq!(findings(radius(0.5)), hovered());
+        assert_eq!(findings(radius(1.0)), []);
+        assert_eq!(findings(offset(0.5)), hovered());
+        assert_eq!(findings(offset(1.0)), []);
+    }
+
+    /// A text input whose hovered and disabled states are far from active, and whose focused
+    /// state matches active.
+    fn input() -> CustomTextInputStyle {
+        CustomTextInputStyle::new()
+            .all()
+            .background_color(Color::WHITE)
+            .border_width(1.0)
+            .border_color(gray(0x80))
+            .icon_color(gray(0x80))
+            .value_color(Color::BLACK)
+            .hovered()
+            .border_color(gray(0x40))
+            .disabled()
+            .background_color(gray(0xc0))
+            .active()
+    }
+
+    fn focused(style: CustomTextInputStyle) -> Vec<(Rule, String)> {
+        let styles = StyleSet {
+            text_input: style,
+            ..StyleSet::from_theme(&iced::Theme::Light)
+        };
+        indistinguishable_states(&styles, Color::WHITE, MIN_STATE_DIFFERENCE)
+            .into_iter()
+            .filter(|finding| finding.path.starts_with("text_input."))
+            .map(|finding| (finding.rule, finding.path))
+            .collect()
+    }
+
+    #[test]
+    fn text_inputs_compare_focused_with_active() {
+        let flagged = vec![(
+            Rule::IndistinguishableStates,
+            String::from("text_input.focused"),
+        )];
+
+        assert_eq!(focused(input()), flagged);
+        assert_eq!(focused(input().focused().border_color(Color::BLACK)), []);
+        assert_eq!(focused(input().focused().border_width(2.0)), []);
+        // The icon is part of the feedback too.
+        assert_eq!(focused(input().focused().icon_color(Color::BLACK)), []);
+        assert_eq!(focused(input().focused().icon_color(gray(0x88))), flagged);
+    }
+
+    /// A scrollbar whose dragged scroller matches the active one, in both orientations.
+    fn scrollbar() -> CustomScrollableStyle {
+        CustomScrollableStyle::new()
+            .all()
+            .background_color(gray(0xe0))
+            .scroller_color(gray(0x80))
+            .hovered_over_scrollbar()
+            .scroller_color(gray(0x40))
+            .active()
+    }
+
+    fn dragging(style: CustomScrollableStyle) -> Vec<(Rule, String)> {
+        let styles = StyleSet {
+            scrollable: style,
+            ..StyleSet::from_theme(&iced::Theme::Light)
+        };
+        indistinguishable_states(&styles, Color::WHITE, MIN_STATE_DIFFERENCE)
+            .into_iter()
+            .filter(|finding| finding.path.starts_with("scrollable."))
+            .map(|finding| (finding.rule, finding.path))
+            .collect()
+    }
+
+    #[test]
+    fn scrollbars_compare_dragging_with_active() {
+        let flagged = vec![
+            (
+                Rule::IndistinguishableStates,
+                String::from("scrollable.dragging"),
+            ),
+            (
+                Rule::IndistinguishableStates,
+                String::from("scrollable.dragging_horizontal"),
+            ),
+        ];
+
+        assert_eq!(dragging(scrollbar()), flagged);
+        assert_eq!(
+            dragging(scrollbar().dragging().scroller_color(gray(0x40))),
+            []
+        );
+        assert_eq!(
+            dragging(scrollbar().dragging_horizontal().scroller_color(gray(0x40))),
+            flagged[..1]
+        );
+    }
+
+    #[test]
+    fn scroller_borders_are_compared_over_the_scroller() {
+        let navy = Color::from_rgb(0.0, 0.0, 0.5);
+        let bordered = |active: Color, dragging: Color| {
+            scrollbar()
+                .all_except(
+                    ScrollableState::HOVERED_OVER_SCROLLBAR
+                        | ScrollableState::HOVERED_HORIZONTAL_OVER_SCROLLBAR,
+                )
+                .scroller_color(navy)
+                .scroller_border_width(1.0)
+                .scroller_border_color(active)
+                .dragging()
+                .scroller_border_color(dragging)
+        };
+
+        assert_eq!(dragging(bordered(Color::BLACK, Color::WHITE)), []);
+        // Faint borders that would differ on the light rail but vanish on the navy scroller.
+        let faint = dragging(bordered(
+            Color::from_rgba(0.0, 0.0, 0.0, 0.04),
+            Color::from_rgba(1.0, 0.0, 0.0, 0.04),
+        ));
+        assert_eq!(faint.len(), 2);
+        // A scroller border appearing is a change of width.
+        let appearing = scrollbar().dragging().scroller_border_width(1.0);
+        assert_eq!(dragging(appearing), []);
+    }
+}
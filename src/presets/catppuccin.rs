@@ -0,0 +1,184 @@
+//! Catppuccin, following its style guide: `base` panes, `surface` elements, `overlay` borders,
+//! `blue` for primary actions and `lavender` for the active border.
+
+use super::with_alpha;
+use crate::color::{mix, rgb};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Radius, TokenTable};
+use iced::theme::Palette;
+
+pub(super) struct Flavor {
+    green: u32,
+    red: u32,
+    blue: u32,
+    lavender: u32,
+    text: u32,
+    subtext0: u32,
+    overlay2: u32,
+    overlay0: u32,
+    surface2: u32,
+    surface1: u32,
+    surface0: u32,
+    base: u32,
+    mantle: u32,
+}
+
+pub(super) const LATTE: Flavor = Flavor {
+    green: 0x40a02b,
+    red: 0xd20f39,
+    blue: 0x1e66f5,
+    lavender: 0x7287fd,
+    text: 0x4c4f69,
+    subtext0: 0x6c6f85,
+    overlay2: 0x7c7f93,
+    overlay0: 0x9ca0b0,
+    surface2: 0xacb0be,
+    surface1: 0xbcc0cc,
+    surface0: 0xccd0da,
+    base: 0xeff1f5,
+    mantle: 0xe6e9ef,
+};
+
+pub(super) const MOCHA: Flavor = Flavor {
+    green: 0xa6e3a1,
+    red: 0xf38ba8,
+    blue: 0x89b4fa,
+    lavender: 0xb4befe,
+    text: 0xcdd6f4,
+    subtext0: 0xa6adc8,
+    overlay2: 0x9399b2,
+    overlay0: 0x6c7086,
+    surface2: 0x585b70,
+    surface1: 0x45475a,
+    surface0: 0x313244,
+    base: 0x1e1e2e,
+    mantle: 0x181825,
+};
+
+pub(super) fn palette(flavor: &Flavor) -> Palette {
+    Palette {
+        background: rgb(flavor.base),
+        text: rgb(flavor.text),
+        primary: rgb(flavor.blue),
+        success: rgb(flavor.green),
+        danger: rgb(flavor.red),
+    }
+}
+
+pub(super) fn styles(flavor: &Flavor, tokens: &TokenTable) -> StyleSet {
+    let f = flavor;
+    // Accents take `base` as their label in every flavor, the style guide pairs them that way.
+    let label = rgb(f.base);
+
+    let button = CustomButtonStyle::new()
+        .tokens(tokens)
+        .all()
+        .border_radius(Radius::Md);
+    let filled = |base: u32| {
+        button
+            .all()
+            .background_color(rgb(base))
+            .text_color(label)
+            .hovered()
+            .background_color(mix(rgb(base), rgb(f.text), 0.12))
+            .pressed()
+            .background_color(mix(rgb(base), rgb(f.base), 0.15))
+            .disabled()
+            .background_color(rgb(f.surface0))
+            .text_color(rgb(f.overlay0))
+            .active()
+    };
+
+    let mut styles = StyleSet::from_palette(palette(f));
+    styles.primary_button = filled(f.blue);
+    styles.secondary_button = button
+        .all()
+        .background_color(rgb(f.surface0))
+        .text_color(rgb(f.text))
+        .hovered()
+        .background_color(rgb(f.surface1))
+        .pressed()
+        .background_color(rgb(f.surface2))
+        .disabled()
+        .background_color(rgb(f.surface0))
+        .text_color(rgb(f.overlay0))
+        .active();
+    styles.positive_button = filled(f.green);
+    styles.destructive_button = filled(f.red);
+    styles.text_button = button
+        .all()
+        .text_color(rgb(f.blue))
+        .hovered()
+        .background_color(with_alpha(rgb(f.overlay2), 0.15))
+        .pressed()
+        .background_color(with_alpha(rgb(f.overlay2), 0.25))
+        .disabled()
+        .text_color(rgb(f.overlay0))
+        .active();
+
+    styles.box_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .background_color(rgb(f.mantle))
+        .text_color(Some(rgb(f.text)))
+        .border_color(rgb(f.surface0))
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Lg);
+    styles.transparent_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .text_color(Some(rgb(f.text)));
+
+    styles.scrollable = CustomScrollableStyle::new()
+        .tokens(tokens)
+        .all()
+        .scroller_border_radius(Radius::Md)
+        .scroller_color(rgb(f.surface1))
+        .hovered()
+        .scroller_color(rgb(f.surface2))
+        .hovered_horizontal()
+        .scroller_color(rgb(f.surface2))
+        .hovered_over_scrollbar()
+        .scroller_color(rgb(f.overlay0))
+        .hovered_horizontal_over_scrollbar()
+        .scroller_color(rgb(f.overlay0))
+        .dragging()
+        .scroller_color(rgb(f.lavender))
+        .dragging_horizontal()
+        .scroller_color(rgb(f.lavender))
+        .active();
+
+    styles.text_input = CustomTextInputStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(rgb(f.surface0))
+        .border_color(rgb(f.overlay0))
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Sm)
+        .icon_color(rgb(f.subtext0))
+        .hovered()
+        .border_color(rgb(f.overlay2))
+        .focused()
+        .border_color(rgb(f.lavender))
+        .border_width(BorderWidth::Thin)
+        .disabled()
+        .background_color(rgb(f.mantle))
+        .border_color(rgb(f.surface1))
+        .active()
+        .placeholder_color(rgb(f.overlay2))
+        .value_color(rgb(f.text))
+        .disabled_color(rgb(f.overlay0))
+        .selection_color(with_alpha(rgb(f.overlay2), 0.25));
+
+    styles.text = CustomTextStyle::new().color(rgb(f.text));
+    #[cfg(feature = "svg")]
+    {
+        styles.svg = CustomSvgStyle::new().color(Some(rgb(f.subtext0)));
+    }
+    styles
+}
@@ -0,0 +1,167 @@
+//! Dracula, following the color roles of the Dracula specification.
+
+use super::with_alpha;
+use crate::color::{mix, rgb};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Radius, TokenTable};
+use iced::theme::Palette;
+use iced::Color;
+
+struct Dracula {
+    background: Color,
+    darker: Color,
+    current_line: Color,
+    foreground: Color,
+    comment: Color,
+    cyan: Color,
+    green: Color,
+    pink: Color,
+    purple: Color,
+    red: Color,
+}
+
+fn dracula() -> Dracula {
+    Dracula {
+        background: rgb(0x282a36),
+        darker: rgb(0x21222c),
+        current_line: rgb(0x44475a),
+        foreground: rgb(0xf8f8f2),
+        comment: rgb(0x6272a4),
+        cyan: rgb(0x8be9fd),
+        green: rgb(0x50fa7b),
+        pink: rgb(0xff79c6),
+        purple: rgb(0xbd93f9),
+        red: rgb(0xff5555),
+    }
+}
+
+pub(super) fn palette() -> Palette {
+    let d = dracula();
+    Palette {
+        background: d.background,
+        text: d.foreground,
+        primary: d.purple,
+        success: d.green,
+        danger: d.red,
+    }
+}
+
+pub(super) fn styles(tokens: &TokenTable) -> StyleSet {
+    let d = dracula();
+
+    // The bright accents all carry dark labels; hover brightens towards the foreground and
+    // pressing sinks back towards the background.
+    let button = CustomButtonStyle::new()
+        .tokens(tokens)
+        .all()
+        .border_radius(Radius::Md);
+    let filled = |base: Color| {
+        button
+            .all()
+            .background_color(base)
+            .text_color(d.background)
+            .hovered()
+            .background_color(mix(base, d.foreground, 0.2))
+            .pressed()
+            .background_color(mix(base, d.background, 0.2))
+            .disabled()
+            .background_color(d.current_line)
+            .text_color(d.comment)
+            .active()
+    };
+
+    let mut styles = StyleSet::from_palette(palette());
+    styles.primary_button = filled(d.purple);
+    styles.secondary_button = button
+        .all()
+        .background_color(d.current_line)
+        .text_color(d.foreground)
+        .border_color(d.comment)
+        .border_width(BorderWidth::Hairline)
+        .hovered()
+        .border_color(d.purple)
+        .pressed()
+        .background_color(d.darker)
+        .border_color(d.pink)
+        .disabled()
+        .text_color(d.comment)
+        .border_color(d.current_line)
+        .active();
+    styles.positive_button = filled(d.green);
+    styles.destructive_button = filled(d.red);
+    styles.text_button = button
+        .all()
+        .text_color(d.cyan)
+        .hovered()
+        .text_color(d.pink)
+        .pressed()
+        .text_color(d.purple)
+        .disabled()
+        .text_color(d.comment)
+        .active();
+
+    styles.box_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .background_color(d.darker)
+        .text_color(Some(d.foreground))
+        .border_color(d.current_line)
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Md);
+    styles.transparent_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .text_color(Some(d.foreground));
+
+    styles.scrollable = CustomScrollableStyle::new()
+        .tokens(tokens)
+        .all()
+        .scroller_border_radius(Radius::Lg)
+        .scroller_color(d.current_line)
+        .hovered()
+        .scroller_color(d.comment)
+        .hovered_horizontal()
+        .scroller_color(d.comment)
+        .hovered_over_scrollbar()
+        .scroller_color(d.purple)
+        .hovered_horizontal_over_scrollbar()
+        .scroller_color(d.purple)
+        .dragging()
+        .scroller_color(d.pink)
+        .dragging_horizontal()
+        .scroller_color(d.pink)
+        .active();
+
+    styles.text_input = CustomTextInputStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(d.darker)
+        .border_color(d.current_line)
+        .border_width(BorderWidth::Thin)
+        .border_radius(Radius::Sm)
+        .icon_color(d.comment)
+        .hovered()
+        .border_color(d.comment)
+        .focused()
+        .border_color(d.purple)
+        .disabled()
+        .background_color(d.background)
+        .border_color(d.background)
+        .active()
+        .placeholder_color(d.comment)
+        .value_color(d.foreground)
+        .disabled_color(d.comment)
+        .selection_color(with_alpha(d.comment, 0.6));
+
+    styles.text = CustomTextStyle::new().color(d.foreground);
+    #[cfg(feature = "svg")]
+    {
+        styles.svg = CustomSvgStyle::new().color(Some(d.purple));
+    }
+    styles
+}
@@ -0,0 +1,192 @@
+//! Gruvbox by morhetz. Dark mode uses the bright accents, light mode the faded ones, so labels
+//! keep their contrast on either background.
+
+use super::with_alpha;
+use crate::color::{mix, rgb};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Radius, TokenTable};
+use iced::theme::Palette;
+use iced::Color;
+
+struct Gruvbox {
+    bg0: Color,
+    bg1: Color,
+    bg2: Color,
+    bg3: Color,
+    fg: Color,
+    fg4: Color,
+    gray: Color,
+    red: Color,
+    green: Color,
+    yellow: Color,
+    blue: Color,
+    aqua: Color,
+}
+
+fn gruvbox(dark: bool) -> Gruvbox {
+    if dark {
+        Gruvbox {
+            bg0: rgb(0x282828),
+            bg1: rgb(0x3c3836),
+            bg2: rgb(0x504945),
+            bg3: rgb(0x665c54),
+            fg: rgb(0xebdbb2),
+            fg4: rgb(0xa89984),
+            gray: rgb(0x928374),
+            red: rgb(0xfb4934),
+            green: rgb(0xb8bb26),
+            yellow: rgb(0xfabd2f),
+            blue: rgb(0x83a598),
+            aqua: rgb(0x8ec07c),
+        }
+    } else {
+        Gruvbox {
+            bg0: rgb(0xfbf1c7),
+            bg1: rgb(0xebdbb2),
+            bg2: rgb(0xd5c4a1),
+            bg3: rgb(0xbdae93),
+            fg: rgb(0x3c3836),
+            fg4: rgb(0x7c6f64),
+            gray: rgb(0x928374),
+            red: rgb(0x9d0006),
+            green: rgb(0x79740e),
+            yellow: rgb(0xb57614),
+            blue: rgb(0x076678),
+            aqua: rgb(0x427b58),
+        }
+    }
+}
+
+pub(super) fn palette(dark: bool) -> Palette {
+    let g = gruvbox(dark);
+    Palette {
+        background: g.bg0,
+        text: g.fg,
+        primary: g.blue,
+        success: g.green,
+        danger: g.red,
+    }
+}
+
+pub(super) fn styles(dark: bool, tokens: &TokenTable) -> StyleSet {
+    let g = gruvbox(dark);
+    // Bright accents take the dark background as their label, faded ones the light one.
+    let label = g.bg0;
+
+    let button = CustomButtonStyle::new()
+        .tokens(tokens)
+        .all()
+        .border_radius(Radius::Xs);
+    let filled = |base: Color| {
+        button
+            .all()
+            .background_color(base)
+            .text_color(label)
+            .hovered()
+            .background_color(mix(base, g.fg, 0.15))
+            .pressed()
+            .background_color(mix(base, g.bg0, 0.2))
+            .disabled()
+            .background_color(g.bg2)
+            .text_color(g.gray)
+            .active()
+    };
+
+    let mut styles = StyleSet::from_palette(palette(dark));
+    styles.primary_button = filled(g.blue);
+    styles.secondary_button = button
+        .all()
+        .background_color(g.bg1)
+        .text_color(g.fg)
+        .border_color(g.bg3)
+        .border_width(BorderWidth::Hairline)
+        .hovered()
+        .background_color(g.bg2)
+        .pressed()
+        .background_color(g.bg3)
+        .disabled()
+        .background_color(g.bg1)
+        .text_color(g.gray)
+        .border_color(g.bg2)
+        .active();
+    styles.positive_button = filled(g.green);
+    styles.destructive_button = filled(g.red);
+    styles.text_button = button
+        .all()
+        .text_color(g.aqua)
+        .hovered()
+        .background_color(g.bg1)
+        .pressed()
+        .background_color(g.bg2)
+        .disabled()
+        .text_color(g.gray)
+        .active();
+
+    styles.box_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .background_color(g.bg1)
+        .text_color(Some(g.fg))
+        .border_color(g.bg2)
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Xs);
+    styles.transparent_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .text_color(Some(g.fg));
+
+    styles.scrollable = CustomScrollableStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(with_alpha(g.bg1, 0.5))
+        .scroller_color(g.bg2)
+        .hovered()
+        .scroller_color(g.bg3)
+        .hovered_horizontal()
+        .scroller_color(g.bg3)
+        .hovered_over_scrollbar()
+        .background_color(g.bg1)
+        .scroller_color(g.fg4)
+        .hovered_horizontal_over_scrollbar()
+        .background_color(g.bg1)
+        .scroller_color(g.fg4)
+        .dragging()
+        .scroller_color(g.yellow)
+        .dragging_horizontal()
+        .scroller_color(g.yellow)
+        .active();
+
+    styles.text_input = CustomTextInputStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(if dark { g.bg1 } else { g.bg0 })
+        .border_color(g.bg3)
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Xs)
+        .icon_color(g.fg4)
+        .hovered()
+        .border_color(g.fg4)
+        .focused()
+        .border_color(g.yellow)
+        .border_width(BorderWidth::Thin)
+        .disabled()
+        .background_color(if dark { g.bg0 } else { g.bg1 })
+        .border_color(g.bg2)
+        .active()
+        .placeholder_color(g.gray)
+        .value_color(g.fg)
+        .disabled_color(g.gray)
+        .selection_color(if dark { g.bg3 } else { g.bg2 });
+
+    styles.text = CustomTextStyle::new().color(g.fg);
+    #[cfg(feature = "svg")]
+    {
+        styles.svg = CustomSvgStyle::new().color(Some(g.fg4));
+    }
+    styles
+}
@@ -0,0 +1,124 @@
+mod catppuccin;
+mod dracula;
+mod gruvbox;
+mod nord;
+mod solarized;
+mod tokyo_night;
+
+use crate::style_set::StyleSet;
+use crate::tokens::TokenTable;
+use iced::theme::Palette;
+use iced::Color;
+use std::fmt;
+
+/// Style sets tuned by hand for popular open color schemes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Preset {
+    Nord,
+    NordLight,
+    Dracula,
+    GruvboxDark,
+    GruvboxLight,
+    SolarizedDark,
+    SolarizedLight,
+    CatppuccinLatte,
+    CatppuccinMocha,
+    TokyoNight,
+    TokyoNightDay,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 11] = [
+        Preset::Nord,
+        Preset::NordLight,
+        Preset::Dracula,
+        Preset::GruvboxDark,
+        Preset::GruvboxLight,
+        Preset::SolarizedDark,
+        Preset::SolarizedLight,
+        Preset::CatppuccinLatte,
+        Preset::CatppuccinMocha,
+        Preset::TokyoNight,
+        Preset::TokyoNightDay,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Preset::Nord => "Nord",
+            Preset::NordLight => "Nord Light",
+            Preset::Dracula => "Dracula",
+            Preset::GruvboxDark => "Gruvbox Dark",
+            Preset::GruvboxLight => "Gruvbox Light",
+            Preset::SolarizedDark => "Solarized Dark",
+            Preset::SolarizedLight => "Solarized Light",
+            Preset::CatppuccinLatte => "Catppuccin Latte",
+            Preset::CatppuccinMocha => "Catppuccin Mocha",
+            Preset::TokyoNight => "Tokyo Night",
+            Preset::TokyoNightDay => "Tokyo Night Day",
+        }
+    }
+
+    pub const fn is_dark(self) -> bool {
+        !matches!(
+            self,
+            Preset::NordLight
+                | Preset::GruvboxLight
+                | Preset::SolarizedLight
+                | Preset::CatppuccinLatte
+                | Preset::TokyoNightDay
+        )
+    }
+
+    pub fn palette(self) -> Palette {
+        match self {
+            Preset::Nord => nord::palette(true),
+            Preset::NordLight => nord::palette(false),
+            Preset::Dracula => dracula::palette(),
+            Preset::GruvboxDark => gruvbox::palette(true),
+            Preset::GruvboxLight => gruvbox::palette(false),
+            Preset::SolarizedDark => solarized::palette(true),
+            Preset::SolarizedLight => solarized::palette(false),
+            Preset::CatppuccinLatte => catppuccin::palette(&catppuccin::LATTE),
+            Preset::CatppuccinMocha => catppuccin::palette(&catppuccin::MOCHA),
+            Preset::TokyoNight => tokyo_night::palette(&tokyo_night::NIGHT),
+            Preset::TokyoNightDay => tokyo_night::palette(&tokyo_night::DAY),
+        }
+    }
+
+    pub fn styles(self) -> StyleSet {
+        self.styles_with(&TokenTable::DEFAULT)
+    }
+
+    /// The preset's styles with radii and border widths resolved against `tokens`, so one table
+    /// restyles every button, container, text input and scrollbar.
+    pub fn styles_with(self, tokens: &TokenTable) -> StyleSet {
+        match self {
+            Preset::Nord => nord::styles(true, tokens),
+            Preset::NordLight => nord::styles(false, tokens),
+            Preset::Dracula => dracula::styles(tokens),
+            Preset::GruvboxDark => gruvbox::styles(true, tokens),
+            Preset::GruvboxLight => gruvbox::styles(false, tokens),
+            Preset::SolarizedDark => solarized::styles(true, tokens),
+            Preset::SolarizedLight => solarized::styles(false, tokens),
+            Preset::CatppuccinLatte => catppuccin::styles(&catppuccin::LATTE, tokens),
+            Preset::CatppuccinMocha => catppuccin::styles(&catppuccin::MOCHA, tokens),
+            Preset::TokyoNight => tokyo_night::styles(&tokyo_night::NIGHT, tokens),
+            Preset::TokyoNightDay => tokyo_night::styles(&tokyo_night::DAY, tokens),
+        }
+    }
+
+    /// The built-in theme the preset's styles are meant to be used with.
+    pub fn theme(self) -> iced::Theme {
+        iced::Theme::custom(self.palette())
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+fn with_alpha(color: Color, a: f32) -> Color {
+    Color { a, ..color }
+}
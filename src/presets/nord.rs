@@ -0,0 +1,180 @@
+//! Nord by Arctic Ice Studio. The light variant uses Snow Storm as its background, as the Nord
+//! guidelines suggest for bright ambiance designs.
+
+use super::with_alpha;
+use crate::color::{mix, rgb};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Radius, TokenTable};
+use iced::theme::Palette;
+use iced::Color;
+
+/// Polar Night `nord0`-`nord3`, Snow Storm `nord4`-`nord6`, Frost `nord7`-`nord10` and Aurora
+/// `nord11`-`nord15`.
+fn nord(index: usize) -> Color {
+    const NORD: [u32; 16] = [
+        0x2e3440, 0x3b4252, 0x434c5e, 0x4c566a, 0xd8dee9, 0xe5e9f0, 0xeceff4, 0x8fbcbb, 0x88c0d0,
+        0x81a1c1, 0x5e81ac, 0xbf616a, 0xd08770, 0xebcb8b, 0xa3be8c, 0xb48ead,
+    ];
+    rgb(NORD[index])
+}
+
+pub(super) fn palette(dark: bool) -> Palette {
+    Palette {
+        background: if dark { nord(0) } else { nord(6) },
+        text: if dark { nord(4) } else { nord(0) },
+        primary: if dark { nord(8) } else { nord(10) },
+        success: nord(14),
+        danger: nord(11),
+    }
+}
+
+pub(super) fn styles(dark: bool, tokens: &TokenTable) -> StyleSet {
+    // Surfaces step away from the background: nord1/nord2 on Polar Night, nord5/nord4 on Snow
+    // Storm. Frost is the accent, a lighter shade on dark and the deeper nord10 on light.
+    let (background, surface, raised) = if dark {
+        (nord(0), nord(1), nord(2))
+    } else {
+        (nord(6), nord(5), nord(4))
+    };
+    let text = if dark { nord(4) } else { nord(0) };
+    let muted = if dark { nord(9) } else { nord(3) };
+    let (accent, accent_hovered, accent_pressed) = if dark {
+        (nord(8), nord(7), nord(9))
+    } else {
+        (
+            nord(10),
+            mix(nord(10), nord(0), 0.15),
+            mix(nord(10), nord(0), 0.3),
+        )
+    };
+    let on_accent = if dark { nord(0) } else { nord(6) };
+
+    let subtle = if dark {
+        mix(nord(3), nord(4), 0.4)
+    } else {
+        nord(3)
+    };
+
+    let button = CustomButtonStyle::new()
+        .tokens(tokens)
+        .all()
+        .border_radius(Radius::Sm);
+    let filled = |base: Color, hovered: Color, pressed: Color, label: Color| {
+        button
+            .all()
+            .background_color(base)
+            .text_color(label)
+            .hovered()
+            .background_color(hovered)
+            .pressed()
+            .background_color(pressed)
+            .disabled()
+            .background_color(raised)
+            .text_color(subtle)
+            .active()
+    };
+
+    let mut styles = StyleSet::from_palette(palette(dark));
+    styles.primary_button = filled(accent, accent_hovered, accent_pressed, on_accent);
+    styles.secondary_button = filled(
+        raised,
+        if dark {
+            nord(3)
+        } else {
+            mix(nord(4), nord(3), 0.25)
+        },
+        surface,
+        text,
+    )
+    .all()
+    .border_color(nord(3))
+    .border_width(BorderWidth::Hairline)
+    .active();
+    styles.positive_button = filled(
+        nord(14),
+        mix(nord(14), nord(6), 0.15),
+        mix(nord(14), nord(0), 0.15),
+        nord(0),
+    );
+    styles.destructive_button = filled(
+        nord(11),
+        mix(nord(11), nord(6), 0.15),
+        mix(nord(11), nord(0), 0.15),
+        nord(6),
+    );
+    styles.text_button = button
+        .all()
+        .text_color(accent)
+        .hovered()
+        .background_color(with_alpha(nord(9), 0.15))
+        .pressed()
+        .background_color(with_alpha(nord(9), 0.25))
+        .disabled()
+        .text_color(subtle)
+        .active();
+
+    styles.box_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .background_color(surface)
+        .text_color(Some(text))
+        .border_color(raised)
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Md);
+    styles.transparent_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .text_color(Some(text));
+
+    styles.scrollable = CustomScrollableStyle::new()
+        .tokens(tokens)
+        .all()
+        .scroller_border_radius(Radius::Sm)
+        .scroller_color(with_alpha(nord(3), 0.6))
+        .hovered()
+        .scroller_color(nord(3))
+        .hovered_horizontal()
+        .scroller_color(nord(3))
+        .hovered_over_scrollbar()
+        .scroller_color(muted)
+        .hovered_horizontal_over_scrollbar()
+        .scroller_color(muted)
+        .dragging()
+        .scroller_color(accent)
+        .dragging_horizontal()
+        .scroller_color(accent)
+        .active();
+
+    styles.text_input = CustomTextInputStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(if dark { surface } else { background })
+        .border_color(raised)
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Sm)
+        .icon_color(muted)
+        .hovered()
+        .border_color(nord(3))
+        .focused()
+        .border_color(accent)
+        .disabled()
+        .background_color(if dark { background } else { surface })
+        .border_color(surface)
+        .active()
+        .placeholder_color(subtle)
+        .value_color(text)
+        .disabled_color(subtle)
+        .selection_color(with_alpha(nord(9), 0.4));
+
+    styles.text = CustomTextStyle::new().color(text);
+    #[cfg(feature = "svg")]
+    {
+        styles.svg = CustomSvgStyle::new().color(Some(muted));
+    }
+    styles
+}
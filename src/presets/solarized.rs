@@ -0,0 +1,184 @@
+//! Solarized by Ethan Schoonover. Both modes share the accents and swap the monotone ramp:
+//! `base03`-`base0` on dark, `base3`-`base00` on light.
+
+use super::with_alpha;
+use crate::color::{mix, rgb};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Radius, TokenTable};
+use iced::theme::Palette;
+use iced::Color;
+
+const BASE03: u32 = 0x002b36;
+const BASE02: u32 = 0x073642;
+const BASE01: u32 = 0x586e75;
+const BASE00: u32 = 0x657b83;
+const BASE0: u32 = 0x839496;
+const BASE1: u32 = 0x93a1a1;
+const BASE2: u32 = 0xeee8d5;
+const BASE3: u32 = 0xfdf6e3;
+const YELLOW: u32 = 0xb58900;
+const RED: u32 = 0xdc322f;
+const VIOLET: u32 = 0x6c71c4;
+const BLUE: u32 = 0x268bd2;
+const CYAN: u32 = 0x2aa198;
+const GREEN: u32 = 0x859900;
+
+/// The monotone ramp for one mode, from the background outwards.
+struct Ramp {
+    background: Color,
+    highlight: Color,
+    secondary: Color,
+    body: Color,
+    emphasis: Color,
+}
+
+fn ramp(dark: bool) -> Ramp {
+    if dark {
+        Ramp {
+            background: rgb(BASE03),
+            highlight: rgb(BASE02),
+            secondary: rgb(BASE01),
+            body: rgb(BASE0),
+            emphasis: rgb(BASE1),
+        }
+    } else {
+        Ramp {
+            background: rgb(BASE3),
+            highlight: rgb(BASE2),
+            secondary: rgb(BASE1),
+            body: rgb(BASE00),
+            emphasis: rgb(BASE01),
+        }
+    }
+}
+
+pub(super) fn palette(dark: bool) -> Palette {
+    let r = ramp(dark);
+    Palette {
+        background: r.background,
+        text: r.body,
+        primary: rgb(BLUE),
+        success: rgb(GREEN),
+        danger: rgb(RED),
+    }
+}
+
+pub(super) fn styles(dark: bool, tokens: &TokenTable) -> StyleSet {
+    let r = ramp(dark);
+    // Accents are mid-tone in both modes. `base3` labels the deep ones, green is light enough
+    // to need `base03`.
+    let button = CustomButtonStyle::new()
+        .tokens(tokens)
+        .all()
+        .border_radius(Radius::Xs);
+    let filled = |base: Color, label: Color| {
+        button
+            .all()
+            .background_color(base)
+            .text_color(label)
+            .hovered()
+            .background_color(mix(base, rgb(BASE3), 0.12))
+            .pressed()
+            .background_color(mix(base, rgb(BASE03), 0.2))
+            .disabled()
+            .background_color(r.highlight)
+            .text_color(r.secondary)
+            .active()
+    };
+
+    let mut styles = StyleSet::from_palette(palette(dark));
+    styles.primary_button = filled(rgb(BLUE), rgb(BASE3));
+    styles.secondary_button = button
+        .all()
+        .background_color(r.highlight)
+        .text_color(r.emphasis)
+        .border_color(r.secondary)
+        .border_width(BorderWidth::Hairline)
+        .hovered()
+        .border_color(rgb(BLUE))
+        .pressed()
+        .background_color(r.background)
+        .border_color(rgb(BLUE))
+        .disabled()
+        .text_color(r.secondary)
+        .border_color(r.highlight)
+        .active();
+    styles.positive_button = filled(rgb(GREEN), rgb(BASE03));
+    styles.destructive_button = filled(rgb(RED), rgb(BASE3));
+    styles.text_button = button
+        .all()
+        .text_color(rgb(BLUE))
+        .hovered()
+        .text_color(rgb(VIOLET))
+        .pressed()
+        .background_color(r.highlight)
+        .text_color(rgb(VIOLET))
+        .disabled()
+        .text_color(r.secondary)
+        .active();
+
+    styles.box_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .background_color(r.highlight)
+        .text_color(Some(r.body))
+        .border_color(with_alpha(r.secondary, 0.5))
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Sm);
+    styles.transparent_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .text_color(Some(r.body));
+
+    styles.scrollable = CustomScrollableStyle::new()
+        .tokens(tokens)
+        .all()
+        .scroller_border_radius(Radius::Xs)
+        .scroller_color(with_alpha(r.secondary, 0.5))
+        .hovered()
+        .scroller_color(r.secondary)
+        .hovered_horizontal()
+        .scroller_color(r.secondary)
+        .hovered_over_scrollbar()
+        .scroller_color(r.body)
+        .hovered_horizontal_over_scrollbar()
+        .scroller_color(r.body)
+        .dragging()
+        .scroller_color(rgb(CYAN))
+        .dragging_horizontal()
+        .scroller_color(rgb(CYAN))
+        .active();
+
+    styles.text_input = CustomTextInputStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(r.background)
+        .border_color(r.secondary)
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Xs)
+        .icon_color(r.secondary)
+        .hovered()
+        .border_color(r.body)
+        .focused()
+        .border_color(rgb(BLUE))
+        .disabled()
+        .background_color(r.highlight)
+        .border_color(r.highlight)
+        .active()
+        .placeholder_color(r.secondary)
+        .value_color(r.emphasis)
+        .disabled_color(r.secondary)
+        .selection_color(with_alpha(rgb(YELLOW), 0.3));
+
+    styles.text = CustomTextStyle::new().color(r.body);
+    #[cfg(feature = "svg")]
+    {
+        styles.svg = CustomSvgStyle::new().color(Some(r.body));
+    }
+    styles
+}
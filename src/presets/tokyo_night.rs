@@ -0,0 +1,191 @@
+//! Tokyo Night by enkia, in its Night and Day styles.
+
+use super::with_alpha;
+use crate::color::{mix, rgb};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Radius, TokenTable};
+use iced::theme::Palette;
+
+pub(super) struct Style {
+    bg: u32,
+    bg_dark: u32,
+    bg_highlight: u32,
+    bg_visual: u32,
+    fg: u32,
+    fg_dark: u32,
+    fg_gutter: u32,
+    comment: u32,
+    dark3: u32,
+    blue: u32,
+    cyan: u32,
+    magenta: u32,
+    green: u32,
+    red: u32,
+    /// The label on accent fills, the dark background at night and white by day.
+    on_accent: u32,
+}
+
+pub(super) const NIGHT: Style = Style {
+    bg: 0x1a1b26,
+    bg_dark: 0x16161e,
+    bg_highlight: 0x292e42,
+    bg_visual: 0x283457,
+    fg: 0xc0caf5,
+    fg_dark: 0xa9b1d6,
+    fg_gutter: 0x3b4261,
+    comment: 0x565f89,
+    dark3: 0x545c7e,
+    blue: 0x7aa2f7,
+    cyan: 0x7dcfff,
+    magenta: 0xbb9af7,
+    green: 0x9ece6a,
+    red: 0xf7768e,
+    on_accent: 0x16161e,
+};
+
+pub(super) const DAY: Style = Style {
+    bg: 0xe1e2e7,
+    bg_dark: 0xd0d5e3,
+    bg_highlight: 0xc4c8da,
+    bg_visual: 0xb7c1e3,
+    fg: 0x3760bf,
+    fg_dark: 0x6172b0,
+    fg_gutter: 0xa8aecb,
+    comment: 0x848cb5,
+    dark3: 0x8990b3,
+    blue: 0x2e7de9,
+    cyan: 0x007197,
+    magenta: 0x9854f1,
+    green: 0x587539,
+    red: 0xf52a65,
+    on_accent: 0xffffff,
+};
+
+pub(super) fn palette(style: &Style) -> Palette {
+    Palette {
+        background: rgb(style.bg),
+        text: rgb(style.fg),
+        primary: rgb(style.blue),
+        success: rgb(style.green),
+        danger: rgb(style.red),
+    }
+}
+
+pub(super) fn styles(style: &Style, tokens: &TokenTable) -> StyleSet {
+    let s = style;
+    let label = rgb(s.on_accent);
+
+    let button = CustomButtonStyle::new()
+        .tokens(tokens)
+        .all()
+        .border_radius(Radius::Sm);
+    let filled = |base: u32| {
+        button
+            .all()
+            .background_color(rgb(base))
+            .text_color(label)
+            .hovered()
+            .background_color(mix(rgb(base), rgb(s.fg), 0.15))
+            .pressed()
+            .background_color(mix(rgb(base), rgb(s.bg_dark), 0.2))
+            .disabled()
+            .background_color(rgb(s.bg_highlight))
+            .text_color(rgb(s.dark3))
+            .active()
+    };
+
+    let mut styles = StyleSet::from_palette(palette(s));
+    styles.primary_button = filled(s.blue);
+    styles.secondary_button = button
+        .all()
+        .background_color(rgb(s.bg_highlight))
+        .text_color(rgb(s.fg))
+        .border_color(rgb(s.fg_gutter))
+        .border_width(BorderWidth::Hairline)
+        .hovered()
+        .background_color(rgb(s.bg_visual))
+        .pressed()
+        .background_color(rgb(s.bg_visual))
+        .border_color(rgb(s.blue))
+        .disabled()
+        .background_color(rgb(s.bg_dark))
+        .text_color(rgb(s.dark3))
+        .active();
+    styles.positive_button = filled(s.green);
+    styles.destructive_button = filled(s.red);
+    styles.text_button = button
+        .all()
+        .text_color(rgb(s.cyan))
+        .hovered()
+        .text_color(rgb(s.blue))
+        .pressed()
+        .text_color(rgb(s.magenta))
+        .disabled()
+        .text_color(rgb(s.dark3))
+        .active();
+
+    styles.box_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .background_color(rgb(s.bg_dark))
+        .text_color(Some(rgb(s.fg_dark)))
+        .border_color(rgb(s.fg_gutter))
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Md);
+    styles.transparent_container = CustomContainerStyle::new()
+        .tokens(tokens)
+        .text_color(Some(rgb(s.fg)));
+
+    styles.scrollable = CustomScrollableStyle::new()
+        .tokens(tokens)
+        .all()
+        .scroller_border_radius(Radius::Sm)
+        .scroller_color(with_alpha(rgb(s.fg_gutter), 0.8))
+        .hovered()
+        .scroller_color(rgb(s.fg_gutter))
+        .hovered_horizontal()
+        .scroller_color(rgb(s.fg_gutter))
+        .hovered_over_scrollbar()
+        .scroller_color(rgb(s.comment))
+        .hovered_horizontal_over_scrollbar()
+        .scroller_color(rgb(s.comment))
+        .dragging()
+        .scroller_color(rgb(s.blue))
+        .dragging_horizontal()
+        .scroller_color(rgb(s.blue))
+        .active();
+
+    styles.text_input = CustomTextInputStyle::new()
+        .tokens(tokens)
+        .all()
+        .background_color(rgb(s.bg_dark))
+        .border_color(rgb(s.fg_gutter))
+        .border_width(BorderWidth::Hairline)
+        .border_radius(Radius::Sm)
+        .icon_color(rgb(s.comment))
+        .hovered()
+        .border_color(rgb(s.dark3))
+        .focused()
+        .border_color(rgb(s.blue))
+        .disabled()
+        .background_color(rgb(s.bg))
+        .border_color(rgb(s.bg_highlight))
+        .active()
+        .placeholder_color(rgb(s.comment))
+        .value_color(rgb(s.fg))
+        .disabled_color(rgb(s.dark3))
+        .selection_color(rgb(s.bg_visual));
+
+    styles.text = CustomTextStyle::new().color(rgb(s.fg));
+    #[cfg(feature = "svg")]
+    {
+        styles.svg = CustomSvgStyle::new().color(Some(rgb(s.fg_dark)));
+    }
+    styles
+}
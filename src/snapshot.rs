@@ -0,0 +1,341 @@
+//! Headless renderings of every widget state, for comparing against golden images on machines
+//! without a GPU.
+//!
+//! Each state is laid out and drawn by the real iced widget and rasterized by iced's CPU
+//! renderer, `iced_tiny_skia`, so backgrounds, gradients, borders, shadows, text and icons come
+//! out as they would in an application. Text is set in the bundled Snapshot Sans, a renamed copy
+//! of DejaVu Sans, so it renders the same whichever fonts the machine has installed.
+
+use crate::styles::button::{ButtonStyle, CustomButtonStyle};
+use crate::styles::scrollable::{CustomScrollableStyle, ScrollableStyle};
+use crate::styles::text_input::{CustomTextInputStyle, TextInputStyle};
+use iced::advanced::layout::{Layout, Limits};
+use iced::advanced::renderer;
+use iced::advanced::text::Renderer as _;
+use iced::advanced::widget::Tree;
+use iced::widget::scrollable::{Direction, Properties};
+use iced::widget::text_input::{Icon, Side, State};
+use iced::widget::{text, Button, Column, Scrollable, Space, TextInput};
+use iced::{mouse, Color, Element, Font, Length, Pixels, Rectangle, Size, Theme};
+use iced_tiny_skia::graphics::Viewport;
+use iced_tiny_skia::Backend;
+use std::borrow::Cow;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+
+pub use tiny_skia::Pixmap;
+
+type Renderer = iced_tiny_skia::Renderer<Theme>;
+type Paragraph = <Renderer as iced::advanced::text::Renderer>::Paragraph;
+
+/// The font every snapshot is set in. Its family name is unique so that no installed font can
+/// stand in for it.
+const FONT: &[u8] = include_bytes!("../fonts/SnapshotSans.ttf");
+const FONT_NAME: &str = "Snapshot Sans";
+
+/// Set to rewrite the golden images instead of comparing against them.
+pub const UPDATE_VARIABLE: &str = "UPDATE_SNAPSHOTS";
+
+/// How far a rendering may stray from its golden image, to absorb anti-aliasing differences
+/// between tiny-skia builds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// Largest difference in any channel for a pixel to still count as equal.
+    pub channel: u8,
+    /// Share of pixels, from 0.0 to 1.0, that may differ by more than `channel`.
+    pub pixels: f32,
+}
+
+impl Tolerance {
+    pub const DEFAULT: Self = Self {
+        channel: 8,
+        pixels: 0.005,
+    };
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Png(String),
+    /// There is no golden image yet, the rendering was written next to where it should be.
+    Missing(PathBuf),
+    Mismatch {
+        golden: PathBuf,
+        differing: usize,
+        total: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Png(error) => write!(f, "{error}"),
+            Error::Missing(golden) => write!(
+                f,
+                "no golden image at {}, set {UPDATE_VARIABLE}=1 to create it",
+                golden.display()
+            ),
+            Error::Mismatch {
+                golden,
+                differing,
+                total,
+            } => write!(
+                f,
+                "{differing} of {total} pixels differ from {}",
+                golden.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+/// The active, hovered, pressed and disabled states, drawn over `canvas`.
+pub fn button(style: &CustomButtonStyle, canvas: Color) -> Vec<(&'static str, Pixmap)> {
+    [
+        ("active", style.active),
+        ("hovered", style.hovered),
+        ("pressed", style.pressed),
+        ("disabled", style.disabled),
+    ]
+    .into_iter()
+    .map(|(state, appearance)| {
+        // The widget picks its appearance from the cursor, which a headless rendering has none
+        // of, so every state of the style is the one being drawn.
+        let forced = ButtonStyle {
+            active: appearance,
+            hovered: appearance,
+            pressed: appearance,
+            disabled: appearance,
+        };
+        let button: Button<'_, (), Renderer> = Button::new(text("Button"))
+            .style(forced.as_custom())
+            .padding([10, 16])
+            .width(Length::Fill)
+            .height(Length::Fill);
+        let button = if state == "disabled" {
+            button
+        } else {
+            button.on_press(())
+        };
+        (state, render(button, (128, 56), canvas, |_| {}))
+    })
+    .collect()
+}
+
+/// The active, hovered, focused and disabled states, drawn over `canvas`. Active shows the
+/// value, hovered the placeholder, focused the value selected, disabled the value in the
+/// disabled color.
+pub fn text_input(style: &CustomTextInputStyle, canvas: Color) -> Vec<(&'static str, Pixmap)> {
+    [
+        ("active", style.active),
+        ("hovered", style.hovered),
+        ("focused", style.focused),
+        ("disabled", style.disabled),
+    ]
+    .into_iter()
+    .map(|(state, appearance)| {
+        let forced = TextInputStyle {
+            active: appearance,
+            hovered: appearance,
+            focused: appearance,
+            disabled: appearance,
+            ..TextInputStyle::from(*style)
+        };
+        let value = if state == "hovered" { "" } else { "Value" };
+        let icon = Icon {
+            font: Renderer::ICON_FONT,
+            code_point: Renderer::CHECKMARK_ICON,
+            size: None,
+            spacing: 4.0,
+            side: Side::Left,
+        };
+        let input: TextInput<'_, (), Renderer> = TextInput::new("Placeholder", value)
+            .icon(icon)
+            .style(forced.as_custom());
+        let input = if state == "disabled" {
+            input
+        } else {
+            input.on_input(|_| ())
+        };
+        let pixmap = render(input, (160, 48), canvas, |tree| {
+            if state == "focused" {
+                // A selection instead of the cursor, which blinks with the time of day.
+                let input = tree.state.downcast_mut::<State<Paragraph>>();
+                input.focus();
+                input.select_all();
+            }
+        });
+        (state, pixmap)
+    })
+    .collect()
+}
+
+/// All eight states over `canvas`, vertical ones with a scrollbar on the right, horizontal ones
+/// with a scrollbar at the bottom.
+pub fn scrollable(style: &CustomScrollableStyle, canvas: Color) -> Vec<(&'static str, Pixmap)> {
+    [
+        ("active", style.active, false),
+        ("hovered", style.hovered, false),
+        (
+            "hovered_over_scrollbar",
+            style.hovered_over_scrollbar,
+            false,
+        ),
+        ("dragging", style.dragging, false),
+        ("active_horizontal", style.active_horizontal, true),
+        ("hovered_horizontal", style.hovered_horizontal, true),
+        (
+            "hovered_horizontal_over_scrollbar",
+            style.hovered_horizontal_over_scrollbar,
+            true,
+        ),
+        ("dragging_horizontal", style.dragging_horizontal, true),
+    ]
+    .into_iter()
+    .map(|(state, scrollbar, horizontal)| {
+        let forced = ScrollableStyle {
+            active: scrollbar,
+            hovered: scrollbar,
+            hovered_over_scrollbar: scrollbar,
+            dragging: scrollbar,
+            active_horizontal: scrollbar,
+            hovered_horizontal: scrollbar,
+            hovered_horizontal_over_scrollbar: scrollbar,
+            dragging_horizontal: scrollbar,
+        };
+        // Content larger than the viewport in both directions, so there is something to scroll.
+        let content = Space::new(Length::Fixed(160.0), Length::Fixed(160.0));
+        let scrollable: Scrollable<'_, (), Renderer> = Scrollable::new(content)
+            .style(forced.as_custom())
+            .width(Length::Fill)
+            .height(Length::Fill);
+        let scrollable = if horizontal {
+            scrollable.direction(Direction::Horizontal(Properties::new()))
+        } else {
+            scrollable
+        };
+        (state, render(scrollable, (72, 72), canvas, |_| {}))
+    })
+    .collect()
+}
+
+/// Compares `pixmap` against the PNG at `golden`. When it differs, or there is no golden image
+/// yet, the rendering is written beside it with a `.new.png` extension for inspection. With
+/// [`UPDATE_VARIABLE`] set, the golden image is overwritten instead.
+pub fn compare(pixmap: &Pixmap, golden: &Path, tolerance: Tolerance) -> Result<(), Error> {
+    let png = |error: &dyn std::error::Error| Error::Png(error.to_string());
+    let write = |path: &Path| -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        pixmap.save_png(path).map_err(|error| png(&error))
+    };
+    let rejected = golden.with_extension("new.png");
+
+    if std::env::var_os(UPDATE_VARIABLE).is_some() {
+        return write(golden);
+    }
+    if !golden.exists() {
+        write(&rejected)?;
+        return Err(Error::Missing(golden.to_path_buf()));
+    }
+
+    let expected = Pixmap::load_png(golden).map_err(|error| png(&error))?;
+    let total = (pixmap.width() * pixmap.height()) as usize;
+    let differing = if (expected.width(), expected.height()) == (pixmap.width(), pixmap.height()) {
+        pixmap
+            .data()
+            .chunks_exact(4)
+            .zip(expected.data().chunks_exact(4))
+            .filter(|(actual, expected)| {
+                actual
+                    .iter()
+                    .zip(expected.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > tolerance.channel)
+            })
+            .count()
+    } else {
+        total
+    };
+
+    if differing as f32 > tolerance.pixels * total as f32 {
+        write(&rejected)?;
+        return Err(Error::Mismatch {
+            golden: golden.to_path_buf(),
+            differing,
+            total,
+        });
+    }
+    Ok(())
+}
+
+/// Lays out `widget` with an 8 pixel margin in a `width` by `height` pixmap filled with `canvas`,
+/// lets `prepare` adjust the widget's state and draws it.
+fn render<'a>(
+    widget: impl Into<Element<'a, (), Renderer>>,
+    (width, height): (u32, u32),
+    canvas: Color,
+    prepare: impl FnOnce(&mut Tree),
+) -> Pixmap {
+    let size = Size::new(width as f32, height as f32);
+    let viewport = Rectangle::with_size(size);
+    // A column keeps the widget's state in its first child tree, where `prepare` looks for it.
+    let element: Element<'_, (), Renderer> = Column::new()
+        .push(widget)
+        .padding(8)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into();
+    let mut renderer = Renderer::new(Backend::new(), Font::with_name(FONT_NAME), Pixels(14.0));
+    // Fonts are loaded into a font system shared by every renderer, and each load invalidates
+    // the shaping caches, so it happens once.
+    static LOAD_FONT: Once = Once::new();
+    LOAD_FONT.call_once(|| renderer.load_font(Cow::Borrowed(FONT)));
+
+    let mut tree = Tree::new(element.as_widget());
+    let node = element
+        .as_widget()
+        .layout(&mut tree, &renderer, &Limits::new(Size::ZERO, size));
+    prepare(&mut tree.children[0]);
+    element.as_widget().draw(
+        &tree,
+        &mut renderer,
+        &Theme::Light,
+        &renderer::Style {
+            text_color: Color::BLACK,
+        },
+        Layout::new(&node),
+        mouse::Cursor::Unavailable,
+        &viewport,
+    );
+
+    let mut pixmap = Pixmap::new(width, height).expect("snapshot sizes are not zero");
+    let mut mask = tiny_skia::Mask::new(width, height).expect("snapshot sizes are not zero");
+    renderer.with_primitives(|backend, primitives| {
+        backend.draw(
+            &mut pixmap.as_mut(),
+            &mut mask,
+            primitives,
+            &Viewport::with_physical_size(Size::new(width, height), 1.0),
+            &[viewport],
+            canvas,
+            &[] as &[&str],
+        );
+    });
+    pixmap
+}
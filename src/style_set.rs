@@ -0,0 +1,116 @@
+use crate::cvd::Deficiency;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use iced::Color;
+
+/// One style per preset the built-in theme offers, for every widget module.
+#[derive(Debug, Clone, Copy)]
+pub struct StyleSet {
+    pub primary_button: CustomButtonStyle,
+    pub secondary_button: CustomButtonStyle,
+    pub positive_button: CustomButtonStyle,
+    pub destructive_button: CustomButtonStyle,
+    pub text_button: CustomButtonStyle,
+    pub box_container: CustomContainerStyle,
+    pub transparent_container: CustomContainerStyle,
+    pub scrollable: CustomScrollableStyle,
+    pub text_input: CustomTextInputStyle,
+    pub text: CustomTextStyle,
+    #[cfg(feature = "svg")]
+    pub svg: CustomSvgStyle,
+}
+
+impl StyleSet {
+    pub fn from_theme(theme: &iced::Theme) -> Self {
+        Self {
+            primary_button: CustomButtonStyle::primary(theme),
+            secondary_button: CustomButtonStyle::secondary(theme),
+            positive_button: CustomButtonStyle::positive(theme),
+            destructive_button: CustomButtonStyle::destructive(theme),
+            text_button: CustomButtonStyle::text(theme),
+            box_container: CustomContainerStyle::boxx(theme),
+            transparent_container: CustomContainerStyle::transparent(theme),
+            scrollable: CustomScrollableStyle::default(theme),
+            text_input: CustomTextInputStyle::default(theme),
+            text: CustomTextStyle::new().color(theme.palette().text),
+            #[cfg(feature = "svg")]
+            svg: CustomSvgStyle::new(),
+        }
+    }
+
+    pub fn from_palette(palette: iced::theme::Palette) -> Self {
+        Self::from_theme(&iced::Theme::custom(palette))
+    }
+
+    pub fn buttons(&self) -> [(&'static str, &CustomButtonStyle); 5] {
+        [
+            ("primary", &self.primary_button),
+            ("secondary", &self.secondary_button),
+            ("positive", &self.positive_button),
+            ("destructive", &self.destructive_button),
+            ("text", &self.text_button),
+        ]
+    }
+
+    pub fn buttons_mut(&mut self) -> [(&'static str, &mut CustomButtonStyle); 5] {
+        [
+            ("primary", &mut self.primary_button),
+            ("secondary", &mut self.secondary_button),
+            ("positive", &mut self.positive_button),
+            ("destructive", &mut self.destructive_button),
+            ("text", &mut self.text_button),
+        ]
+    }
+
+    pub fn containers(&self) -> [(&'static str, &CustomContainerStyle); 2] {
+        [
+            ("box", &self.box_container),
+            ("transparent", &self.transparent_container),
+        ]
+    }
+
+    pub fn containers_mut(&mut self) -> [(&'static str, &mut CustomContainerStyle); 2] {
+        [
+            ("box", &mut self.box_container),
+            ("transparent", &mut self.transparent_container),
+        ]
+    }
+
+    pub fn map_colors(mut self, mut f: impl FnMut(Color) -> Color) -> Self {
+        for (_, button) in self.buttons_mut() {
+            *button = button.map_colors(&mut f);
+        }
+        for (_, container) in self.containers_mut() {
+            *container = container.map_colors(&mut f);
+        }
+        self.scrollable = self.scrollable.map_colors(&mut f);
+        self.text_input = self.text_input.map_colors(&mut f);
+        self.text = self.text.map_colors(&mut f);
+        #[cfg(feature = "svg")]
+        {
+            self.svg = self.svg.map_colors(&mut f);
+        }
+        self
+    }
+
+    pub fn high_contrast(mut self, theme: &iced::Theme) -> Self {
+        for (_, button) in self.buttons_mut() {
+            *button = button.high_contrast(theme);
+        }
+        for (_, container) in self.containers_mut() {
+            *container = container.high_contrast(theme);
+        }
+        self.scrollable = self.scrollable.high_contrast(theme);
+        self.text_input = self.text_input.high_contrast(theme);
+        self
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+}
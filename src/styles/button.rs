@@ -1,3 +1,13 @@
+use crate::css_color;
+use crate::cvd::Deficiency;
+use crate::diagnostic::Diagnostic;
+use crate::high_contrast::{
+    effective_background, enforce_contrast, opaque_background, MIN_BORDER_WIDTH, NON_TEXT_CONTRAST,
+    TEXT_CONTRAST,
+};
+use crate::tokens::{OffsetValue, RadiusValue, TokenTable, WidthValue};
+use crate::visit::{background_colors, map_background};
+use bitflags::bitflags;
 use iced::widget::button::{Appearance, StyleSheet};
 use iced::{Background, BorderRadius, Color};
 
@@ -8,224 +18,345 @@ pub struct CustomButtonStyle {
     pub pressed: Appearance,
     pub disabled: Appearance,
     pub current_state: ButtonState,
+    /// What the radius, width and offset tokens passed to the setters resolve to.
+    pub tokens: TokenTable,
 }
 
-#[derive(Default, Copy, Clone, Debug)]
-pub enum ButtonState {
-    #[default]
-    Active,
-    Hovered,
-    Pressed,
-    Disabled,
-    All,
+bitflags! {
+    /// The states the builder methods apply to, e.g. `ButtonState::HOVERED | ButtonState::PRESSED`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct ButtonState: u8 {
+        const ACTIVE = 1 << 0;
+        const HOVERED = 1 << 1;
+        const PRESSED = 1 << 2;
+        const DISABLED = 1 << 3;
+    }
+}
+
+impl Default for ButtonState {
+    fn default() -> Self {
+        Self::ACTIVE
+    }
+}
+
+/// The names the states had as enum variants, before they became flags.
+#[allow(non_upper_case_globals)]
+impl ButtonState {
+    #[deprecated(note = "use `ButtonState::ACTIVE`")]
+    pub const Active: Self = Self::ACTIVE;
+    #[deprecated(note = "use `ButtonState::HOVERED`")]
+    pub const Hovered: Self = Self::HOVERED;
+    #[deprecated(note = "use `ButtonState::PRESSED`")]
+    pub const Pressed: Self = Self::PRESSED;
+    #[deprecated(note = "use `ButtonState::DISABLED`")]
+    pub const Disabled: Self = Self::DISABLED;
+    #[deprecated(note = "use `ButtonState::all()`")]
+    pub const All: Self = Self::all();
 }
 
 impl CustomButtonStyle {
-    pub fn new() -> Self {
-        let default = Appearance {
-            shadow_offset: Default::default(),
-            background: None,
-            border_radius: Default::default(),
-            border_width: 0.0,
-            border_color: Default::default(),
-            text_color: Default::default(),
-        };
+    pub const fn new() -> Self {
+        let default = AppearanceEditor::new().0;
         Self {
             active: default,
             hovered: default,
             pressed: default,
             disabled: default,
-            current_state: Default::default(),
+            current_state: ButtonState::ACTIVE,
+            tokens: TokenTable::DEFAULT,
         }
     }
 
-    pub fn primary(theme: &iced::Theme) -> Self {
+    fn from_preset(theme: &iced::Theme, preset: &iced::theme::Button) -> Self {
         Self {
-            active: theme.active(&iced::theme::Button::Primary),
-            hovered: theme.hovered(&iced::theme::Button::Primary),
-            pressed: theme.pressed(&iced::theme::Button::Primary),
-            disabled: theme.disabled(&iced::theme::Button::Primary),
+            active: theme.active(preset),
+            hovered: theme.hovered(preset),
+            pressed: theme.pressed(preset),
+            disabled: theme.disabled(preset),
             current_state: Default::default(),
+            tokens: TokenTable::DEFAULT,
         }
     }
 
+    pub fn primary(theme: &iced::Theme) -> Self {
+        Self::from_preset(theme, &iced::theme::Button::Primary)
+    }
+
     pub fn secondary(theme: &iced::Theme) -> Self {
-        Self {
-            active: theme.active(&iced::theme::Button::Secondary),
-            hovered: theme.hovered(&iced::theme::Button::Secondary),
-            pressed: theme.pressed(&iced::theme::Button::Secondary),
-            disabled: theme.disabled(&iced::theme::Button::Secondary),
-            current_state: Default::default(),
-        }
+        Self::from_preset(theme, &iced::theme::Button::Secondary)
     }
 
     pub fn destructive(theme: &iced::Theme) -> Self {
-        Self {
-            active: theme.active(&iced::theme::Button::Destructive),
-            hovered: theme.hovered(&iced::theme::Button::Destructive),
-            pressed: theme.pressed(&iced::theme::Button::Destructive),
-            disabled: theme.disabled(&iced::theme::Button::Destructive),
-            current_state: Default::default(),
-        }
+        Self::from_preset(theme, &iced::theme::Button::Destructive)
     }
 
     pub fn positive(theme: &iced::Theme) -> Self {
-        Self {
-            active: theme.active(&iced::theme::Button::Positive),
-            hovered: theme.hovered(&iced::theme::Button::Positive),
-            pressed: theme.pressed(&iced::theme::Button::Positive),
-            disabled: theme.disabled(&iced::theme::Button::Positive),
-            current_state: Default::default(),
-        }
+        Self::from_preset(theme, &iced::theme::Button::Positive)
     }
 
     pub fn text(theme: &iced::Theme) -> Self {
-        Self {
-            active: theme.active(&iced::theme::Button::Text),
-            hovered: theme.hovered(&iced::theme::Button::Text),
-            pressed: theme.pressed(&iced::theme::Button::Text),
-            disabled: theme.disabled(&iced::theme::Button::Text),
-            current_state: Default::default(),
-        }
+        Self::from_preset(theme, &iced::theme::Button::Text)
     }
 
-    pub fn active(mut self) -> Self {
-        self.current_state = ButtonState::Active;
-        self
+    /// Starts a [`ButtonBuilder`] from the same appearances as [`CustomButtonStyle::new`].
+    pub const fn builder() -> ButtonBuilder {
+        let style = Self::new();
+        ButtonBuilder(ButtonStyle {
+            active: style.active,
+            hovered: style.hovered,
+            pressed: style.pressed,
+            disabled: style.disabled,
+        })
     }
 
-    pub fn all(mut self) -> Self {
-        self.current_state = ButtonState::All;
+    /// Resolves the tokens passed to later setters against `tokens`.
+    pub fn tokens(mut self, tokens: &TokenTable) -> Self {
+        self.tokens = *tokens;
         self
     }
 
-    pub fn hovered(mut self) -> Self {
-        self.current_state = ButtonState::Hovered;
+    pub fn states(mut self, states: ButtonState) -> Self {
+        self.current_state = states;
         self
     }
 
-    pub fn pressed(mut self) -> Self {
-        self.current_state = ButtonState::Pressed;
-        self
+    pub fn all_except(self, states: ButtonState) -> Self {
+        self.states(ButtonState::all().difference(states))
     }
 
-    pub fn disabled(mut self) -> Self {
-        self.current_state = ButtonState::Disabled;
-        self
+    pub fn active(self) -> Self {
+        self.states(ButtonState::ACTIVE)
+    }
+
+    pub fn all(self) -> Self {
+        self.states(ButtonState::all())
+    }
+
+    pub fn hovered(self) -> Self {
+        self.states(ButtonState::HOVERED)
+    }
+
+    pub fn pressed(self) -> Self {
+        self.states(ButtonState::PRESSED)
+    }
+
+    pub fn disabled(self) -> Self {
+        self.states(ButtonState::DISABLED)
+    }
+
+    fn appearances_mut(
+        &mut self,
+        states: ButtonState,
+    ) -> impl Iterator<Item = (ButtonState, &mut Appearance)> {
+        [
+            (ButtonState::ACTIVE, &mut self.active),
+            (ButtonState::HOVERED, &mut self.hovered),
+            (ButtonState::PRESSED, &mut self.pressed),
+            (ButtonState::DISABLED, &mut self.disabled),
+        ]
+        .into_iter()
+        .filter(move |(state, _)| states.contains(*state))
+    }
+
+    fn selected(&mut self) -> impl Iterator<Item = &mut Appearance> {
+        self.appearances_mut(self.current_state)
+            .map(|(_, appearance)| appearance)
+    }
+
+    /// The appearance of the first state in `states`, or the active one when it is empty.
+    fn appearance(&self, states: ButtonState) -> Appearance {
+        [
+            (ButtonState::ACTIVE, self.active),
+            (ButtonState::HOVERED, self.hovered),
+            (ButtonState::PRESSED, self.pressed),
+            (ButtonState::DISABLED, self.disabled),
+        ]
+        .into_iter()
+        .find(|(state, _)| states.contains(*state))
+        .map_or(self.active, |(_, appearance)| appearance)
     }
 
     pub fn background(mut self, background: Option<Background>) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.background = background,
-            ButtonState::Hovered => self.hovered.background = background,
-            ButtonState::Pressed => self.pressed.background = background,
-            ButtonState::Disabled => self.disabled.background = background,
-            ButtonState::All => {
-                self.active.background = background;
-                self.hovered.background = background;
-                self.pressed.background = background;
-                self.disabled.background = background;
-            }
+        for appearance in self.selected() {
+            appearance.background = background;
         }
         self
     }
 
     pub fn text_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.text_color = color,
-            ButtonState::Hovered => self.hovered.text_color = color,
-            ButtonState::Pressed => self.pressed.text_color = color,
-            ButtonState::Disabled => self.disabled.text_color = color,
-            ButtonState::All => {
-                self.active.text_color = color;
-                self.hovered.text_color = color;
-                self.pressed.text_color = color;
-                self.disabled.text_color = color;
-            }
+        for appearance in self.selected() {
+            appearance.text_color = color;
         }
         self
     }
 
+    pub fn text_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.text_color(css_color::parse(color)?))
+    }
+
     pub fn background_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.background = Some(Background::Color(color)),
-            ButtonState::Hovered => self.hovered.background = Some(Background::Color(color)),
-            ButtonState::Pressed => self.pressed.background = Some(Background::Color(color)),
-            ButtonState::Disabled => self.disabled.background = Some(Background::Color(color)),
-            ButtonState::All => {
-                self.active.background = Some(Background::Color(color));
-                self.hovered.background = Some(Background::Color(color));
-                self.pressed.background = Some(Background::Color(color));
-                self.disabled.background = Some(Background::Color(color));
-            }
+        for appearance in self.selected() {
+            appearance.background = Some(Background::Color(color));
         }
         self
     }
 
-    pub fn border_radius(mut self, radius: BorderRadius) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.border_radius = radius,
-            ButtonState::Hovered => self.hovered.border_radius = radius,
-            ButtonState::Pressed => self.pressed.border_radius = radius,
-            ButtonState::Disabled => self.disabled.border_radius = radius,
-            ButtonState::All => {
-                self.active.border_radius = radius;
-                self.hovered.border_radius = radius;
-                self.pressed.border_radius = radius;
-                self.disabled.border_radius = radius;
-            }
+    pub fn background_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.background_color(css_color::parse(color)?))
+    }
+
+    pub fn border_radius(mut self, radius: impl Into<RadiusValue>) -> Self {
+        let radius = radius.into().resolve(&self.tokens);
+        for appearance in self.selected() {
+            appearance.border_radius = radius;
         }
         self
     }
 
-    pub fn border_width(mut self, width: f32) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.border_width = width,
-            ButtonState::Hovered => self.hovered.border_width = width,
-            ButtonState::Pressed => self.pressed.border_width = width,
-            ButtonState::Disabled => self.disabled.border_width = width,
-            ButtonState::All => {
-                self.active.border_width = width;
-                self.hovered.border_width = width;
-                self.pressed.border_width = width;
-                self.disabled.border_width = width;
-            }
+    pub fn border_width(mut self, width: impl Into<WidthValue>) -> Self {
+        let width = width.into().resolve(&self.tokens);
+        for appearance in self.selected() {
+            appearance.border_width = width;
         }
         self
     }
 
     pub fn border_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.border_color = color,
-            ButtonState::Hovered => self.hovered.border_color = color,
-            ButtonState::Pressed => self.pressed.border_color = color,
-            ButtonState::Disabled => self.disabled.border_color = color,
-            ButtonState::All => {
-                self.active.border_color = color;
-                self.hovered.border_color = color;
-                self.pressed.border_color = color;
-                self.disabled.border_color = color;
-            }
+        for appearance in self.selected() {
+            appearance.border_color = color;
         }
         self
     }
 
-    pub fn shadow_offset(mut self, offset: iced::Vector) -> Self {
-        match self.current_state {
-            ButtonState::Active => self.active.shadow_offset = offset,
-            ButtonState::Hovered => self.hovered.shadow_offset = offset,
-            ButtonState::Pressed => self.pressed.shadow_offset = offset,
-            ButtonState::Disabled => self.disabled.shadow_offset = offset,
-            ButtonState::All => {
-                self.active.shadow_offset = offset;
-                self.hovered.shadow_offset = offset;
-                self.pressed.shadow_offset = offset;
-                self.disabled.shadow_offset = offset;
-            }
+    pub fn border_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.border_color(css_color::parse(color)?))
+    }
+
+    pub fn shadow_offset(mut self, offset: impl Into<OffsetValue>) -> Self {
+        let offset = offset.into().resolve(&self.tokens);
+        for appearance in self.selected() {
+            appearance.shadow_offset = offset;
         }
         self
     }
 
+    /// Copies the states in `from` over those in `to`, pairing them in flag order, e.g.
+    /// `.copy_state(ButtonState::HOVERED, ButtonState::PRESSED)`. A single state in `from` is
+    /// copied over every state in `to`.
+    ///
+    /// # Panics
+    ///
+    /// When `from` holds several states but not as many as `to`.
+    pub fn copy_state(self, from: ButtonState, to: ButtonState) -> Self {
+        let count = from.bits().count_ones();
+        assert!(
+            count == 1 || count == to.bits().count_ones(),
+            "cannot pair {from:?} with {to:?}"
+        );
+        let sources: Vec<_> = from.iter().map(|state| self.appearance(state)).collect();
+        let mut sources = sources.into_iter().cycle();
+        self.map_state(to, |_| sources.next().expect("`from` is not empty"))
+    }
+
+    /// Exchanges the states in `a` with those in `b`, pairing them in flag order.
+    ///
+    /// # Panics
+    ///
+    /// When `a` and `b` hold different numbers of states.
+    pub fn swap_states(self, a: ButtonState, b: ButtonState) -> Self {
+        assert_eq!(
+            a.bits().count_ones(),
+            b.bits().count_ones(),
+            "cannot pair {a:?} with {b:?}"
+        );
+        let first: Vec<_> = a.iter().map(|state| self.appearance(state)).collect();
+        let second: Vec<_> = b.iter().map(|state| self.appearance(state)).collect();
+        let (mut first, mut second) = (first.into_iter(), second.into_iter());
+        self.map_state(a, |_| second.next().expect("as many states as `a`"))
+            .map_state(b, |_| first.next().expect("as many states as `b`"))
+    }
+
+    /// Restores `states` to how `preset` looks in `theme`.
+    pub fn reset_state_to_theme(
+        mut self,
+        states: ButtonState,
+        theme: &iced::Theme,
+        preset: &iced::theme::Button,
+    ) -> Self {
+        let preset = Self::from_preset(theme, preset);
+        for (state, appearance) in self.appearances_mut(states) {
+            *appearance = preset.appearance(state);
+        }
+        self
+    }
+
+    pub fn map_state(
+        mut self,
+        states: ButtonState,
+        mut f: impl FnMut(Appearance) -> Appearance,
+    ) -> Self {
+        for (_, appearance) in self.appearances_mut(states) {
+            *appearance = f(*appearance);
+        }
+        self
+    }
+
+    pub fn high_contrast(mut self, theme: &iced::Theme) -> Self {
+        let canvas = theme.palette().background;
+        for appearance in [
+            &mut self.active,
+            &mut self.hovered,
+            &mut self.pressed,
+            &mut self.disabled,
+        ] {
+            appearance.background = opaque_background(appearance.background, canvas);
+            let background = effective_background(appearance.background, canvas);
+            appearance.text_color =
+                enforce_contrast(appearance.text_color, &[background], TEXT_CONTRAST);
+            appearance.border_width = appearance.border_width.max(MIN_BORDER_WIDTH);
+            appearance.border_color =
+                enforce_contrast(appearance.border_color, &[canvas], NON_TEXT_CONTRAST);
+        }
+        self
+    }
+
+    pub fn map_colors(mut self, mut f: impl FnMut(Color) -> Color) -> Self {
+        for appearance in [
+            &mut self.active,
+            &mut self.hovered,
+            &mut self.pressed,
+            &mut self.disabled,
+        ] {
+            appearance.background = appearance
+                .background
+                .map(|background| map_background(background, &mut f));
+            appearance.text_color = f(appearance.text_color);
+            appearance.border_color = f(appearance.border_color);
+        }
+        self
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (String, Color)> {
+        let mut colors = Vec::new();
+        for (state, appearance) in [
+            ("active", &self.active),
+            ("hovered", &self.hovered),
+            ("pressed", &self.pressed),
+            ("disabled", &self.disabled),
+        ] {
+            if let Some(background) = appearance.background {
+                background_colors(format!("{state}.background"), background, &mut colors);
+            }
+            colors.push((format!("{state}.text_color"), appearance.text_color));
+            colors.push((format!("{state}.border_color"), appearance.border_color));
+        }
+        colors.into_iter()
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+
     pub fn as_custom(&self) -> iced::theme::Button {
         iced::theme::Button::Custom(Box::new(*self))
     }
@@ -234,6 +365,195 @@ impl CustomButtonStyle {
 impl StyleSheet for CustomButtonStyle {
     type Style = iced::Theme;
 
+    fn active(&self, style: &Self::Style) -> Appearance {
+        ButtonStyle::from(*self).active(style)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        ButtonStyle::from(*self).hovered(style)
+    }
+
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        ButtonStyle::from(*self).pressed(style)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        ButtonStyle::from(*self).disabled(style)
+    }
+}
+
+/// Edits each state on its own, so a setter can only reach the state it was written for.
+///
+/// The `*_with` methods replace a state with an editor chain and are `const`, so styles can be
+/// built into constants, e.g. `.hovered_with(BASE.background_color(c))` with a `const BASE:
+/// AppearanceEditor`. The closure-taking methods edit a state's current appearance instead,
+/// which keeps them from being `const`.
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonBuilder(ButtonStyle);
+
+impl ButtonBuilder {
+    pub const fn active_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.active = editor.0;
+        self
+    }
+
+    pub const fn hovered_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.hovered = editor.0;
+        self
+    }
+
+    pub const fn pressed_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.pressed = editor.0;
+        self
+    }
+
+    pub const fn disabled_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.disabled = editor.0;
+        self
+    }
+
+    /// Sets every state to the appearance of `editor`.
+    pub const fn all_with(self, editor: AppearanceEditor) -> Self {
+        self.active_with(editor)
+            .hovered_with(editor)
+            .pressed_with(editor)
+            .disabled_with(editor)
+    }
+
+    pub fn active(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.active = edit(AppearanceEditor(self.0.active)).0;
+        self
+    }
+
+    pub fn hovered(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.hovered = edit(AppearanceEditor(self.0.hovered)).0;
+        self
+    }
+
+    pub fn pressed(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.pressed = edit(AppearanceEditor(self.0.pressed)).0;
+        self
+    }
+
+    pub fn disabled(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.disabled = edit(AppearanceEditor(self.0.disabled)).0;
+        self
+    }
+
+    /// Runs `edit` on every state in turn.
+    pub fn all(self, mut edit: impl FnMut(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.active(&mut edit)
+            .hovered(&mut edit)
+            .pressed(&mut edit)
+            .disabled(&mut edit)
+    }
+
+    pub const fn build(self) -> ButtonStyle {
+        self.0
+    }
+}
+
+impl From<CustomButtonStyle> for ButtonBuilder {
+    fn from(style: CustomButtonStyle) -> Self {
+        Self(style.into())
+    }
+}
+
+/// The setters of [`CustomButtonStyle`] for a single [`Appearance`].
+#[derive(Debug, Clone, Copy)]
+pub struct AppearanceEditor(pub Appearance);
+
+impl AppearanceEditor {
+    /// Starts from the appearance every state of [`CustomButtonStyle::new`] has.
+    pub const fn new() -> Self {
+        Self(Appearance {
+            shadow_offset: iced::Vector::new(0.0, 0.0),
+            background: None,
+            border_radius: super::border_radius([0.0; 4]),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::TRANSPARENT,
+        })
+    }
+
+    pub const fn background(mut self, background: Option<Background>) -> Self {
+        self.0.background = background;
+        self
+    }
+
+    pub const fn background_color(mut self, color: Color) -> Self {
+        self.0.background = Some(Background::Color(color));
+        self
+    }
+
+    pub const fn text_color(mut self, color: Color) -> Self {
+        self.0.text_color = color;
+        self
+    }
+
+    pub const fn border_radius(mut self, radius: BorderRadius) -> Self {
+        self.0.border_radius = radius;
+        self
+    }
+
+    pub const fn border_width(mut self, width: f32) -> Self {
+        self.0.border_width = width;
+        self
+    }
+
+    pub const fn border_color(mut self, color: Color) -> Self {
+        self.0.border_color = color;
+        self
+    }
+
+    pub const fn shadow_offset(mut self, offset: iced::Vector) -> Self {
+        self.0.shadow_offset = offset;
+        self
+    }
+}
+
+/// A button style without a state cursor, as produced by [`ButtonBuilder`].
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonStyle {
+    pub active: Appearance,
+    pub hovered: Appearance,
+    pub pressed: Appearance,
+    pub disabled: Appearance,
+}
+
+impl ButtonStyle {
+    pub fn as_custom(&self) -> iced::theme::Button {
+        iced::theme::Button::Custom(Box::new(*self))
+    }
+}
+
+impl From<CustomButtonStyle> for ButtonStyle {
+    fn from(style: CustomButtonStyle) -> Self {
+        Self {
+            active: style.active,
+            hovered: style.hovered,
+            pressed: style.pressed,
+            disabled: style.disabled,
+        }
+    }
+}
+
+impl From<ButtonStyle> for CustomButtonStyle {
+    fn from(style: ButtonStyle) -> Self {
+        Self {
+            active: style.active,
+            hovered: style.hovered,
+            pressed: style.pressed,
+            disabled: style.disabled,
+            current_state: Default::default(),
+            tokens: TokenTable::DEFAULT,
+        }
+    }
+}
+
+impl StyleSheet for ButtonStyle {
+    type Style = iced::Theme;
+
     fn active(&self, _style: &Self::Style) -> Appearance {
         self.active
     }
@@ -250,3 +570,176 @@ impl StyleSheet for CustomButtonStyle {
         self.disabled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::border_radius;
+
+    /// A style whose text colors are numbered in flag order, to tell the states apart.
+    fn numbered() -> CustomButtonStyle {
+        let mut style = CustomButtonStyle::new();
+        for (index, state) in ButtonState::all().iter().enumerate() {
+            style = style.states(state).text_color(shade(index));
+        }
+        style
+    }
+
+    fn shade(index: usize) -> Color {
+        Color::from_rgb(index as f32 / 8.0, 0.0, 0.0)
+    }
+
+    /// The number of the text color of each state, in flag order.
+    fn numbers(style: CustomButtonStyle) -> [usize; 4] {
+        [style.active, style.hovered, style.pressed, style.disabled]
+            .map(|appearance| (appearance.text_color.r * 8.0).round() as usize)
+    }
+
+    const BLUE: Color = Color::from_rgb(0.2, 0.4, 0.8);
+    const LIGHT_BLUE: Color = Color::from_rgb(0.3, 0.5, 0.9);
+    const GRAY: Color = Color::from_rgb(0.5, 0.5, 0.5);
+
+    const BASE: AppearanceEditor = AppearanceEditor::new()
+        .border_radius(border_radius([4.0; 4]))
+        .text_color(Color::WHITE);
+    const BUILT: ButtonStyle = CustomButtonStyle::builder()
+        .all_with(BASE.background_color(BLUE))
+        .hovered_with(BASE.background_color(LIGHT_BLUE))
+        .disabled_with(BASE.text_color(GRAY))
+        .build();
+
+    /// Appearances have no `PartialEq`, so styles are compared by their debug output.
+    fn debug(style: impl Into<ButtonStyle>) -> String {
+        format!("{:?}", style.into())
+    }
+
+    #[test]
+    fn const_builders_match_the_setter_chain() {
+        let chained = CustomButtonStyle::new()
+            .all()
+            .border_radius(4.0)
+            .text_color(Color::WHITE)
+            .background_color(BLUE)
+            .hovered()
+            .background_color(LIGHT_BLUE)
+            .disabled()
+            .background(None)
+            .text_color(GRAY);
+        assert_eq!(debug(BUILT), debug(chained));
+
+        let closures = CustomButtonStyle::builder()
+            .all(|editor| {
+                editor
+                    .border_radius(4.0.into())
+                    .text_color(Color::WHITE)
+                    .background_color(BLUE)
+            })
+            .hovered(|editor| editor.background_color(LIGHT_BLUE))
+            .disabled(|editor| editor.background(None).text_color(GRAY))
+            .build();
+        assert_eq!(debug(BUILT), debug(closures));
+    }
+
+    #[test]
+    fn built_styles_carry_no_state_cursor() {
+        // The last state edited was disabled, yet setters on the converted style start at active.
+        let style = CustomButtonStyle::from(BUILT);
+        assert_eq!(style.current_state, ButtonState::ACTIVE);
+        let style = style.text_color(Color::BLACK);
+        assert_eq!(style.active.text_color, Color::BLACK);
+        assert_eq!(style.disabled.text_color, GRAY);
+    }
+
+    #[test]
+    fn copies_states_pairwise_in_flag_order() {
+        let style = numbered().copy_state(
+            ButtonState::ACTIVE | ButtonState::HOVERED,
+            ButtonState::PRESSED | ButtonState::DISABLED,
+        );
+        assert_eq!(numbers(style), [0, 1, 0, 1]);
+
+        let style = numbered().copy_state(ButtonState::DISABLED, ButtonState::all());
+        assert_eq!(numbers(style), [3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn swaps_states_pairwise_in_flag_order() {
+        let style = numbered().swap_states(
+            ButtonState::ACTIVE | ButtonState::PRESSED,
+            ButtonState::HOVERED | ButtonState::DISABLED,
+        );
+        assert_eq!(numbers(style), [1, 0, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pair")]
+    fn copying_between_sets_of_different_sizes_panics() {
+        numbered().copy_state(
+            ButtonState::ACTIVE | ButtonState::HOVERED,
+            ButtonState::all(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pair")]
+    fn swapping_sets_of_different_sizes_panics() {
+        numbered().swap_states(
+            ButtonState::ACTIVE,
+            ButtonState::HOVERED | ButtonState::PRESSED,
+        );
+    }
+
+    #[test]
+    fn map_state_reaches_only_the_given_states() {
+        let style =
+            numbered().map_state(ButtonState::HOVERED | ButtonState::DISABLED, |appearance| {
+                Appearance {
+                    text_color: shade(8),
+                    ..appearance
+                }
+            });
+        assert_eq!(numbers(style), [0, 8, 2, 8]);
+    }
+
+    #[test]
+    fn reset_state_to_theme_restores_only_the_given_states() {
+        let theme = iced::Theme::Dark;
+        let preset = iced::theme::Button::Destructive;
+        let style = numbered().reset_state_to_theme(ButtonState::PRESSED, &theme, &preset);
+        let themed = CustomButtonStyle::destructive(&theme);
+        assert_eq!(
+            format!("{:?}", style.pressed),
+            format!("{:?}", themed.pressed)
+        );
+        assert_eq!(numbers(style)[..2], [0, 1]);
+        assert_eq!(numbers(style)[3], 3);
+    }
+
+    #[test]
+    fn one_setter_reaches_every_selected_state() {
+        let style = numbered()
+            .states(ButtonState::HOVERED | ButtonState::DISABLED)
+            .text_color(shade(8));
+        assert_eq!(numbers(style), [0, 8, 2, 8]);
+    }
+
+    #[test]
+    fn all_except_selects_the_complement() {
+        let style = numbered().all_except(ButtonState::PRESSED);
+        assert_eq!(
+            style.current_state,
+            ButtonState::ACTIVE | ButtonState::HOVERED | ButtonState::DISABLED
+        );
+        assert_eq!(numbers(style.text_color(shade(8))), [8, 8, 2, 8]);
+
+        let style = numbered().all_except(ButtonState::empty());
+        assert_eq!(style.current_state, ButtonState::all());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_variant_names_alias_the_flags() {
+        assert_eq!(ButtonState::Hovered, ButtonState::HOVERED);
+        assert_eq!(ButtonState::All, ButtonState::all());
+    }
+}
@@ -1,9 +1,20 @@
+use crate::css_color;
+use crate::cvd::Deficiency;
+use crate::diagnostic::Diagnostic;
+use crate::high_contrast::{
+    effective_background, enforce_contrast, opaque_background, MIN_BORDER_WIDTH, NON_TEXT_CONTRAST,
+    TEXT_CONTRAST,
+};
+use crate::tokens::{RadiusValue, TokenTable, WidthValue};
+use crate::visit::{background_colors, map_background};
 use iced::widget::container::{Appearance, StyleSheet};
-use iced::{Background, BorderRadius};
+use iced::{Background, Color};
 
 #[derive(Debug, Clone, Copy)]
 pub struct CustomContainerStyle {
     pub appearance: Appearance,
+    /// What the radius and width tokens passed to the setters resolve to.
+    pub tokens: TokenTable,
 }
 
 impl CustomContainerStyle {
@@ -16,18 +27,21 @@ impl CustomContainerStyle {
                 border_width: 0.0,
                 border_color: Default::default(),
             },
+            tokens: TokenTable::DEFAULT,
         }
     }
 
     pub fn transparent(theme: &iced::Theme) -> Self {
         Self {
             appearance: theme.appearance(&iced::theme::Container::Transparent),
+            tokens: TokenTable::DEFAULT,
         }
     }
 
     pub fn boxx(theme: &iced::Theme) -> Self {
         Self {
             appearance: theme.appearance(&iced::theme::Container::Box),
+            tokens: TokenTable::DEFAULT,
         }
     }
 
@@ -41,13 +55,23 @@ impl CustomContainerStyle {
         self
     }
 
-    pub const fn border_radius(mut self, radius: BorderRadius) -> Self {
-        self.appearance.border_radius = radius;
+    pub fn background_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.background_color(css_color::parse(color)?))
+    }
+
+    /// Resolves the tokens passed to later setters against `tokens`.
+    pub const fn tokens(mut self, tokens: &TokenTable) -> Self {
+        self.tokens = *tokens;
+        self
+    }
+
+    pub fn border_radius(mut self, radius: impl Into<RadiusValue>) -> Self {
+        self.appearance.border_radius = radius.into().resolve(&self.tokens);
         self
     }
 
-    pub const fn border_width(mut self, width: f32) -> Self {
-        self.appearance.border_width = width;
+    pub fn border_width(mut self, width: impl Into<WidthValue>) -> Self {
+        self.appearance.border_width = width.into().resolve(&self.tokens);
         self
     }
 
@@ -56,11 +80,66 @@ impl CustomContainerStyle {
         self
     }
 
+    pub fn border_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.border_color(css_color::parse(color)?))
+    }
+
     pub const fn text_color(mut self, color: Option<iced::Color>) -> Self {
         self.appearance.text_color = color;
         self
     }
 
+    pub fn text_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.text_color(Some(css_color::parse(color)?)))
+    }
+
+    pub fn high_contrast(mut self, theme: &iced::Theme) -> Self {
+        let palette = theme.palette();
+        let appearance = &mut self.appearance;
+        appearance.background = opaque_background(appearance.background, palette.background);
+        let background = effective_background(appearance.background, palette.background);
+        appearance.text_color = Some(enforce_contrast(
+            appearance.text_color.unwrap_or(palette.text),
+            &[background],
+            TEXT_CONTRAST,
+        ));
+        if appearance.background.is_some() || appearance.border_width > 0.0 {
+            appearance.border_width = appearance.border_width.max(MIN_BORDER_WIDTH);
+            appearance.border_color = enforce_contrast(
+                appearance.border_color,
+                &[palette.background],
+                NON_TEXT_CONTRAST,
+            );
+        }
+        self
+    }
+
+    pub fn map_colors(mut self, mut f: impl FnMut(Color) -> Color) -> Self {
+        let appearance = &mut self.appearance;
+        appearance.text_color = appearance.text_color.map(&mut f);
+        appearance.background = appearance
+            .background
+            .map(|background| map_background(background, &mut f));
+        appearance.border_color = f(appearance.border_color);
+        self
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (String, Color)> {
+        let mut colors = Vec::new();
+        if let Some(text_color) = self.appearance.text_color {
+            colors.push(("text_color".to_string(), text_color));
+        }
+        if let Some(background) = self.appearance.background {
+            background_colors("background".to_string(), background, &mut colors);
+        }
+        colors.push(("border_color".to_string(), self.appearance.border_color));
+        colors.into_iter()
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+
     pub fn as_custom(&self) -> iced::theme::Container {
         iced::theme::Container::Custom(Box::new(*self))
     }
@@ -5,3 +5,13 @@ pub mod scrollable;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+
+use iced::BorderRadius;
+
+/// `BorderRadius::from(radii)` for `const` items, where `From` cannot be called, e.g. with the
+/// editors of the style builders.
+pub const fn border_radius(radii: [f32; 4]) -> BorderRadius {
+    // SAFETY: `BorderRadius` holds the four radii and nothing else, which `transmute` checks by
+    // refusing types of different sizes, and any bits are a valid `f32`.
+    unsafe { std::mem::transmute::<[f32; 4], BorderRadius>(radii) }
+}
@@ -1,3 +1,12 @@
+use crate::css_color;
+use crate::cvd::Deficiency;
+use crate::diagnostic::Diagnostic;
+use crate::high_contrast::{
+    effective_background, enforce_contrast, opaque_background, NON_TEXT_CONTRAST,
+};
+use crate::tokens::{RadiusValue, TokenTable, WidthValue};
+use crate::visit::{background_colors, map_background};
+use bitflags::bitflags;
 use iced::widget::scrollable::{Scrollbar, Scroller, StyleSheet};
 use iced::{Background, BorderRadius, Color};
 
@@ -12,36 +21,85 @@ pub struct CustomScrollableStyle {
     pub hovered_horizontal_over_scrollbar: Scrollbar,
     pub dragging_horizontal: Scrollbar,
     pub current_state: ScrollableState,
+    /// What the radius and width tokens passed to the setters resolve to.
+    pub tokens: TokenTable,
 }
 
-#[derive(Default, Copy, Clone, Debug)]
-pub enum ScrollableState {
+bitflags! {
+    /// The scrollbars the builder methods apply to, one flag per field of the style in the same
+    /// order. Each horizontal flag is its vertical counterpart shifted by four.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct ScrollableState: u8 {
+        const ACTIVE = 1 << 0;
+        const HOVERED = 1 << 1;
+        const HOVERED_OVER_SCROLLBAR = 1 << 2;
+        const DRAGGING = 1 << 3;
+        const ACTIVE_HORIZONTAL = 1 << 4;
+        const HOVERED_HORIZONTAL = 1 << 5;
+        const HOVERED_HORIZONTAL_OVER_SCROLLBAR = 1 << 6;
+        const DRAGGING_HORIZONTAL = 1 << 7;
+
+        const VERTICAL = Self::ACTIVE.bits()
+            | Self::HOVERED.bits()
+            | Self::HOVERED_OVER_SCROLLBAR.bits()
+            | Self::DRAGGING.bits();
+        const HORIZONTAL = Self::VERTICAL.bits() << 4;
+    }
+}
+
+impl Default for ScrollableState {
+    fn default() -> Self {
+        Self::ACTIVE
+    }
+}
+
+/// The names the states had as enum variants, before they became flags.
+#[allow(non_upper_case_globals)]
+impl ScrollableState {
+    #[deprecated(note = "use `ScrollableState::ACTIVE`")]
+    pub const Active: Self = Self::ACTIVE;
+    #[deprecated(note = "use `ScrollableState::HOVERED`")]
+    pub const Hovered: Self = Self::HOVERED;
+    #[deprecated(note = "use `ScrollableState::HOVERED_OVER_SCROLLBAR`")]
+    pub const HoveredOverScrollbar: Self = Self::HOVERED_OVER_SCROLLBAR;
+    #[deprecated(note = "use `ScrollableState::DRAGGING`")]
+    pub const Dragging: Self = Self::DRAGGING;
+    #[deprecated(note = "use `ScrollableState::ACTIVE_HORIZONTAL`")]
+    pub const ActiveHorizontal: Self = Self::ACTIVE_HORIZONTAL;
+    #[deprecated(note = "use `ScrollableState::HOVERED_HORIZONTAL`")]
+    pub const HoveredHorizontal: Self = Self::HOVERED_HORIZONTAL;
+    #[deprecated(note = "use `ScrollableState::HOVERED_HORIZONTAL_OVER_SCROLLBAR`")]
+    pub const HoveredHorizontalOverScrollbar: Self = Self::HOVERED_HORIZONTAL_OVER_SCROLLBAR;
+    #[deprecated(note = "use `ScrollableState::DRAGGING_HORIZONTAL`")]
+    pub const DraggingHorizontal: Self = Self::DRAGGING_HORIZONTAL;
+    /// Unlike the old variant, this covers the over-scrollbar states too.
+    #[deprecated(note = "use `ScrollableState::all()`")]
+    pub const All: Self = Self::all();
+}
+
+/// Which scrollbars [`CustomScrollableStyle::orientation`] moves the selection to.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
     #[default]
-    Active,
-    Hovered,
-    HoveredOverScrollbar,
-    Dragging,
-    ActiveHorizontal,
-    HoveredHorizontal,
-    HoveredHorizontalOverScrollbar,
-    DraggingHorizontal,
-    All,
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+/// The vertical `states` moved to `orientation`.
+const fn oriented(states: ScrollableState, orientation: Orientation) -> ScrollableState {
+    let vertical = states.bits();
+    let bits = match orientation {
+        Orientation::Vertical => vertical,
+        Orientation::Horizontal => vertical << 4,
+        Orientation::Both => vertical | vertical << 4,
+    };
+    ScrollableState::from_bits_retain(bits)
 }
 
 impl CustomScrollableStyle {
-    pub fn new() -> Self {
-        let default = Scrollbar {
-            background: None,
-            border_radius: Default::default(),
-            border_width: 0.0,
-            border_color: Default::default(),
-            scroller: Scroller {
-                color: Default::default(),
-                border_radius: Default::default(),
-                border_width: 0.0,
-                border_color: Default::default(),
-            },
-        };
+    pub const fn new() -> Self {
+        let default = ScrollbarEditor::new().0;
         Self {
             active: default,
             hovered: default,
@@ -51,328 +109,750 @@ impl CustomScrollableStyle {
             hovered_horizontal: default,
             hovered_horizontal_over_scrollbar: default,
             dragging_horizontal: default,
-            current_state: Default::default(),
+            current_state: ScrollableState::ACTIVE,
+            tokens: TokenTable::DEFAULT,
         }
     }
 
     pub fn default(theme: &iced::Theme) -> Self {
+        Self::from_preset(theme, &iced::theme::Scrollable::Default)
+    }
+
+    fn from_preset(theme: &iced::Theme, preset: &iced::theme::Scrollable) -> Self {
         Self {
-            active: theme.active(&iced::theme::Scrollable::Default),
-            hovered: theme.hovered(&iced::theme::Scrollable::Default, false),
-            hovered_over_scrollbar: theme.hovered(&iced::theme::Scrollable::Default, true),
-            dragging: theme.dragging(&iced::theme::Scrollable::Default),
-            active_horizontal: theme.active_horizontal(&iced::theme::Scrollable::Default),
-            hovered_horizontal: theme.hovered_horizontal(&iced::theme::Scrollable::Default, false),
-            hovered_horizontal_over_scrollbar: theme
-                .hovered_horizontal(&iced::theme::Scrollable::Default, true),
-            dragging_horizontal: theme.dragging_horizontal(&iced::theme::Scrollable::Default),
+            active: theme.active(preset),
+            hovered: theme.hovered(preset, false),
+            hovered_over_scrollbar: theme.hovered(preset, true),
+            dragging: theme.dragging(preset),
+            active_horizontal: theme.active_horizontal(preset),
+            hovered_horizontal: theme.hovered_horizontal(preset, false),
+            hovered_horizontal_over_scrollbar: theme.hovered_horizontal(preset, true),
+            dragging_horizontal: theme.dragging_horizontal(preset),
             current_state: Default::default(),
+            tokens: TokenTable::DEFAULT,
         }
     }
 
-    pub const fn all(mut self) -> Self {
-        self.current_state = ScrollableState::All;
-        self
+    /// Starts a [`ScrollableBuilder`] from the same scrollbars as [`CustomScrollableStyle::new`].
+    pub const fn builder() -> ScrollableBuilder {
+        let style = Self::new();
+        ScrollableBuilder(ScrollableStyle {
+            active: style.active,
+            hovered: style.hovered,
+            hovered_over_scrollbar: style.hovered_over_scrollbar,
+            dragging: style.dragging,
+            active_horizontal: style.active_horizontal,
+            hovered_horizontal: style.hovered_horizontal,
+            hovered_horizontal_over_scrollbar: style.hovered_horizontal_over_scrollbar,
+            dragging_horizontal: style.dragging_horizontal,
+        })
     }
 
-    pub const fn active(mut self) -> Self {
-        self.current_state = ScrollableState::Active;
+    /// Resolves the tokens passed to later setters against `tokens`.
+    pub const fn tokens(mut self, tokens: &TokenTable) -> Self {
+        self.tokens = *tokens;
         self
     }
 
-    pub const fn hovered(mut self) -> Self {
-        self.current_state = ScrollableState::Hovered;
+    pub const fn states(mut self, states: ScrollableState) -> Self {
+        self.current_state = states;
         self
     }
 
-    pub const fn hovered_over_scrollbar(mut self) -> Self {
-        self.current_state = ScrollableState::HoveredOverScrollbar;
-        self
+    pub const fn all_except(self, states: ScrollableState) -> Self {
+        self.states(ScrollableState::all().difference(states))
     }
 
-    pub const fn dragging(mut self) -> Self {
-        self.current_state = ScrollableState::Dragging;
-        self
+    pub const fn all(self) -> Self {
+        self.states(ScrollableState::all())
+    }
+
+    pub const fn vertical(self) -> Self {
+        self.orientation(Orientation::Vertical)
     }
 
-    pub const fn active_horizontal(mut self) -> Self {
-        self.current_state = ScrollableState::ActiveHorizontal;
+    pub const fn horizontal(self) -> Self {
+        self.orientation(Orientation::Horizontal)
+    }
+
+    /// Selects the current states in both scrollbars, e.g. `.hovered().both()` selects
+    /// `HOVERED | HOVERED_HORIZONTAL`. Interaction states chosen while both scrollbars are
+    /// selected stay in both, so `.both().hovered_over_scrollbar().scroller_color(c)` sets the
+    /// scroller of the vertical and the horizontal scrollbar.
+    pub const fn both(self) -> Self {
+        self.orientation(Orientation::Both)
+    }
+
+    /// Moves the states selected in either orientation over to `orientation`.
+    pub const fn orientation(mut self, orientation: Orientation) -> Self {
+        let bits = self.current_state.bits();
+        let vertical = ScrollableState::from_bits_retain(bits | bits >> 4)
+            .intersection(ScrollableState::VERTICAL);
+        self.current_state = oriented(vertical, orientation);
         self
     }
 
-    pub const fn hovered_horizontal(mut self) -> Self {
-        self.current_state = ScrollableState::HoveredHorizontal;
+    /// Selects the vertical `states`, in both scrollbars when both are selected now.
+    const fn interaction(self, states: ScrollableState) -> Self {
+        let current = self.current_state;
+        let orientation = if current.intersects(ScrollableState::VERTICAL)
+            && current.intersects(ScrollableState::HORIZONTAL)
+        {
+            Orientation::Both
+        } else {
+            Orientation::Vertical
+        };
+        self.states(oriented(states, orientation))
+    }
+
+    pub const fn active(self) -> Self {
+        self.interaction(ScrollableState::ACTIVE)
+    }
+
+    pub const fn hovered(self) -> Self {
+        self.interaction(ScrollableState::HOVERED)
+    }
+
+    pub const fn hovered_over_scrollbar(self) -> Self {
+        self.interaction(ScrollableState::HOVERED_OVER_SCROLLBAR)
+    }
+
+    pub const fn dragging(self) -> Self {
+        self.interaction(ScrollableState::DRAGGING)
+    }
+
+    pub const fn active_horizontal(self) -> Self {
+        self.states(ScrollableState::ACTIVE_HORIZONTAL)
+    }
+
+    pub const fn hovered_horizontal(self) -> Self {
+        self.states(ScrollableState::HOVERED_HORIZONTAL)
+    }
+
+    pub const fn hovered_horizontal_over_scrollbar(self) -> Self {
+        self.states(ScrollableState::HOVERED_HORIZONTAL_OVER_SCROLLBAR)
+    }
+
+    pub const fn dragging_horizontal(self) -> Self {
+        self.states(ScrollableState::DRAGGING_HORIZONTAL)
+    }
+
+    const fn scrollbars(&self) -> [Scrollbar; 8] {
+        [
+            self.active,
+            self.hovered,
+            self.hovered_over_scrollbar,
+            self.dragging,
+            self.active_horizontal,
+            self.hovered_horizontal,
+            self.hovered_horizontal_over_scrollbar,
+            self.dragging_horizontal,
+        ]
+    }
+
+    fn scrollbars_mut(
+        &mut self,
+        states: ScrollableState,
+    ) -> impl Iterator<Item = (ScrollableState, &mut Scrollbar)> {
+        ScrollableState::all()
+            .iter()
+            .zip([
+                &mut self.active,
+                &mut self.hovered,
+                &mut self.hovered_over_scrollbar,
+                &mut self.dragging,
+                &mut self.active_horizontal,
+                &mut self.hovered_horizontal,
+                &mut self.hovered_horizontal_over_scrollbar,
+                &mut self.dragging_horizontal,
+            ])
+            .filter(move |(state, _)| states.contains(*state))
+    }
+
+    /// The first scrollbar in `states`, or the active one when it is empty.
+    fn scrollbar(&self, states: ScrollableState) -> Scrollbar {
+        ScrollableState::all()
+            .iter()
+            .zip(self.scrollbars())
+            .find(|(state, _)| states.contains(*state))
+            .map_or(self.active, |(_, scrollbar)| scrollbar)
+    }
+
+    /// Writes back the selected entries of `scrollbars`, which are in the order of the flags.
+    /// Loops instead of closures keep the setters `const`.
+    const fn with_selected(mut self, scrollbars: [Scrollbar; 8]) -> Self {
+        let [active, hovered, hovered_over_scrollbar, dragging, active_horizontal, hovered_horizontal, hovered_horizontal_over_scrollbar, dragging_horizontal] =
+            scrollbars;
+        let states = self.current_state;
+        if states.contains(ScrollableState::ACTIVE) {
+            self.active = active;
+        }
+        if states.contains(ScrollableState::HOVERED) {
+            self.hovered = hovered;
+        }
+        if states.contains(ScrollableState::HOVERED_OVER_SCROLLBAR) {
+            self.hovered_over_scrollbar = hovered_over_scrollbar;
+        }
+        if states.contains(ScrollableState::DRAGGING) {
+            self.dragging = dragging;
+        }
+        if states.contains(ScrollableState::ACTIVE_HORIZONTAL) {
+            self.active_horizontal = active_horizontal;
+        }
+        if states.contains(ScrollableState::HOVERED_HORIZONTAL) {
+            self.hovered_horizontal = hovered_horizontal;
+        }
+        if states.contains(ScrollableState::HOVERED_HORIZONTAL_OVER_SCROLLBAR) {
+            self.hovered_horizontal_over_scrollbar = hovered_horizontal_over_scrollbar;
+        }
+        if states.contains(ScrollableState::DRAGGING_HORIZONTAL) {
+            self.dragging_horizontal = dragging_horizontal;
+        }
         self
     }
 
-    pub const fn hovered_horizontal_over_scrollbar(mut self) -> Self {
-        self.current_state = ScrollableState::HoveredHorizontalOverScrollbar;
+    pub const fn background(self, background: Option<Background>) -> Self {
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].background = background;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub const fn background_color(self, color: Color) -> Self {
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].background = Some(Background::Color(color));
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub fn background_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.background_color(css_color::parse(color)?))
+    }
+
+    pub fn border_radius(self, radius: impl Into<RadiusValue>) -> Self {
+        let radius = radius.into().resolve(&self.tokens);
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].border_radius = radius;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub fn border_width(self, width: impl Into<WidthValue>) -> Self {
+        let width = width.into().resolve(&self.tokens);
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].border_width = width;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub const fn border_color(self, color: Color) -> Self {
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].border_color = color;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub fn border_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.border_color(css_color::parse(color)?))
+    }
+
+    pub const fn scroller_color(self, color: Color) -> Self {
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].scroller.color = color;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub fn scroller_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.scroller_color(css_color::parse(color)?))
+    }
+
+    pub fn scroller_border_radius(self, radius: impl Into<RadiusValue>) -> Self {
+        let radius = radius.into().resolve(&self.tokens);
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].scroller.border_radius = radius;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub fn scroller_border_width(self, width: impl Into<WidthValue>) -> Self {
+        let width = width.into().resolve(&self.tokens);
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].scroller.border_width = width;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub const fn scroller_border_color(self, color: Color) -> Self {
+        let mut scrollbars = self.scrollbars();
+        let mut index = 0;
+        while index < scrollbars.len() {
+            scrollbars[index].scroller.border_color = color;
+            index += 1;
+        }
+        self.with_selected(scrollbars)
+    }
+
+    pub fn scroller_border_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.scroller_border_color(css_color::parse(color)?))
+    }
+
+    /// Copies the scrollbars in `from` over those in `to`, pairing them in flag order, e.g.
+    /// `.copy_state(ScrollableState::VERTICAL, ScrollableState::HORIZONTAL)` to style both
+    /// scrollbars alike. A single scrollbar in `from` is copied over every scrollbar in `to`.
+    ///
+    /// # Panics
+    ///
+    /// When `from` holds several scrollbars but not as many as `to`.
+    pub fn copy_state(self, from: ScrollableState, to: ScrollableState) -> Self {
+        let count = from.bits().count_ones();
+        assert!(
+            count == 1 || count == to.bits().count_ones(),
+            "cannot pair {from:?} with {to:?}"
+        );
+        let sources: Vec<_> = from.iter().map(|state| self.scrollbar(state)).collect();
+        let mut sources = sources.into_iter().cycle();
+        self.map_state(to, |_| sources.next().expect("`from` is not empty"))
+    }
+
+    /// Exchanges the scrollbars in `a` with those in `b`, pairing them in flag order.
+    ///
+    /// # Panics
+    ///
+    /// When `a` and `b` hold different numbers of scrollbars.
+    pub fn swap_states(self, a: ScrollableState, b: ScrollableState) -> Self {
+        assert_eq!(
+            a.bits().count_ones(),
+            b.bits().count_ones(),
+            "cannot pair {a:?} with {b:?}"
+        );
+        let first: Vec<_> = a.iter().map(|state| self.scrollbar(state)).collect();
+        let second: Vec<_> = b.iter().map(|state| self.scrollbar(state)).collect();
+        let (mut first, mut second) = (first.into_iter(), second.into_iter());
+        self.map_state(a, |_| second.next().expect("as many states as `a`"))
+            .map_state(b, |_| first.next().expect("as many states as `b`"))
+    }
+
+    /// Restores `states` to how `preset` looks in `theme`.
+    pub fn reset_state_to_theme(
+        mut self,
+        states: ScrollableState,
+        theme: &iced::Theme,
+        preset: &iced::theme::Scrollable,
+    ) -> Self {
+        let preset = Self::from_preset(theme, preset);
+        for (state, scrollbar) in self.scrollbars_mut(states) {
+            *scrollbar = preset.scrollbar(state);
+        }
         self
     }
 
-    pub const fn dragging_horizontal(mut self) -> Self {
-        self.current_state = ScrollableState::DraggingHorizontal;
+    pub fn map_state(
+        mut self,
+        states: ScrollableState,
+        mut f: impl FnMut(Scrollbar) -> Scrollbar,
+    ) -> Self {
+        for (_, scrollbar) in self.scrollbars_mut(states) {
+            *scrollbar = f(*scrollbar);
+        }
         self
     }
 
-    pub const fn background(mut self, background: Option<Background>) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.background = background,
-            ScrollableState::Hovered => self.hovered.background = background,
-            ScrollableState::HoveredOverScrollbar => {}
-            ScrollableState::Dragging => self.dragging.background = background,
-            ScrollableState::ActiveHorizontal => self.active_horizontal.background = background,
-            ScrollableState::HoveredHorizontal => self.hovered_horizontal.background = background,
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.background = background
+    pub fn high_contrast(mut self, theme: &iced::Theme) -> Self {
+        let canvas = theme.palette().background;
+        for scrollbar in [
+            &mut self.active,
+            &mut self.hovered,
+            &mut self.hovered_over_scrollbar,
+            &mut self.dragging,
+            &mut self.active_horizontal,
+            &mut self.hovered_horizontal,
+            &mut self.hovered_horizontal_over_scrollbar,
+            &mut self.dragging_horizontal,
+        ] {
+            scrollbar.background = opaque_background(scrollbar.background, canvas);
+            let rail = effective_background(scrollbar.background, canvas);
+            scrollbar.scroller.color =
+                enforce_contrast(scrollbar.scroller.color, &[rail], NON_TEXT_CONTRAST);
+            if scrollbar.border_width > 0.0 {
+                scrollbar.border_color =
+                    enforce_contrast(scrollbar.border_color, &[canvas], NON_TEXT_CONTRAST);
             }
-            ScrollableState::DraggingHorizontal => self.dragging.background = background,
-            ScrollableState::All => {
-                self.active.background = background;
-                self.hovered.background = background;
-                self.dragging.background = background;
-                self.active_horizontal.background = background;
-                self.hovered_horizontal.background = background;
-                self.dragging_horizontal.background = background;
+            if scrollbar.scroller.border_width > 0.0 {
+                scrollbar.scroller.border_color =
+                    enforce_contrast(scrollbar.scroller.border_color, &[rail], NON_TEXT_CONTRAST);
             }
         }
         self
     }
 
-    pub const fn background_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.background = Some(Background::Color(color)),
-            ScrollableState::Hovered => self.hovered.background = Some(Background::Color(color)),
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.background = Some(Background::Color(color))
-            }
-            ScrollableState::Dragging => self.dragging.background = Some(Background::Color(color)),
-            ScrollableState::ActiveHorizontal => {
-                self.active_horizontal.background = Some(Background::Color(color))
-            }
-            ScrollableState::HoveredHorizontal => {
-                self.hovered_horizontal.background = Some(Background::Color(color))
-            }
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.background = Some(Background::Color(color))
-            }
-            ScrollableState::DraggingHorizontal => {
-                self.dragging.background = Some(Background::Color(color))
-            }
-            ScrollableState::All => {
-                self.active.background = Some(Background::Color(color));
-                self.hovered.background = Some(Background::Color(color));
-                self.dragging.background = Some(Background::Color(color));
-                self.active_horizontal.background = Some(Background::Color(color));
-                self.hovered_horizontal.background = Some(Background::Color(color));
-                self.dragging_horizontal.background = Some(Background::Color(color));
-            }
+    pub fn map_colors(mut self, mut f: impl FnMut(Color) -> Color) -> Self {
+        for scrollbar in [
+            &mut self.active,
+            &mut self.hovered,
+            &mut self.hovered_over_scrollbar,
+            &mut self.dragging,
+            &mut self.active_horizontal,
+            &mut self.hovered_horizontal,
+            &mut self.hovered_horizontal_over_scrollbar,
+            &mut self.dragging_horizontal,
+        ] {
+            scrollbar.background = scrollbar
+                .background
+                .map(|background| map_background(background, &mut f));
+            scrollbar.border_color = f(scrollbar.border_color);
+            scrollbar.scroller.color = f(scrollbar.scroller.color);
+            scrollbar.scroller.border_color = f(scrollbar.scroller.border_color);
         }
         self
     }
 
-    pub const fn border_radius(mut self, radius: BorderRadius) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.border_radius = radius,
-            ScrollableState::Hovered => self.hovered.border_radius = radius,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.border_radius = radius
-            }
-            ScrollableState::Dragging => self.dragging.border_radius = radius,
-            ScrollableState::ActiveHorizontal => self.active_horizontal.border_radius = radius,
-            ScrollableState::HoveredHorizontal => self.hovered_horizontal.border_radius = radius,
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.border_radius = radius
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.border_radius = radius,
-            ScrollableState::All => {
-                self.active.border_radius = radius;
-                self.hovered.border_radius = radius;
-                self.dragging.border_radius = radius;
-                self.active_horizontal.border_radius = radius;
-                self.hovered_horizontal.border_radius = radius;
-                self.dragging_horizontal.border_radius = radius;
+    pub fn colors(&self) -> impl Iterator<Item = (String, Color)> {
+        let mut colors = Vec::new();
+        for (state, scrollbar) in [
+            ("active", &self.active),
+            ("hovered", &self.hovered),
+            ("hovered_over_scrollbar", &self.hovered_over_scrollbar),
+            ("dragging", &self.dragging),
+            ("active_horizontal", &self.active_horizontal),
+            ("hovered_horizontal", &self.hovered_horizontal),
+            (
+                "hovered_horizontal_over_scrollbar",
+                &self.hovered_horizontal_over_scrollbar,
+            ),
+            ("dragging_horizontal", &self.dragging_horizontal),
+        ] {
+            if let Some(background) = scrollbar.background {
+                background_colors(format!("{state}.background"), background, &mut colors);
             }
+            colors.push((format!("{state}.border_color"), scrollbar.border_color));
+            colors.push((format!("{state}.scroller.color"), scrollbar.scroller.color));
+            colors.push((
+                format!("{state}.scroller.border_color"),
+                scrollbar.scroller.border_color,
+            ));
         }
+        colors.into_iter()
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+
+    pub fn as_custom(&self) -> iced::theme::Scrollable {
+        iced::theme::Scrollable::Custom(Box::new(*self))
+    }
+}
+
+impl StyleSheet for CustomScrollableStyle {
+    type Style = iced::Theme;
+
+    fn active(&self, style: &Self::Style) -> Scrollbar {
+        ScrollableStyle::from(*self).active(style)
+    }
+
+    fn hovered(&self, style: &Self::Style, is_mouse_over_scrollbar: bool) -> Scrollbar {
+        ScrollableStyle::from(*self).hovered(style, is_mouse_over_scrollbar)
+    }
+
+    fn dragging(&self, style: &Self::Style) -> Scrollbar {
+        ScrollableStyle::from(*self).dragging(style)
+    }
+
+    fn active_horizontal(&self, style: &Self::Style) -> Scrollbar {
+        ScrollableStyle::from(*self).active_horizontal(style)
+    }
+
+    fn hovered_horizontal(&self, style: &Self::Style, is_mouse_over_scrollbar: bool) -> Scrollbar {
+        ScrollableStyle::from(*self).hovered_horizontal(style, is_mouse_over_scrollbar)
+    }
+
+    fn dragging_horizontal(&self, style: &Self::Style) -> Scrollbar {
+        ScrollableStyle::from(*self).dragging_horizontal(style)
+    }
+}
+
+/// Like [`ButtonBuilder`](crate::styles::button::ButtonBuilder), with one editor or closure per
+/// scrollbar.
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollableBuilder(ScrollableStyle);
+
+impl ScrollableBuilder {
+    pub const fn active_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.active = editor.0;
+        self
+    }
+
+    pub const fn hovered_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.hovered = editor.0;
+        self
+    }
+
+    pub const fn hovered_over_scrollbar_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.hovered_over_scrollbar = editor.0;
+        self
+    }
+
+    pub const fn dragging_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.dragging = editor.0;
+        self
+    }
+
+    pub const fn active_horizontal_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.active_horizontal = editor.0;
+        self
+    }
+
+    pub const fn hovered_horizontal_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.hovered_horizontal = editor.0;
+        self
+    }
+
+    pub const fn hovered_horizontal_over_scrollbar_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.hovered_horizontal_over_scrollbar = editor.0;
+        self
+    }
+
+    pub const fn dragging_horizontal_with(mut self, editor: ScrollbarEditor) -> Self {
+        self.0.dragging_horizontal = editor.0;
+        self
+    }
+
+    /// Sets every scrollbar to the one of `editor`.
+    pub const fn all_with(self, editor: ScrollbarEditor) -> Self {
+        self.active_with(editor)
+            .hovered_with(editor)
+            .hovered_over_scrollbar_with(editor)
+            .dragging_with(editor)
+            .active_horizontal_with(editor)
+            .hovered_horizontal_with(editor)
+            .hovered_horizontal_over_scrollbar_with(editor)
+            .dragging_horizontal_with(editor)
+    }
+
+    pub fn active(mut self, edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor) -> Self {
+        self.0.active = edit(ScrollbarEditor(self.0.active)).0;
+        self
+    }
+
+    pub fn hovered(mut self, edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor) -> Self {
+        self.0.hovered = edit(ScrollbarEditor(self.0.hovered)).0;
+        self
+    }
+
+    pub fn hovered_over_scrollbar(
+        mut self,
+        edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor,
+    ) -> Self {
+        self.0.hovered_over_scrollbar = edit(ScrollbarEditor(self.0.hovered_over_scrollbar)).0;
+        self
+    }
+
+    pub fn dragging(mut self, edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor) -> Self {
+        self.0.dragging = edit(ScrollbarEditor(self.0.dragging)).0;
+        self
+    }
+
+    pub fn active_horizontal(
+        mut self,
+        edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor,
+    ) -> Self {
+        self.0.active_horizontal = edit(ScrollbarEditor(self.0.active_horizontal)).0;
+        self
+    }
+
+    pub fn hovered_horizontal(
+        mut self,
+        edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor,
+    ) -> Self {
+        self.0.hovered_horizontal = edit(ScrollbarEditor(self.0.hovered_horizontal)).0;
+        self
+    }
+
+    pub fn hovered_horizontal_over_scrollbar(
+        mut self,
+        edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor,
+    ) -> Self {
+        self.0.hovered_horizontal_over_scrollbar =
+            edit(ScrollbarEditor(self.0.hovered_horizontal_over_scrollbar)).0;
+        self
+    }
+
+    pub fn dragging_horizontal(
+        mut self,
+        edit: impl FnOnce(ScrollbarEditor) -> ScrollbarEditor,
+    ) -> Self {
+        self.0.dragging_horizontal = edit(ScrollbarEditor(self.0.dragging_horizontal)).0;
+        self
+    }
+
+    /// Runs `edit` on every scrollbar in turn.
+    pub fn all(self, mut edit: impl FnMut(ScrollbarEditor) -> ScrollbarEditor) -> Self {
+        self.active(&mut edit)
+            .hovered(&mut edit)
+            .hovered_over_scrollbar(&mut edit)
+            .dragging(&mut edit)
+            .active_horizontal(&mut edit)
+            .hovered_horizontal(&mut edit)
+            .hovered_horizontal_over_scrollbar(&mut edit)
+            .dragging_horizontal(&mut edit)
+    }
+
+    pub const fn build(self) -> ScrollableStyle {
+        self.0
+    }
+}
+
+impl From<CustomScrollableStyle> for ScrollableBuilder {
+    fn from(style: CustomScrollableStyle) -> Self {
+        Self(style.into())
+    }
+}
+
+/// The setters of [`CustomScrollableStyle`] for a single [`Scrollbar`].
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollbarEditor(pub Scrollbar);
+
+impl ScrollbarEditor {
+    /// Starts from the scrollbar every state of [`CustomScrollableStyle::new`] has.
+    pub const fn new() -> Self {
+        let radius = super::border_radius([0.0; 4]);
+        Self(Scrollbar {
+            background: None,
+            border_radius: radius,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            scroller: Scroller {
+                color: Color::TRANSPARENT,
+                border_radius: radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+        })
+    }
+
+    pub const fn background(mut self, background: Option<Background>) -> Self {
+        self.0.background = background;
+        self
+    }
+
+    pub const fn background_color(mut self, color: Color) -> Self {
+        self.0.background = Some(Background::Color(color));
+        self
+    }
+
+    pub const fn border_radius(mut self, radius: BorderRadius) -> Self {
+        self.0.border_radius = radius;
         self
     }
 
     pub const fn border_width(mut self, width: f32) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.border_width = width,
-            ScrollableState::Hovered => self.hovered.border_width = width,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.border_width = width
-            }
-            ScrollableState::Dragging => self.dragging.border_width = width,
-            ScrollableState::ActiveHorizontal => self.active_horizontal.border_width = width,
-            ScrollableState::HoveredHorizontal => self.hovered_horizontal.border_width = width,
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.border_width = width
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.border_width = width,
-            ScrollableState::All => {
-                self.active.border_width = width;
-                self.hovered.border_width = width;
-                self.dragging.border_width = width;
-                self.active_horizontal.border_width = width;
-                self.hovered_horizontal.border_width = width;
-                self.dragging_horizontal.border_width = width;
-            }
-        }
+        self.0.border_width = width;
         self
     }
 
     pub const fn border_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.border_color = color,
-            ScrollableState::Hovered => self.hovered.border_color = color,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.border_color = color
-            }
-            ScrollableState::Dragging => self.dragging.border_color = color,
-            ScrollableState::ActiveHorizontal => self.active_horizontal.border_color = color,
-            ScrollableState::HoveredHorizontal => self.hovered_horizontal.border_color = color,
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.border_color = color
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.border_color = color,
-            ScrollableState::All => {
-                self.active.border_color = color;
-                self.hovered.border_color = color;
-                self.dragging.border_color = color;
-                self.active_horizontal.border_color = color;
-                self.hovered_horizontal.border_color = color;
-                self.dragging_horizontal.border_color = color;
-            }
-        }
+        self.0.border_color = color;
         self
     }
 
     pub const fn scroller_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.scroller.color = color,
-            ScrollableState::Hovered => self.hovered.scroller.color = color,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.scroller.color = color
-            }
-            ScrollableState::Dragging => self.dragging.scroller.color = color,
-            ScrollableState::ActiveHorizontal => self.active_horizontal.scroller.color = color,
-            ScrollableState::HoveredHorizontal => self.hovered_horizontal.scroller.color = color,
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.scroller.color = color
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.scroller.color = color,
-            ScrollableState::All => {
-                self.active.scroller.color = color;
-                self.hovered.scroller.color = color;
-                self.dragging.scroller.color = color;
-                self.active_horizontal.scroller.color = color;
-                self.hovered_horizontal.scroller.color = color;
-                self.dragging_horizontal.scroller.color = color;
-            }
-        }
+        self.0.scroller.color = color;
         self
     }
 
     pub const fn scroller_border_radius(mut self, radius: BorderRadius) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.scroller.border_radius = radius,
-            ScrollableState::Hovered => self.hovered.scroller.border_radius = radius,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.scroller.border_radius = radius
-            }
-            ScrollableState::Dragging => self.dragging.scroller.border_radius = radius,
-            ScrollableState::ActiveHorizontal => {
-                self.active_horizontal.scroller.border_radius = radius
-            }
-            ScrollableState::HoveredHorizontal => {
-                self.hovered_horizontal.scroller.border_radius = radius
-            }
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar
-                    .scroller
-                    .border_radius = radius
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.scroller.border_radius = radius,
-            ScrollableState::All => {
-                self.active.scroller.border_radius = radius;
-                self.hovered.scroller.border_radius = radius;
-                self.dragging.scroller.border_radius = radius;
-                self.active_horizontal.scroller.border_radius = radius;
-                self.hovered_horizontal.scroller.border_radius = radius;
-                self.dragging_horizontal.scroller.border_radius = radius;
-            }
-        }
+        self.0.scroller.border_radius = radius;
         self
     }
 
     pub const fn scroller_border_width(mut self, width: f32) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.scroller.border_width = width,
-            ScrollableState::Hovered => self.hovered.scroller.border_width = width,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.scroller.border_width = width
-            }
-            ScrollableState::Dragging => self.dragging.scroller.border_width = width,
-            ScrollableState::ActiveHorizontal => {
-                self.active_horizontal.scroller.border_width = width
-            }
-            ScrollableState::HoveredHorizontal => {
-                self.hovered_horizontal.scroller.border_width = width
-            }
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.scroller.border_width = width
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.scroller.border_width = width,
-            ScrollableState::All => {
-                self.active.scroller.border_width = width;
-                self.hovered.scroller.border_width = width;
-                self.dragging.scroller.border_width = width;
-                self.active_horizontal.scroller.border_width = width;
-                self.hovered_horizontal.scroller.border_width = width;
-                self.dragging_horizontal.scroller.border_width = width;
-            }
-        }
+        self.0.scroller.border_width = width;
         self
     }
 
     pub const fn scroller_border_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            ScrollableState::Active => self.active.scroller.border_color = color,
-            ScrollableState::Hovered => self.hovered.scroller.border_color = color,
-            ScrollableState::HoveredOverScrollbar => {
-                self.hovered_over_scrollbar.scroller.border_color = color
-            }
-            ScrollableState::Dragging => self.dragging.scroller.border_color = color,
-            ScrollableState::ActiveHorizontal => {
-                self.active_horizontal.scroller.border_color = color
-            }
-            ScrollableState::HoveredHorizontal => {
-                self.hovered_horizontal.scroller.border_color = color
-            }
-            ScrollableState::HoveredHorizontalOverScrollbar => {
-                self.hovered_horizontal_over_scrollbar.scroller.border_color = color
-            }
-            ScrollableState::DraggingHorizontal => self.dragging.scroller.border_color = color,
-            ScrollableState::All => {
-                self.active.scroller.border_color = color;
-                self.hovered.scroller.border_color = color;
-                self.dragging.scroller.border_color = color;
-                self.active_horizontal.scroller.border_color = color;
-                self.hovered_horizontal.scroller.border_color = color;
-                self.dragging_horizontal.scroller.border_color = color;
-            }
-        }
+        self.0.scroller.border_color = color;
         self
     }
+}
 
+/// A scrollable style without a state cursor, as produced by [`ScrollableBuilder`].
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollableStyle {
+    pub active: Scrollbar,
+    pub hovered: Scrollbar,
+    pub hovered_over_scrollbar: Scrollbar,
+    pub dragging: Scrollbar,
+    pub active_horizontal: Scrollbar,
+    pub hovered_horizontal: Scrollbar,
+    pub hovered_horizontal_over_scrollbar: Scrollbar,
+    pub dragging_horizontal: Scrollbar,
+}
+
+impl ScrollableStyle {
     pub fn as_custom(&self) -> iced::theme::Scrollable {
         iced::theme::Scrollable::Custom(Box::new(*self))
     }
 }
 
-impl StyleSheet for CustomScrollableStyle {
+impl From<CustomScrollableStyle> for ScrollableStyle {
+    fn from(style: CustomScrollableStyle) -> Self {
+        Self {
+            active: style.active,
+            hovered: style.hovered,
+            hovered_over_scrollbar: style.hovered_over_scrollbar,
+            dragging: style.dragging,
+            active_horizontal: style.active_horizontal,
+            hovered_horizontal: style.hovered_horizontal,
+            hovered_horizontal_over_scrollbar: style.hovered_horizontal_over_scrollbar,
+            dragging_horizontal: style.dragging_horizontal,
+        }
+    }
+}
+
+impl From<ScrollableStyle> for CustomScrollableStyle {
+    fn from(style: ScrollableStyle) -> Self {
+        Self {
+            active: style.active,
+            hovered: style.hovered,
+            hovered_over_scrollbar: style.hovered_over_scrollbar,
+            dragging: style.dragging,
+            active_horizontal: style.active_horizontal,
+            hovered_horizontal: style.hovered_horizontal,
+            hovered_horizontal_over_scrollbar: style.hovered_horizontal_over_scrollbar,
+            dragging_horizontal: style.dragging_horizontal,
+            current_state: Default::default(),
+            tokens: TokenTable::DEFAULT,
+        }
+    }
+}
+
+impl StyleSheet for ScrollableStyle {
     type Style = iced::Theme;
 
     fn active(&self, _style: &Self::Style) -> Scrollbar {
@@ -407,3 +887,167 @@ impl StyleSheet for CustomScrollableStyle {
         self.dragging_horizontal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A style whose scrollers are numbered in flag order, to tell the scrollbars apart.
+    fn numbered() -> CustomScrollableStyle {
+        let mut style = CustomScrollableStyle::new();
+        for (index, state) in ScrollableState::all().iter().enumerate() {
+            style = style.states(state).scroller_color(shade(index));
+        }
+        style
+    }
+
+    fn shade(index: usize) -> Color {
+        Color::from_rgb(index as f32 / 8.0, 0.0, 0.0)
+    }
+
+    /// The number of the scroller of each scrollbar, in flag order.
+    fn numbers(style: CustomScrollableStyle) -> [usize; 8] {
+        style
+            .scrollbars()
+            .map(|scrollbar| (scrollbar.scroller.color.r * 8.0).round() as usize)
+    }
+
+    #[test]
+    fn copies_each_vertical_scrollbar_to_its_horizontal_twin() {
+        let style = numbered().copy_state(ScrollableState::VERTICAL, ScrollableState::HORIZONTAL);
+        assert_eq!(numbers(style), [0, 1, 2, 3, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn copies_a_single_scrollbar_over_all_of_the_target() {
+        let style = numbered().copy_state(ScrollableState::DRAGGING, ScrollableState::HORIZONTAL);
+        assert_eq!(numbers(style), [0, 1, 2, 3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn swaps_several_scrollbars_pairwise() {
+        let style = numbered().swap_states(
+            ScrollableState::ACTIVE | ScrollableState::DRAGGING,
+            ScrollableState::HOVERED_HORIZONTAL | ScrollableState::DRAGGING_HORIZONTAL,
+        );
+        assert_eq!(numbers(style), [5, 1, 2, 7, 4, 0, 6, 3]);
+
+        let style = numbered().swap_states(ScrollableState::VERTICAL, ScrollableState::HORIZONTAL);
+        assert_eq!(numbers(style), [4, 5, 6, 7, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pair")]
+    fn copying_between_sets_of_different_sizes_panics() {
+        numbered().copy_state(
+            ScrollableState::ACTIVE | ScrollableState::HOVERED,
+            ScrollableState::HORIZONTAL,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pair")]
+    fn swapping_sets_of_different_sizes_panics() {
+        numbered().swap_states(ScrollableState::DRAGGING, ScrollableState::HORIZONTAL);
+    }
+
+    #[test]
+    fn both_selects_one_interaction_state_in_each_orientation() {
+        let style = numbered()
+            .both()
+            .hovered_over_scrollbar()
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 1, 8, 3, 4, 5, 8, 7]);
+    }
+
+    #[test]
+    fn all_covers_the_over_scrollbar_variants() {
+        let style = numbered().all().scroller_color(shade(8));
+        assert_eq!(numbers(style), [8; 8]);
+    }
+
+    #[test]
+    fn orientation_moves_the_current_selection() {
+        let style = numbered().hovered().horizontal().scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 1, 2, 3, 4, 8, 6, 7]);
+
+        let style = numbered().dragging().both().scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 1, 2, 8, 4, 5, 6, 8]);
+
+        let style = numbered()
+            .states(ScrollableState::HOVERED | ScrollableState::DRAGGING_HORIZONTAL)
+            .vertical()
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 8, 2, 8, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn interaction_states_stay_in_both_scrollbars_while_both_are_selected() {
+        let style = numbered()
+            .both()
+            .hovered()
+            .scroller_color(shade(8))
+            .dragging()
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 8, 2, 8, 4, 8, 6, 8]);
+
+        let style = numbered().all().hovered().scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 8, 2, 3, 4, 8, 6, 7]);
+
+        let style = numbered()
+            .both()
+            .hovered()
+            .vertical()
+            .dragging()
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 1, 2, 8, 4, 5, 6, 7]);
+
+        let style = numbered()
+            .hovered_horizontal()
+            .dragging()
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 1, 2, 8, 4, 5, 6, 7]);
+    }
+
+    const THUMB: ScrollbarEditor = ScrollbarEditor::new()
+        .scroller_color(Color::from_rgb(0.5, 0.5, 0.5))
+        .scroller_border_radius(crate::styles::border_radius([4.0; 4]));
+    const BUILT: ScrollableStyle = CustomScrollableStyle::builder()
+        .all_with(THUMB)
+        .dragging_with(THUMB.scroller_color(Color::BLACK))
+        .dragging_horizontal_with(THUMB.scroller_color(Color::BLACK))
+        .build();
+
+    #[test]
+    fn const_builders_match_the_setter_chain() {
+        let chained = CustomScrollableStyle::new()
+            .all()
+            .scroller_color(Color::from_rgb(0.5, 0.5, 0.5))
+            .scroller_border_radius(4.0)
+            .dragging()
+            .both()
+            .scroller_color(Color::BLACK);
+        assert_eq!(
+            format!("{BUILT:?}"),
+            format!("{:?}", ScrollableStyle::from(chained))
+        );
+
+        let style = CustomScrollableStyle::from(BUILT);
+        assert_eq!(style.current_state, ScrollableState::ACTIVE);
+    }
+
+    #[test]
+    fn all_except_selects_the_complement() {
+        let style = numbered()
+            .all_except(ScrollableState::VERTICAL)
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [0, 1, 2, 3, 8, 8, 8, 8]);
+
+        let style = numbered()
+            .all_except(
+                ScrollableState::HOVERED_OVER_SCROLLBAR | ScrollableState::DRAGGING_HORIZONTAL,
+            )
+            .scroller_color(shade(8));
+        assert_eq!(numbers(style), [8, 8, 2, 8, 8, 8, 8, 7]);
+    }
+}
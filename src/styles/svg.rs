@@ -1,4 +1,8 @@
+use crate::css_color;
+use crate::cvd::Deficiency;
+use crate::diagnostic::Diagnostic;
 use iced::widget::svg::{Appearance, StyleSheet};
+use iced::Color;
 
 #[derive(Debug, Clone, Copy)]
 pub struct CustomSvgStyle {
@@ -19,6 +23,26 @@ impl CustomSvgStyle {
         self
     }
 
+    pub fn color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.color(Some(css_color::parse(color)?)))
+    }
+
+    pub fn map_colors(mut self, f: impl FnMut(Color) -> Color) -> Self {
+        self.appearance.color = self.appearance.color.map(f);
+        self
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (String, Color)> {
+        self.appearance
+            .color
+            .map(|color| ("color".to_string(), color))
+            .into_iter()
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+
     pub fn as_custom(&self) -> iced::theme::Svg {
         iced::theme::Svg::Custom(Box::new(*self))
     }
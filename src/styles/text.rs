@@ -1,3 +1,6 @@
+use crate::css_color;
+use crate::cvd::Deficiency;
+use crate::diagnostic::Diagnostic;
 use iced::widget::text::{Appearance, StyleSheet};
 use iced::Color;
 
@@ -20,6 +23,26 @@ impl CustomTextStyle {
         self
     }
 
+    pub fn color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.color(css_color::parse(color)?))
+    }
+
+    pub fn map_colors(mut self, f: impl FnMut(Color) -> Color) -> Self {
+        self.appearance.color = self.appearance.color.map(f);
+        self
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (String, Color)> {
+        self.appearance
+            .color
+            .map(|color| ("color".to_string(), color))
+            .into_iter()
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+
     pub fn as_custom(&self) -> iced::theme::Text {
         iced::theme::Text::Color(self.appearance.color.unwrap_or(Color::TRANSPARENT))
     }
@@ -1,3 +1,14 @@
+use crate::color::over;
+use crate::css_color;
+use crate::cvd::Deficiency;
+use crate::diagnostic::Diagnostic;
+use crate::high_contrast::{
+    effective_background, enforce_contrast, opaque_background, FOCUSED_BORDER_WIDTH,
+    MIN_BORDER_WIDTH, NON_TEXT_CONTRAST, TEXT_CONTRAST,
+};
+use crate::tokens::{RadiusValue, TokenTable, WidthValue};
+use crate::visit::{background_colors, map_background};
+use bitflags::bitflags;
 use iced::widget::text_input::{Appearance, StyleSheet};
 use iced::{Background, BorderRadius, Color};
 
@@ -12,195 +23,418 @@ pub struct CustomTextInputStyle {
     pub disabled_color: Color,
     pub selection_color: Color,
     pub current_state: TextInputState,
+    /// What the radius and width tokens passed to the setters resolve to.
+    pub tokens: TokenTable,
 }
 
-#[derive(Default, Copy, Clone, Debug)]
-pub enum TextInputState {
-    #[default]
-    Active,
-    Hovered,
-    Disabled,
-    Focused,
-    All,
+bitflags! {
+    /// The states the builder methods apply to, e.g. `TextInputState::HOVERED | TextInputState::ACTIVE`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TextInputState: u8 {
+        const ACTIVE = 1 << 0;
+        const HOVERED = 1 << 1;
+        const DISABLED = 1 << 2;
+        const FOCUSED = 1 << 3;
+    }
+}
+
+impl Default for TextInputState {
+    fn default() -> Self {
+        Self::ACTIVE
+    }
+}
+
+/// The names the states had as enum variants, before they became flags.
+#[allow(non_upper_case_globals)]
+impl TextInputState {
+    #[deprecated(note = "use `TextInputState::ACTIVE`")]
+    pub const Active: Self = Self::ACTIVE;
+    #[deprecated(note = "use `TextInputState::HOVERED`")]
+    pub const Hovered: Self = Self::HOVERED;
+    #[deprecated(note = "use `TextInputState::DISABLED`")]
+    pub const Disabled: Self = Self::DISABLED;
+    #[deprecated(note = "use `TextInputState::FOCUSED`")]
+    pub const Focused: Self = Self::FOCUSED;
+    #[deprecated(note = "use `TextInputState::all()`")]
+    pub const All: Self = Self::all();
 }
 
 impl CustomTextInputStyle {
-    pub fn new() -> Self {
-        let default = Appearance {
-            background: Background::Color(Color::BLACK),
-            border_radius: BorderRadius::from(0.0),
-            border_width: 0.0,
-            border_color: Color::WHITE,
-            icon_color: Color::WHITE,
-        };
+    pub const fn new() -> Self {
+        let default = AppearanceEditor::new().0;
         Self {
             active: default,
             hovered: default,
             disabled: default,
             focused: default,
-            placeholder_color: Default::default(),
-            value_color: Default::default(),
-            disabled_color: Default::default(),
-            selection_color: Default::default(),
-            current_state: Default::default(),
+            placeholder_color: Color::TRANSPARENT,
+            value_color: Color::TRANSPARENT,
+            disabled_color: Color::TRANSPARENT,
+            selection_color: Color::TRANSPARENT,
+            current_state: TextInputState::ACTIVE,
+            tokens: TokenTable::DEFAULT,
         }
     }
 
     pub fn default(theme: &iced::Theme) -> Self {
+        Self::from_preset(theme, &iced::theme::TextInput::Default)
+    }
+
+    fn from_preset(theme: &iced::Theme, preset: &iced::theme::TextInput) -> Self {
         Self {
-            active: theme.active(&iced::theme::TextInput::Default),
-            hovered: theme.hovered(&iced::theme::TextInput::Default),
-            disabled: theme.disabled(&iced::theme::TextInput::Default),
-            placeholder_color: theme.placeholder_color(&iced::theme::TextInput::Default),
-            value_color: theme.value_color(&iced::theme::TextInput::Default),
-            disabled_color: theme.disabled_color(&iced::theme::TextInput::Default),
-            focused: theme.focused(&iced::theme::TextInput::Default),
-            selection_color: theme.selection_color(&iced::theme::TextInput::Default),
+            active: theme.active(preset),
+            hovered: theme.hovered(preset),
+            disabled: theme.disabled(preset),
+            placeholder_color: theme.placeholder_color(preset),
+            value_color: theme.value_color(preset),
+            disabled_color: theme.disabled_color(preset),
+            focused: theme.focused(preset),
+            selection_color: theme.selection_color(preset),
             current_state: Default::default(),
+            tokens: TokenTable::DEFAULT,
         }
     }
 
-    pub fn all(mut self) -> Self {
-        self.current_state = TextInputState::All;
-        self
+    /// Starts a [`TextInputBuilder`] from the same appearances and colors as
+    /// [`CustomTextInputStyle::new`].
+    pub const fn builder() -> TextInputBuilder {
+        let style = Self::new();
+        TextInputBuilder(TextInputStyle {
+            active: style.active,
+            focused: style.focused,
+            hovered: style.hovered,
+            disabled: style.disabled,
+            placeholder_color: style.placeholder_color,
+            value_color: style.value_color,
+            disabled_color: style.disabled_color,
+            selection_color: style.selection_color,
+        })
     }
 
-    pub fn active(mut self) -> Self {
-        self.current_state = TextInputState::Active;
+    /// Resolves the tokens passed to later setters against `tokens`.
+    pub fn tokens(mut self, tokens: &TokenTable) -> Self {
+        self.tokens = *tokens;
         self
     }
 
-    pub fn hovered(mut self) -> Self {
-        self.current_state = TextInputState::Hovered;
+    pub fn states(mut self, states: TextInputState) -> Self {
+        self.current_state = states;
         self
     }
 
-    pub fn disabled(mut self) -> Self {
-        self.current_state = TextInputState::Disabled;
-        self
+    pub fn all_except(self, states: TextInputState) -> Self {
+        self.states(TextInputState::all().difference(states))
     }
 
-    pub fn focused(mut self) -> Self {
-        self.current_state = TextInputState::Focused;
-        self
+    pub fn all(self) -> Self {
+        self.states(TextInputState::all())
+    }
+
+    pub fn active(self) -> Self {
+        self.states(TextInputState::ACTIVE)
+    }
+
+    pub fn hovered(self) -> Self {
+        self.states(TextInputState::HOVERED)
+    }
+
+    pub fn disabled(self) -> Self {
+        self.states(TextInputState::DISABLED)
+    }
+
+    pub fn focused(self) -> Self {
+        self.states(TextInputState::FOCUSED)
+    }
+
+    fn appearances_mut(
+        &mut self,
+        states: TextInputState,
+    ) -> impl Iterator<Item = (TextInputState, &mut Appearance)> {
+        [
+            (TextInputState::ACTIVE, &mut self.active),
+            (TextInputState::HOVERED, &mut self.hovered),
+            (TextInputState::DISABLED, &mut self.disabled),
+            (TextInputState::FOCUSED, &mut self.focused),
+        ]
+        .into_iter()
+        .filter(move |(state, _)| states.contains(*state))
+    }
+
+    fn selected(&mut self) -> impl Iterator<Item = &mut Appearance> {
+        self.appearances_mut(self.current_state)
+            .map(|(_, appearance)| appearance)
+    }
+
+    /// The appearance of the first state in `states`, or the active one when it is empty.
+    fn appearance(&self, states: TextInputState) -> Appearance {
+        [
+            (TextInputState::ACTIVE, self.active),
+            (TextInputState::HOVERED, self.hovered),
+            (TextInputState::DISABLED, self.disabled),
+            (TextInputState::FOCUSED, self.focused),
+        ]
+        .into_iter()
+        .find(|(state, _)| states.contains(*state))
+        .map_or(self.active, |(_, appearance)| appearance)
     }
 
     pub fn background(mut self, background: Background) -> Self {
-        match self.current_state {
-            TextInputState::Active => self.active.background = background,
-            TextInputState::Hovered => self.hovered.background = background,
-            TextInputState::Disabled => self.disabled.background = background,
-            TextInputState::Focused => self.active.background = background,
-            TextInputState::All => {
-                self.active.background = background;
-                self.hovered.background = background;
-                self.disabled.background = background;
-                self.focused.background = background;
-            }
+        for appearance in self.selected() {
+            appearance.background = background;
         }
         self
     }
 
     pub fn background_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            TextInputState::Active => self.active.background = Background::Color(color),
-            TextInputState::Hovered => self.hovered.background = Background::Color(color),
-            TextInputState::Disabled => self.disabled.background = Background::Color(color),
-            TextInputState::Focused => self.focused.background = Background::Color(color),
-            TextInputState::All => {
-                self.active.background = Background::Color(color);
-                self.hovered.background = Background::Color(color);
-                self.disabled.background = Background::Color(color);
-                self.focused.background = Background::Color(color);
-            }
+        for appearance in self.selected() {
+            appearance.background = Background::Color(color);
         }
         self
     }
 
-    pub fn border_radius(mut self, radius: BorderRadius) -> Self {
-        match self.current_state {
-            TextInputState::Active => self.active.border_radius = radius,
-            TextInputState::Hovered => self.hovered.border_radius = radius,
-            TextInputState::Disabled => self.disabled.border_radius = radius,
-            TextInputState::Focused => self.focused.border_radius = radius,
-            TextInputState::All => {
-                self.active.border_radius = radius;
-                self.hovered.border_radius = radius;
-                self.disabled.border_radius = radius;
-                self.focused.border_radius = radius;
-            }
+    pub fn background_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.background_color(css_color::parse(color)?))
+    }
+
+    pub fn border_radius(mut self, radius: impl Into<RadiusValue>) -> Self {
+        let radius = radius.into().resolve(&self.tokens);
+        for appearance in self.selected() {
+            appearance.border_radius = radius;
         }
         self
     }
 
-    pub fn border_width(mut self, width: f32) -> Self {
-        match self.current_state {
-            TextInputState::Active => self.active.border_width = width,
-            TextInputState::Hovered => self.hovered.border_width = width,
-            TextInputState::Disabled => self.disabled.border_width = width,
-            TextInputState::Focused => self.focused.border_width = width,
-            TextInputState::All => {
-                self.active.border_width = width;
-                self.hovered.border_width = width;
-                self.disabled.border_width = width;
-                self.focused.border_width = width;
-            }
+    pub fn border_width(mut self, width: impl Into<WidthValue>) -> Self {
+        let width = width.into().resolve(&self.tokens);
+        for appearance in self.selected() {
+            appearance.border_width = width;
         }
         self
     }
 
     pub fn border_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            TextInputState::Active => self.active.border_color = color,
-            TextInputState::Hovered => self.hovered.border_color = color,
-            TextInputState::Disabled => self.disabled.border_color = color,
-            TextInputState::Focused => self.focused.border_color = color,
-            TextInputState::All => {
-                self.active.border_color = color;
-                self.hovered.border_color = color;
-                self.disabled.border_color = color;
-                self.focused.border_color = color;
-            }
+        for appearance in self.selected() {
+            appearance.border_color = color;
         }
         self
     }
 
+    pub fn border_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.border_color(css_color::parse(color)?))
+    }
+
     pub fn icon_color(mut self, color: Color) -> Self {
-        match self.current_state {
-            TextInputState::Active => self.active.icon_color = color,
-            TextInputState::Hovered => self.hovered.icon_color = color,
-            TextInputState::Disabled => self.disabled.icon_color = color,
-            TextInputState::Focused => self.focused.icon_color = color,
-            TextInputState::All => {
-                self.active.icon_color = color;
-                self.hovered.icon_color = color;
-                self.disabled.icon_color = color;
-                self.focused.icon_color = color;
-            }
+        for appearance in self.selected() {
+            appearance.icon_color = color;
         }
         self
     }
 
+    pub fn icon_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.icon_color(css_color::parse(color)?))
+    }
+
     pub fn placeholder_color(mut self, color: Color) -> Self {
         self.placeholder_color = color;
         self
     }
 
+    pub fn placeholder_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.placeholder_color(css_color::parse(color)?))
+    }
+
     pub fn value_color(mut self, color: Color) -> Self {
         self.value_color = color;
         self
     }
 
+    pub fn value_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.value_color(css_color::parse(color)?))
+    }
+
     pub fn disabled_color(mut self, color: Color) -> Self {
         self.disabled_color = color;
         self
     }
 
+    pub fn disabled_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.disabled_color(css_color::parse(color)?))
+    }
+
     pub fn selection_color(mut self, color: Color) -> Self {
         self.selection_color = color;
         self
     }
 
+    pub fn selection_color_str(self, color: &str) -> Result<Self, Diagnostic> {
+        Ok(self.selection_color(css_color::parse(color)?))
+    }
+
+    /// Copies the states in `from` over those in `to`, pairing them in flag order. A single
+    /// state in `from` is copied over every state in `to`.
+    ///
+    /// # Panics
+    ///
+    /// When `from` holds several states but not as many as `to`.
+    pub fn copy_state(self, from: TextInputState, to: TextInputState) -> Self {
+        let count = from.bits().count_ones();
+        assert!(
+            count == 1 || count == to.bits().count_ones(),
+            "cannot pair {from:?} with {to:?}"
+        );
+        let sources: Vec<_> = from.iter().map(|state| self.appearance(state)).collect();
+        let mut sources = sources.into_iter().cycle();
+        self.map_state(to, |_| sources.next().expect("`from` is not empty"))
+    }
+
+    /// Exchanges the states in `a` with those in `b`, pairing them in flag order.
+    ///
+    /// # Panics
+    ///
+    /// When `a` and `b` hold different numbers of states.
+    pub fn swap_states(self, a: TextInputState, b: TextInputState) -> Self {
+        assert_eq!(
+            a.bits().count_ones(),
+            b.bits().count_ones(),
+            "cannot pair {a:?} with {b:?}"
+        );
+        let first: Vec<_> = a.iter().map(|state| self.appearance(state)).collect();
+        let second: Vec<_> = b.iter().map(|state| self.appearance(state)).collect();
+        let (mut first, mut second) = (first.into_iter(), second.into_iter());
+        self.map_state(a, |_| second.next().expect("as many states as `a`"))
+            .map_state(b, |_| first.next().expect("as many states as `b`"))
+    }
+
+    /// Restores the appearance of `states` to that of `preset` in `theme`. The colors shared by
+    /// all states are kept.
+    pub fn reset_state_to_theme(
+        mut self,
+        states: TextInputState,
+        theme: &iced::Theme,
+        preset: &iced::theme::TextInput,
+    ) -> Self {
+        let preset = Self::from_preset(theme, preset);
+        for (state, appearance) in self.appearances_mut(states) {
+            *appearance = preset.appearance(state);
+        }
+        self
+    }
+
+    pub fn map_state(
+        mut self,
+        states: TextInputState,
+        mut f: impl FnMut(Appearance) -> Appearance,
+    ) -> Self {
+        for (_, appearance) in self.appearances_mut(states) {
+            *appearance = f(*appearance);
+        }
+        self
+    }
+
+    pub fn high_contrast(mut self, theme: &iced::Theme) -> Self {
+        let palette = theme.palette();
+        let canvas = palette.background;
+        let mut backgrounds = [canvas; 4];
+        for (appearance, background) in [
+            &mut self.active,
+            &mut self.hovered,
+            &mut self.focused,
+            &mut self.disabled,
+        ]
+        .into_iter()
+        .zip(backgrounds.iter_mut())
+        {
+            *background = effective_background(Some(appearance.background), canvas);
+            appearance.background = opaque_background(Some(appearance.background), canvas)
+                .unwrap_or(Background::Color(*background));
+            appearance.icon_color =
+                enforce_contrast(appearance.icon_color, &[*background], TEXT_CONTRAST);
+            appearance.border_width = appearance.border_width.max(MIN_BORDER_WIDTH);
+            appearance.border_color = enforce_contrast(
+                appearance.border_color,
+                &[canvas, *background],
+                NON_TEXT_CONTRAST,
+            );
+        }
+        let [active, hovered, focused, disabled] = backgrounds;
+
+        self.selection_color = over(self.selection_color, focused);
+        self.value_color = enforce_contrast(
+            self.value_color,
+            &[active, hovered, focused, self.selection_color],
+            TEXT_CONTRAST,
+        );
+        self.placeholder_color = enforce_contrast(
+            self.placeholder_color,
+            &[active, hovered, focused],
+            TEXT_CONTRAST,
+        );
+        self.disabled_color = enforce_contrast(self.disabled_color, &[disabled], TEXT_CONTRAST);
+
+        let widest = self
+            .active
+            .border_width
+            .max(self.hovered.border_width)
+            .max(self.disabled.border_width);
+        self.focused.border_width = (widest + 1.0).max(FOCUSED_BORDER_WIDTH);
+        self.focused.border_color =
+            enforce_contrast(palette.primary, &[canvas, focused], TEXT_CONTRAST);
+        self
+    }
+
+    pub fn map_colors(mut self, mut f: impl FnMut(Color) -> Color) -> Self {
+        for appearance in [
+            &mut self.active,
+            &mut self.hovered,
+            &mut self.focused,
+            &mut self.disabled,
+        ] {
+            appearance.background = map_background(appearance.background, &mut f);
+            appearance.border_color = f(appearance.border_color);
+            appearance.icon_color = f(appearance.icon_color);
+        }
+        for color in [
+            &mut self.placeholder_color,
+            &mut self.value_color,
+            &mut self.disabled_color,
+            &mut self.selection_color,
+        ] {
+            *color = f(*color);
+        }
+        self
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = (String, Color)> {
+        let mut colors = Vec::new();
+        for (state, appearance) in [
+            ("active", &self.active),
+            ("hovered", &self.hovered),
+            ("focused", &self.focused),
+            ("disabled", &self.disabled),
+        ] {
+            background_colors(
+                format!("{state}.background"),
+                appearance.background,
+                &mut colors,
+            );
+            colors.push((format!("{state}.border_color"), appearance.border_color));
+            colors.push((format!("{state}.icon_color"), appearance.icon_color));
+        }
+        for (field, color) in [
+            ("placeholder_color", self.placeholder_color),
+            ("value_color", self.value_color),
+            ("disabled_color", self.disabled_color),
+            ("selection_color", self.selection_color),
+        ] {
+            colors.push((field.to_string(), color));
+        }
+        colors.into_iter()
+    }
+
+    pub fn simulate_deficiency(self, deficiency: Deficiency) -> Self {
+        self.map_colors(|color| deficiency.simulate(color))
+    }
+
     pub fn as_custom(&self) -> iced::theme::TextInput {
         iced::theme::TextInput::Custom(Box::new(*self))
     }
@@ -209,6 +443,233 @@ impl CustomTextInputStyle {
 impl StyleSheet for CustomTextInputStyle {
     type Style = iced::Theme;
 
+    fn active(&self, style: &Self::Style) -> Appearance {
+        TextInputStyle::from(*self).active(style)
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        TextInputStyle::from(*self).focused(style)
+    }
+
+    fn placeholder_color(&self, style: &Self::Style) -> Color {
+        TextInputStyle::from(*self).placeholder_color(style)
+    }
+
+    fn value_color(&self, style: &Self::Style) -> Color {
+        TextInputStyle::from(*self).value_color(style)
+    }
+
+    fn disabled_color(&self, style: &Self::Style) -> Color {
+        TextInputStyle::from(*self).disabled_color(style)
+    }
+
+    fn selection_color(&self, style: &Self::Style) -> Color {
+        TextInputStyle::from(*self).selection_color(style)
+    }
+
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        TextInputStyle::from(*self).hovered(style)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        TextInputStyle::from(*self).disabled(style)
+    }
+}
+
+/// Like [`ButtonBuilder`](crate::styles::button::ButtonBuilder), with the colors that are shared
+/// by all states set directly.
+#[derive(Debug, Clone, Copy)]
+pub struct TextInputBuilder(TextInputStyle);
+
+impl TextInputBuilder {
+    pub const fn active_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.active = editor.0;
+        self
+    }
+
+    pub const fn hovered_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.hovered = editor.0;
+        self
+    }
+
+    pub const fn focused_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.focused = editor.0;
+        self
+    }
+
+    pub const fn disabled_with(mut self, editor: AppearanceEditor) -> Self {
+        self.0.disabled = editor.0;
+        self
+    }
+
+    /// Sets every state to the appearance of `editor`.
+    pub const fn all_with(self, editor: AppearanceEditor) -> Self {
+        self.active_with(editor)
+            .hovered_with(editor)
+            .focused_with(editor)
+            .disabled_with(editor)
+    }
+
+    pub fn active(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.active = edit(AppearanceEditor(self.0.active)).0;
+        self
+    }
+
+    pub fn hovered(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.hovered = edit(AppearanceEditor(self.0.hovered)).0;
+        self
+    }
+
+    pub fn focused(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.focused = edit(AppearanceEditor(self.0.focused)).0;
+        self
+    }
+
+    pub fn disabled(mut self, edit: impl FnOnce(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.0.disabled = edit(AppearanceEditor(self.0.disabled)).0;
+        self
+    }
+
+    /// Runs `edit` on every state in turn.
+    pub fn all(self, mut edit: impl FnMut(AppearanceEditor) -> AppearanceEditor) -> Self {
+        self.active(&mut edit)
+            .hovered(&mut edit)
+            .focused(&mut edit)
+            .disabled(&mut edit)
+    }
+
+    pub const fn placeholder_color(mut self, color: Color) -> Self {
+        self.0.placeholder_color = color;
+        self
+    }
+
+    pub const fn value_color(mut self, color: Color) -> Self {
+        self.0.value_color = color;
+        self
+    }
+
+    pub const fn disabled_color(mut self, color: Color) -> Self {
+        self.0.disabled_color = color;
+        self
+    }
+
+    pub const fn selection_color(mut self, color: Color) -> Self {
+        self.0.selection_color = color;
+        self
+    }
+
+    pub const fn build(self) -> TextInputStyle {
+        self.0
+    }
+}
+
+impl From<CustomTextInputStyle> for TextInputBuilder {
+    fn from(style: CustomTextInputStyle) -> Self {
+        Self(style.into())
+    }
+}
+
+/// The setters of [`CustomTextInputStyle`] for a single [`Appearance`].
+#[derive(Debug, Clone, Copy)]
+pub struct AppearanceEditor(pub Appearance);
+
+impl AppearanceEditor {
+    /// Starts from the appearance every state of [`CustomTextInputStyle::new`] has.
+    pub const fn new() -> Self {
+        Self(Appearance {
+            background: Background::Color(Color::BLACK),
+            border_radius: super::border_radius([0.0; 4]),
+            border_width: 0.0,
+            border_color: Color::WHITE,
+            icon_color: Color::WHITE,
+        })
+    }
+
+    pub const fn background(mut self, background: Background) -> Self {
+        self.0.background = background;
+        self
+    }
+
+    pub const fn background_color(mut self, color: Color) -> Self {
+        self.0.background = Background::Color(color);
+        self
+    }
+
+    pub const fn border_radius(mut self, radius: BorderRadius) -> Self {
+        self.0.border_radius = radius;
+        self
+    }
+
+    pub const fn border_width(mut self, width: f32) -> Self {
+        self.0.border_width = width;
+        self
+    }
+
+    pub const fn border_color(mut self, color: Color) -> Self {
+        self.0.border_color = color;
+        self
+    }
+
+    pub const fn icon_color(mut self, color: Color) -> Self {
+        self.0.icon_color = color;
+        self
+    }
+}
+
+/// A text input style without a state cursor, as produced by [`TextInputBuilder`].
+#[derive(Debug, Clone, Copy)]
+pub struct TextInputStyle {
+    pub active: Appearance,
+    pub focused: Appearance,
+    pub hovered: Appearance,
+    pub disabled: Appearance,
+    pub placeholder_color: Color,
+    pub value_color: Color,
+    pub disabled_color: Color,
+    pub selection_color: Color,
+}
+
+impl TextInputStyle {
+    pub fn as_custom(&self) -> iced::theme::TextInput {
+        iced::theme::TextInput::Custom(Box::new(*self))
+    }
+}
+
+impl From<CustomTextInputStyle> for TextInputStyle {
+    fn from(style: CustomTextInputStyle) -> Self {
+        Self {
+            active: style.active,
+            focused: style.focused,
+            hovered: style.hovered,
+            disabled: style.disabled,
+            placeholder_color: style.placeholder_color,
+            value_color: style.value_color,
+            disabled_color: style.disabled_color,
+            selection_color: style.selection_color,
+        }
+    }
+}
+
+impl From<TextInputStyle> for CustomTextInputStyle {
+    fn from(style: TextInputStyle) -> Self {
+        Self {
+            active: style.active,
+            focused: style.focused,
+            hovered: style.hovered,
+            disabled: style.disabled,
+            placeholder_color: style.placeholder_color,
+            value_color: style.value_color,
+            disabled_color: style.disabled_color,
+            selection_color: style.selection_color,
+            current_state: Default::default(),
+            tokens: TokenTable::DEFAULT,
+        }
+    }
+}
+
+impl StyleSheet for TextInputStyle {
+    type Style = iced::Theme;
+
     fn active(&self, _style: &Self::Style) -> Appearance {
         self.active
     }
@@ -241,3 +702,154 @@ impl StyleSheet for CustomTextInputStyle {
         self.disabled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::border_radius;
+
+    /// A style whose border colors are numbered in flag order, to tell the states apart.
+    fn numbered() -> CustomTextInputStyle {
+        let mut style = CustomTextInputStyle::new();
+        for (index, state) in TextInputState::all().iter().enumerate() {
+            style = style.states(state).border_color(shade(index));
+        }
+        style
+    }
+
+    fn shade(index: usize) -> Color {
+        Color::from_rgb(index as f32 / 8.0, 0.0, 0.0)
+    }
+
+    /// The number of the border color of each state, in flag order.
+    fn numbers(style: CustomTextInputStyle) -> [usize; 4] {
+        [style.active, style.hovered, style.disabled, style.focused]
+            .map(|appearance| (appearance.border_color.r * 8.0).round() as usize)
+    }
+
+    const ACCENT: Color = Color::from_rgb(0.2, 0.4, 0.8);
+    const GRAY: Color = Color::from_rgb(0.5, 0.5, 0.5);
+
+    const BASE: AppearanceEditor = AppearanceEditor::new()
+        .background_color(Color::WHITE)
+        .border_radius(border_radius([2.0; 4]))
+        .border_width(1.0)
+        .border_color(GRAY);
+    const BUILT: TextInputStyle = CustomTextInputStyle::builder()
+        .all_with(BASE)
+        .focused_with(BASE.border_color(ACCENT).border_width(2.0))
+        .value_color(Color::BLACK)
+        .build();
+
+    /// Appearances have no `PartialEq`, so styles are compared by their debug output.
+    fn debug(style: impl Into<TextInputStyle>) -> String {
+        format!("{:?}", style.into())
+    }
+
+    #[test]
+    fn const_builders_match_the_setter_chain() {
+        let chained = CustomTextInputStyle::new()
+            .all()
+            .background_color(Color::WHITE)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .border_color(GRAY)
+            .focused()
+            .border_color(ACCENT)
+            .border_width(2.0)
+            .value_color(Color::BLACK);
+        assert_eq!(debug(BUILT), debug(chained));
+    }
+
+    #[test]
+    fn built_styles_carry_no_state_cursor() {
+        let style = CustomTextInputStyle::from(BUILT);
+        assert_eq!(style.current_state, TextInputState::ACTIVE);
+        let style = style.border_color(Color::BLACK);
+        assert_eq!(style.active.border_color, Color::BLACK);
+        assert_eq!(style.focused.border_color, ACCENT);
+    }
+
+    #[test]
+    fn copies_states_pairwise_in_flag_order() {
+        let style = numbered().copy_state(
+            TextInputState::ACTIVE | TextInputState::FOCUSED,
+            TextInputState::HOVERED | TextInputState::DISABLED,
+        );
+        assert_eq!(numbers(style), [0, 0, 3, 3]);
+
+        let style = numbered().copy_state(
+            TextInputState::FOCUSED,
+            TextInputState::ACTIVE | TextInputState::HOVERED,
+        );
+        assert_eq!(numbers(style), [3, 3, 2, 3]);
+    }
+
+    #[test]
+    fn swaps_states_pairwise_in_flag_order() {
+        let style = numbered().swap_states(
+            TextInputState::ACTIVE | TextInputState::HOVERED,
+            TextInputState::DISABLED | TextInputState::FOCUSED,
+        );
+        assert_eq!(numbers(style), [2, 3, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pair")]
+    fn copying_between_sets_of_different_sizes_panics() {
+        numbered().copy_state(
+            TextInputState::ACTIVE | TextInputState::HOVERED,
+            TextInputState::all(),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pair")]
+    fn swapping_sets_of_different_sizes_panics() {
+        numbered().swap_states(TextInputState::all(), TextInputState::FOCUSED);
+    }
+
+    #[test]
+    fn map_state_reaches_only_the_given_states() {
+        let style = numbered().map_state(
+            TextInputState::ACTIVE | TextInputState::FOCUSED,
+            |appearance| Appearance {
+                border_color: shade(8),
+                ..appearance
+            },
+        );
+        assert_eq!(numbers(style), [8, 1, 2, 8]);
+    }
+
+    #[test]
+    fn reset_state_to_theme_restores_only_the_given_states() {
+        let theme = iced::Theme::Light;
+        let preset = iced::theme::TextInput::Default;
+        let style = numbered().reset_state_to_theme(TextInputState::HOVERED, &theme, &preset);
+        let themed = CustomTextInputStyle::default(&theme);
+        assert_eq!(
+            format!("{:?}", style.hovered),
+            format!("{:?}", themed.hovered)
+        );
+        assert_eq!(numbers(style)[0], 0);
+        assert_eq!(numbers(style)[2..], [2, 3]);
+    }
+
+    #[test]
+    fn one_setter_reaches_every_selected_state() {
+        let style = numbered()
+            .states(TextInputState::HOVERED | TextInputState::FOCUSED)
+            .border_color(shade(8));
+        assert_eq!(numbers(style), [0, 8, 2, 8]);
+    }
+
+    #[test]
+    fn all_except_selects_the_complement() {
+        let style = numbered().all_except(TextInputState::DISABLED | TextInputState::FOCUSED);
+        assert_eq!(
+            style.current_state,
+            TextInputState::ACTIVE | TextInputState::HOVERED
+        );
+        assert_eq!(numbers(style.border_color(shade(8))), [8, 8, 2, 3]);
+    }
+}
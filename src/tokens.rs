@@ -0,0 +1,293 @@
+use iced::{BorderRadius, Color, Vector};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Radius {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderWidth {
+    Hairline,
+    Thin,
+    Thick,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Elevation {
+    Level0,
+    Level1,
+    Level2,
+    Level3,
+    Level4,
+    Level5,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opacity {
+    Hover,
+    Pressed,
+    Disabled,
+}
+
+/// A radius given as a token or in pixels, e.g. `.border_radius(Radius::Md)` or
+/// `.border_radius(6.0)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RadiusValue {
+    Token(Radius),
+    Pixels(BorderRadius),
+}
+
+impl RadiusValue {
+    pub fn resolve(self, table: &TokenTable) -> BorderRadius {
+        match self {
+            RadiusValue::Token(radius) => table.radius(radius).into(),
+            RadiusValue::Pixels(radius) => radius,
+        }
+    }
+}
+
+impl From<Radius> for RadiusValue {
+    fn from(radius: Radius) -> Self {
+        RadiusValue::Token(radius)
+    }
+}
+
+impl From<BorderRadius> for RadiusValue {
+    fn from(radius: BorderRadius) -> Self {
+        RadiusValue::Pixels(radius)
+    }
+}
+
+impl From<f32> for RadiusValue {
+    fn from(radius: f32) -> Self {
+        RadiusValue::Pixels(radius.into())
+    }
+}
+
+impl From<[f32; 4]> for RadiusValue {
+    fn from(radii: [f32; 4]) -> Self {
+        RadiusValue::Pixels(radii.into())
+    }
+}
+
+/// A border width given as a token or in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WidthValue {
+    Token(BorderWidth),
+    Pixels(f32),
+}
+
+impl WidthValue {
+    pub fn resolve(self, table: &TokenTable) -> f32 {
+        match self {
+            WidthValue::Token(width) => table.border_width(width),
+            WidthValue::Pixels(width) => width,
+        }
+    }
+}
+
+impl From<BorderWidth> for WidthValue {
+    fn from(width: BorderWidth) -> Self {
+        WidthValue::Token(width)
+    }
+}
+
+impl From<f32> for WidthValue {
+    fn from(width: f32) -> Self {
+        WidthValue::Pixels(width)
+    }
+}
+
+/// A shadow offset given as an elevation token or in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OffsetValue {
+    Token(Elevation),
+    Pixels(Vector),
+}
+
+impl OffsetValue {
+    pub fn resolve(self, table: &TokenTable) -> Vector {
+        match self {
+            OffsetValue::Token(elevation) => table.elevation(elevation),
+            OffsetValue::Pixels(offset) => offset,
+        }
+    }
+}
+
+impl From<Elevation> for OffsetValue {
+    fn from(elevation: Elevation) -> Self {
+        OffsetValue::Token(elevation)
+    }
+}
+
+impl From<Vector> for OffsetValue {
+    fn from(offset: Vector) -> Self {
+        OffsetValue::Pixels(offset)
+    }
+}
+
+/// The values every token resolves to. Indexed by the token's variant order.
+///
+/// Each style carries a table, [`TokenTable::DEFAULT`] unless replaced with its `tokens` setter,
+/// and its setters resolve tokens against it when they are called. The presets and tonal
+/// palettes build from a table passed in, see [`crate::presets::Preset::styles_with`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenTable {
+    pub radius: [f32; 5],
+    pub border_width: [f32; 3],
+    pub elevation: [Vector; 6],
+    pub opacity: [f32; 3],
+}
+
+impl TokenTable {
+    pub const DEFAULT: Self = Self {
+        radius: [2.0, 4.0, 8.0, 12.0, 16.0],
+        border_width: [1.0, 2.0, 3.0],
+        elevation: [
+            Vector::new(0.0, 0.0),
+            Vector::new(0.0, 1.0),
+            Vector::new(0.0, 2.0),
+            Vector::new(0.0, 3.0),
+            Vector::new(0.0, 4.0),
+            Vector::new(0.0, 6.0),
+        ],
+        opacity: [0.08, 0.12, 0.38],
+    };
+
+    pub const fn radius(&self, radius: Radius) -> f32 {
+        self.radius[radius as usize]
+    }
+
+    pub const fn border_width(&self, width: BorderWidth) -> f32 {
+        self.border_width[width as usize]
+    }
+
+    pub const fn elevation(&self, elevation: Elevation) -> Vector {
+        self.elevation[elevation as usize]
+    }
+
+    pub const fn opacity(&self, opacity: Opacity) -> f32 {
+        self.opacity[opacity as usize]
+    }
+
+    /// Blends `overlay` onto `base` at the given opacity level.
+    pub fn overlay(&self, opacity: Opacity, base: Color, overlay: Color) -> Color {
+        crate::color::mix(
+            base,
+            Color {
+                a: base.a,
+                ..overlay
+            },
+            self.opacity(opacity),
+        )
+    }
+
+    /// Scales the alpha of `color` by the given opacity level.
+    pub fn apply(&self, opacity: Opacity, color: Color) -> Color {
+        Color {
+            a: color.a * self.opacity(opacity),
+            ..color
+        }
+    }
+}
+
+impl Default for TokenTable {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Opacity {
+    /// Blends `overlay` onto `base` at this opacity level of [`TokenTable::DEFAULT`], e.g. a
+    /// hover highlight.
+    pub fn overlay(self, base: Color, overlay: Color) -> Color {
+        TokenTable::DEFAULT.overlay(self, base, overlay)
+    }
+
+    /// Scales the alpha of `color` by this opacity level of [`TokenTable::DEFAULT`].
+    pub fn apply(self, color: Color) -> Color {
+        TokenTable::DEFAULT.apply(self, color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets::Preset;
+    use crate::style_set::StyleSet;
+    use crate::styles::container::CustomContainerStyle;
+    use crate::tonal::CorePalette;
+
+    #[test]
+    fn setters_resolve_tokens_against_the_style_table() {
+        let style = CustomContainerStyle::new()
+            .border_radius(Radius::Lg)
+            .border_width(BorderWidth::Thick);
+        assert_eq!(<[f32; 4]>::from(style.appearance.border_radius), [12.0; 4]);
+        assert_eq!(style.appearance.border_width, 3.0);
+
+        let table = TokenTable {
+            radius: [0.0, 1.0, 2.0, 3.0, 4.0],
+            ..TokenTable::DEFAULT
+        };
+        let style = CustomContainerStyle::new()
+            .tokens(&table)
+            .border_radius(Radius::Lg)
+            .border_width(2.5);
+        assert_eq!(<[f32; 4]>::from(style.appearance.border_radius), [3.0; 4]);
+        assert_eq!(style.appearance.border_width, 2.5);
+    }
+
+    #[test]
+    fn swapping_the_table_restyles_presets() {
+        let table = TokenTable {
+            radius: [20.0; 5],
+            border_width: [5.0; 3],
+            ..TokenTable::DEFAULT
+        };
+        let radius = |radius: BorderRadius| <[f32; 4]>::from(radius);
+        for preset in Preset::ALL {
+            let styles = preset.styles_with(&table);
+            for (name, button) in styles.buttons() {
+                assert_eq!(
+                    radius(button.active.border_radius),
+                    [20.0; 4],
+                    "{preset} {name}"
+                );
+            }
+            assert_eq!(
+                radius(styles.box_container.appearance.border_radius),
+                [20.0; 4]
+            );
+            assert_eq!(radius(styles.text_input.focused.border_radius), [20.0; 4]);
+            assert_eq!(styles.text_input.active.border_width, 5.0, "{preset}");
+            // Gruvbox keeps its scrollers square.
+            let scroller =
+                |styles: &StyleSet| radius(styles.scrollable.dragging.scroller.border_radius);
+            if scroller(&preset.styles()) != [0.0; 4] {
+                assert_eq!(scroller(&styles), [20.0; 4], "{preset}");
+            }
+        }
+        let styles = CorePalette::from_seed(Color::from_rgb(0.4, 0.3, 0.8))
+            .light()
+            .styles_with(&table);
+        assert_eq!(
+            radius(styles.primary_button.hovered.border_radius),
+            [20.0; 4]
+        );
+    }
+
+    #[test]
+    fn custom_tables_are_passed_explicitly() {
+        let table = TokenTable {
+            opacity: [0.5, 0.5, 0.25],
+            ..TokenTable::DEFAULT
+        };
+        assert_eq!(table.apply(Opacity::Disabled, Color::BLACK).a, 0.25);
+        assert_eq!(Opacity::Disabled.apply(Color::BLACK).a, 0.38);
+    }
+}
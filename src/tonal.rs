@@ -0,0 +1,404 @@
+//! Tonal palettes generated from a single seed color, in the manner of Material 3.
+//!
+//! Hue and chroma come from OKLCH. Tones are CIELAB lightness, so a difference of 40 tones
+//! gives at least 3:1 contrast and 50 tones at least 4.5:1, whatever the hue.
+
+use crate::color::{from_oklch, oklch_in_gamut, relative_luminance, to_oklch};
+use crate::style_set::StyleSet;
+use crate::styles::button::CustomButtonStyle;
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::scrollable::CustomScrollableStyle;
+#[cfg(feature = "svg")]
+use crate::styles::svg::CustomSvgStyle;
+use crate::styles::text::CustomTextStyle;
+use crate::styles::text_input::CustomTextInputStyle;
+use crate::tokens::{BorderWidth, Opacity, Radius, TokenTable};
+use iced::theme::Palette;
+use iced::Color;
+
+/// The tones Material 3 publishes for every palette.
+pub const TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+/// One hue at a fixed chroma, from black at tone 0 to white at tone 100.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TonalPalette {
+    pub hue: f32,
+    pub chroma: f32,
+}
+
+impl TonalPalette {
+    pub const fn new(hue: f32, chroma: f32) -> Self {
+        Self { hue, chroma }
+    }
+
+    /// The color at `tone`, from 0 to 100. Chroma is reduced where sRGB cannot reach it.
+    pub fn tone(&self, tone: u8) -> Color {
+        let target = lstar_to_luminance(f32::from(tone.min(100)));
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..24 {
+            let lightness = (low + high) / 2.0;
+            if relative_luminance(self.in_gamut(lightness)) < target {
+                low = lightness;
+            } else {
+                high = lightness;
+            }
+        }
+        self.in_gamut((low + high) / 2.0)
+    }
+
+    fn in_gamut(&self, lightness: f32) -> Color {
+        if oklch_in_gamut([lightness, self.chroma, self.hue]) {
+            return from_oklch([lightness, self.chroma, self.hue], 1.0);
+        }
+        let (mut low, mut high) = (0.0, self.chroma);
+        for _ in 0..16 {
+            let chroma = (low + high) / 2.0;
+            if oklch_in_gamut([lightness, chroma, self.hue]) {
+                low = chroma;
+            } else {
+                high = chroma;
+            }
+        }
+        from_oklch([lightness, low, self.hue], 1.0)
+    }
+}
+
+fn lstar_to_luminance(lstar: f32) -> f32 {
+    if lstar > 8.0 {
+        ((lstar + 16.0) / 116.0).powi(3)
+    } else {
+        lstar / 903.2963
+    }
+}
+
+/// The key palettes of a scheme. `success` has no Material 3 counterpart, iced palettes need
+/// it for positive buttons.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorePalette {
+    pub primary: TonalPalette,
+    pub secondary: TonalPalette,
+    pub tertiary: TonalPalette,
+    pub neutral: TonalPalette,
+    pub neutral_variant: TonalPalette,
+    pub error: TonalPalette,
+    pub success: TonalPalette,
+}
+
+impl CorePalette {
+    /// Keeps the seed's hue and lifts its chroma to stay colorful, then derives the other
+    /// palettes at lower chroma, with tertiary rotated by 60 degrees.
+    pub fn from_seed(seed: Color) -> Self {
+        let [_, chroma, hue] = to_oklch(seed);
+        let chroma = chroma.max(0.12);
+        Self {
+            primary: TonalPalette::new(hue, chroma),
+            secondary: TonalPalette::new(hue, chroma / 3.0),
+            tertiary: TonalPalette::new((hue + 60.0) % 360.0, chroma / 2.0),
+            neutral: TonalPalette::new(hue, 0.01),
+            neutral_variant: TonalPalette::new(hue, 0.02),
+            error: TonalPalette::new(27.0, 0.19),
+            success: TonalPalette::new(145.0, chroma.min(0.15)),
+        }
+    }
+
+    pub fn light(&self) -> Roles {
+        Roles {
+            primary: self.primary.tone(40),
+            on_primary: self.primary.tone(100),
+            primary_container: self.primary.tone(90),
+            on_primary_container: self.primary.tone(10),
+            secondary: self.secondary.tone(40),
+            on_secondary: self.secondary.tone(100),
+            secondary_container: self.secondary.tone(90),
+            on_secondary_container: self.secondary.tone(10),
+            tertiary: self.tertiary.tone(40),
+            on_tertiary: self.tertiary.tone(100),
+            tertiary_container: self.tertiary.tone(90),
+            on_tertiary_container: self.tertiary.tone(10),
+            error: self.error.tone(40),
+            on_error: self.error.tone(100),
+            success: self.success.tone(40),
+            on_success: self.success.tone(100),
+            surface: self.neutral.tone(99),
+            on_surface: self.neutral.tone(10),
+            surface_container: self.neutral.tone(95),
+            surface_variant: self.neutral_variant.tone(90),
+            on_surface_variant: self.neutral_variant.tone(30),
+            outline: self.neutral_variant.tone(50),
+            outline_variant: self.neutral_variant.tone(80),
+        }
+    }
+
+    pub fn dark(&self) -> Roles {
+        Roles {
+            primary: self.primary.tone(80),
+            on_primary: self.primary.tone(20),
+            primary_container: self.primary.tone(30),
+            on_primary_container: self.primary.tone(90),
+            secondary: self.secondary.tone(80),
+            on_secondary: self.secondary.tone(20),
+            secondary_container: self.secondary.tone(30),
+            on_secondary_container: self.secondary.tone(90),
+            tertiary: self.tertiary.tone(80),
+            on_tertiary: self.tertiary.tone(20),
+            tertiary_container: self.tertiary.tone(30),
+            on_tertiary_container: self.tertiary.tone(90),
+            error: self.error.tone(80),
+            on_error: self.error.tone(20),
+            success: self.success.tone(80),
+            on_success: self.success.tone(20),
+            surface: self.neutral.tone(10),
+            on_surface: self.neutral.tone(90),
+            surface_container: self.neutral.tone(20),
+            surface_variant: self.neutral_variant.tone(30),
+            on_surface_variant: self.neutral_variant.tone(80),
+            outline: self.neutral_variant.tone(60),
+            outline_variant: self.neutral_variant.tone(30),
+        }
+    }
+}
+
+/// Colors assigned to their roles for a light or dark scheme.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Roles {
+    pub primary: Color,
+    pub on_primary: Color,
+    pub primary_container: Color,
+    pub on_primary_container: Color,
+    pub secondary: Color,
+    pub on_secondary: Color,
+    pub secondary_container: Color,
+    pub on_secondary_container: Color,
+    pub tertiary: Color,
+    pub on_tertiary: Color,
+    pub tertiary_container: Color,
+    pub on_tertiary_container: Color,
+    pub error: Color,
+    pub on_error: Color,
+    pub success: Color,
+    pub on_success: Color,
+    pub surface: Color,
+    pub on_surface: Color,
+    pub surface_container: Color,
+    pub surface_variant: Color,
+    pub on_surface_variant: Color,
+    pub outline: Color,
+    pub outline_variant: Color,
+}
+
+impl Roles {
+    pub fn palette(&self) -> Palette {
+        Palette {
+            background: self.surface,
+            text: self.on_surface,
+            primary: self.primary,
+            success: self.success,
+            danger: self.error,
+        }
+    }
+
+    /// Filled buttons for primary, positive and destructive, a tonal button for secondary and
+    /// outlined text fields, with Material's state layer opacities from the design tokens.
+    pub fn styles(&self) -> StyleSet {
+        self.styles_with(&TokenTable::DEFAULT)
+    }
+
+    /// [`Self::styles`] with radii, widths and opacities resolved against `tokens`.
+    pub fn styles_with(&self, tokens: &TokenTable) -> StyleSet {
+        let disabled_container = tokens.apply(Opacity::Pressed, self.on_surface);
+        let disabled_content = tokens.apply(Opacity::Disabled, self.on_surface);
+
+        let button = CustomButtonStyle::new()
+            .tokens(tokens)
+            .all()
+            .border_radius(Radius::Xl);
+        let filled = |container: Color, content: Color| {
+            button
+                .all()
+                .background_color(container)
+                .text_color(content)
+                .hovered()
+                .background_color(tokens.overlay(Opacity::Hover, container, content))
+                .pressed()
+                .background_color(tokens.overlay(Opacity::Pressed, container, content))
+                .disabled()
+                .background_color(disabled_container)
+                .text_color(disabled_content)
+                .active()
+        };
+
+        let mut styles = StyleSet::from_palette(self.palette());
+        styles.primary_button = filled(self.primary, self.on_primary);
+        styles.secondary_button = filled(self.secondary_container, self.on_secondary_container);
+        styles.positive_button = filled(self.success, self.on_success);
+        styles.destructive_button = filled(self.error, self.on_error);
+        styles.text_button = button
+            .all()
+            .text_color(self.primary)
+            .hovered()
+            .background_color(tokens.apply(Opacity::Hover, self.primary))
+            .pressed()
+            .background_color(tokens.apply(Opacity::Pressed, self.primary))
+            .disabled()
+            .text_color(disabled_content)
+            .active();
+
+        styles.box_container = CustomContainerStyle::new()
+            .tokens(tokens)
+            .background_color(self.surface_container)
+            .text_color(Some(self.on_surface))
+            .border_radius(Radius::Lg);
+        styles.transparent_container = CustomContainerStyle::new()
+            .tokens(tokens)
+            .text_color(Some(self.on_surface));
+
+        styles.scrollable = CustomScrollableStyle::new()
+            .tokens(tokens)
+            .all()
+            .scroller_border_radius(Radius::Sm)
+            .scroller_color(self.outline_variant)
+            .hovered()
+            .scroller_color(self.outline)
+            .hovered_horizontal()
+            .scroller_color(self.outline)
+            .hovered_over_scrollbar()
+            .scroller_color(self.on_surface_variant)
+            .hovered_horizontal_over_scrollbar()
+            .scroller_color(self.on_surface_variant)
+            .dragging()
+            .scroller_color(self.primary)
+            .dragging_horizontal()
+            .scroller_color(self.primary)
+            .active();
+
+        styles.text_input = CustomTextInputStyle::new()
+            .tokens(tokens)
+            .all()
+            .background_color(self.surface)
+            .border_color(self.outline)
+            .border_width(BorderWidth::Hairline)
+            .border_radius(Radius::Xs)
+            .icon_color(self.on_surface_variant)
+            .hovered()
+            .border_color(self.on_surface)
+            .focused()
+            .border_color(self.primary)
+            .border_width(BorderWidth::Thin)
+            .disabled()
+            .border_color(disabled_container)
+            .icon_color(disabled_content)
+            .active()
+            .placeholder_color(self.on_surface_variant)
+            .value_color(self.on_surface)
+            .disabled_color(disabled_content)
+            .selection_color(Color {
+                a: 0.4,
+                ..self.primary
+            });
+
+        styles.text = CustomTextStyle::new().color(self.on_surface);
+        #[cfg(feature = "svg")]
+        {
+            styles.svg = CustomSvgStyle::new().color(Some(self.on_surface_variant));
+        }
+        styles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::contrast_ratio;
+    use crate::export::format_color;
+
+    const SEED: Color = Color::from_rgb(
+        0x67 as f32 / 255.0,
+        0x50 as f32 / 255.0,
+        0xa4 as f32 / 255.0,
+    );
+
+    fn palettes() -> Vec<TonalPalette> {
+        let core = CorePalette::from_seed(SEED);
+        let mut palettes = vec![
+            core.primary,
+            core.secondary,
+            core.tertiary,
+            core.neutral,
+            core.error,
+            core.success,
+        ];
+        // Hues where sRGB runs out of chroma first, at the light and dark ends.
+        palettes.extend([60.0, 110.0, 200.0, 265.0].map(|hue| TonalPalette::new(hue, 0.3)));
+        palettes
+    }
+
+    fn lstar(color: Color) -> f32 {
+        let luminance = relative_luminance(color);
+        if luminance > 216.0 / 24389.0 {
+            116.0 * luminance.cbrt() - 16.0
+        } else {
+            luminance * 24389.0 / 27.0
+        }
+    }
+
+    #[test]
+    fn tones_are_cielab_lightness() {
+        for palette in palettes() {
+            for tone in TONES {
+                let color = palette.tone(tone);
+                assert!(
+                    (lstar(color) - f32::from(tone)).abs() < 0.5,
+                    "{palette:?} tone {tone} is L* {}",
+                    lstar(color)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tone_differences_guarantee_contrast() {
+        for palette in palettes() {
+            for (dark, light, minimum) in [(40, 100, 4.5), (10, 90, 4.5), (50, 90, 3.0)] {
+                let ratio = contrast_ratio(palette.tone(dark), palette.tone(light));
+                assert!(
+                    ratio >= minimum,
+                    "{palette:?} tones {dark} and {light} reach only {ratio}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn roles_are_stable_for_a_seed() {
+        let core = CorePalette::from_seed(SEED);
+        let roles = |roles: Roles| {
+            [
+                roles.primary,
+                roles.on_primary,
+                roles.primary_container,
+                roles.on_primary_container,
+                roles.surface,
+                roles.on_surface,
+                roles.error,
+                roles.success,
+            ]
+            .map(format_color)
+        };
+
+        // The seed is Material's baseline primary, which sits at tone 40.
+        assert_eq!(
+            roles(core.light()),
+            [
+                "#6750a4", "#ffffff", "#e4dfff", "#270255", "#fcfcff", "#1c1b20", "#b91c1e",
+                "#1c6d26"
+            ]
+        );
+        assert_eq!(
+            roles(core.dark()),
+            [
+                "#cbbeff", "#3a1e6f", "#503789", "#e4dfff", "#1c1b20", "#e3e2e9", "#ffb4aa",
+                "#8ad88d"
+            ]
+        );
+    }
+}
@@ -0,0 +1,497 @@
+//! Compact utility class strings in the manner of Tailwind CSS, e.g.
+//! `"bg-blue-500 hover:bg-blue-600 text-white rounded-lg border-2 disabled:opacity-50"`.
+//!
+//! Colors come from Tailwind's default palette and take an optional `/alpha` in percent, radii
+//! and border widths follow its default scales at 16px to the rem. Arbitrary colors go in
+//! brackets with underscores for spaces, e.g. `bg-[rgb(30_30_46)]`. As in Tailwind the order of
+//! classes does not matter: utilities without a variant are applied first, to every state.
+
+use crate::color::rgb;
+use crate::css_color;
+use crate::diagnostic::{Diagnostic, Span};
+use crate::styles::button::{ButtonState, CustomButtonStyle};
+use crate::styles::container::CustomContainerStyle;
+use crate::styles::text_input::{CustomTextInputStyle, TextInputState};
+use crate::visit::map_background;
+use iced::Color;
+
+/// Parses utilities for a button on top of [`CustomButtonStyle::new`]. Supports the `hover:`,
+/// `active:` and `disabled:` variants.
+pub fn button(classes: &str) -> Result<CustomButtonStyle, Diagnostic> {
+    let mut style = CustomButtonStyle::new();
+    let mut opacity = [None; 4];
+
+    for utility in parse(classes)? {
+        style.current_state = match utility.variant {
+            None => ButtonState::all(),
+            Some("hover") => ButtonState::HOVERED,
+            Some("active") => ButtonState::PRESSED,
+            Some("disabled") => ButtonState::DISABLED,
+            Some(variant) => return Err(utility.unsupported_variant(variant, "buttons")),
+        };
+        style = match utility.property {
+            Property::Background(color) => style.background_color(color),
+            Property::Text(color) => style.text_color(color),
+            Property::BorderWidth(width) => style.border_width(width),
+            Property::BorderColor(color) => style.border_color(color),
+            Property::Radius(radius) => style.border_radius(radius),
+            Property::Opacity(value) => {
+                select(&mut opacity, style.current_state.bits(), value);
+                style
+            }
+            Property::Fill(_) => return Err(utility.unsupported("buttons")),
+        };
+    }
+
+    for (appearance, opacity) in [
+        &mut style.active,
+        &mut style.hovered,
+        &mut style.pressed,
+        &mut style.disabled,
+    ]
+    .into_iter()
+    .zip(opacity)
+    {
+        if let Some(opacity) = opacity {
+            let mut fade = |color| fade(color, opacity);
+            appearance.background = appearance
+                .background
+                .map(|background| map_background(background, &mut fade));
+            appearance.text_color = fade(appearance.text_color);
+            appearance.border_color = fade(appearance.border_color);
+        }
+    }
+    Ok(style.active())
+}
+
+/// Parses utilities for a container on top of [`CustomContainerStyle::new`]. Containers have
+/// no states, so variants are rejected.
+pub fn container(classes: &str) -> Result<CustomContainerStyle, Diagnostic> {
+    let mut style = CustomContainerStyle::new();
+    let mut opacity = None;
+
+    for utility in parse(classes)? {
+        if let Some(variant) = utility.variant {
+            return Err(utility.unsupported_variant(variant, "containers"));
+        }
+        style = match utility.property {
+            Property::Background(color) => style.background_color(color),
+            Property::Text(color) => style.text_color(Some(color)),
+            Property::BorderWidth(width) => style.border_width(width),
+            Property::BorderColor(color) => style.border_color(color),
+            Property::Radius(radius) => style.border_radius(radius),
+            Property::Opacity(value) => {
+                opacity = Some(value);
+                style
+            }
+            Property::Fill(_) => return Err(utility.unsupported("containers")),
+        };
+    }
+
+    Ok(match opacity {
+        Some(opacity) => style.map_colors(|color| fade(color, opacity)),
+        None => style,
+    })
+}
+
+/// Parses utilities for a text input on top of [`CustomTextInputStyle::new`]. Supports the
+/// `hover:`, `focus:` and `disabled:` variants as well as `placeholder:` and `selection:`.
+/// `text-*` sets the value color, or the disabled color under `disabled:`, and `fill-*` the
+/// icon color.
+pub fn text_input(classes: &str) -> Result<CustomTextInputStyle, Diagnostic> {
+    let mut style = CustomTextInputStyle::new();
+    let mut opacity = [None; 4];
+
+    for utility in parse(classes)? {
+        let state = match utility.variant {
+            None => TextInputState::all(),
+            Some("hover") => TextInputState::HOVERED,
+            Some("focus") => TextInputState::FOCUSED,
+            Some("disabled") => TextInputState::DISABLED,
+            Some("placeholder") => {
+                style = match utility.property {
+                    Property::Text(color) => style.placeholder_color(color),
+                    _ => return Err(utility.unsupported("placeholders")),
+                };
+                continue;
+            }
+            Some("selection") => {
+                style = match utility.property {
+                    Property::Background(color) => style.selection_color(color),
+                    _ => return Err(utility.unsupported("selections")),
+                };
+                continue;
+            }
+            Some(variant) => return Err(utility.unsupported_variant(variant, "text inputs")),
+        };
+        style.current_state = state;
+        style = match utility.property {
+            Property::Background(color) => style.background_color(color),
+            Property::Text(color) if state == TextInputState::all() => style.value_color(color),
+            Property::Text(color) if state == TextInputState::DISABLED => {
+                style.disabled_color(color)
+            }
+            Property::Text(_) => {
+                return Err(Diagnostic::new(
+                    "the text color of text inputs only changes when disabled",
+                    utility.span,
+                ))
+            }
+            Property::Fill(color) => style.icon_color(color),
+            Property::BorderWidth(width) => style.border_width(width),
+            Property::BorderColor(color) => style.border_color(color),
+            Property::Radius(radius) => style.border_radius(radius),
+            Property::Opacity(value) => {
+                select(&mut opacity, state.bits(), value);
+                style
+            }
+        };
+    }
+
+    let [active, _, disabled, _] = opacity;
+    for (appearance, opacity) in [
+        &mut style.active,
+        &mut style.hovered,
+        &mut style.disabled,
+        &mut style.focused,
+    ]
+    .into_iter()
+    .zip(opacity)
+    {
+        if let Some(opacity) = opacity {
+            let mut fade = |color| fade(color, opacity);
+            appearance.background = map_background(appearance.background, &mut fade);
+            appearance.border_color = fade(appearance.border_color);
+            appearance.icon_color = fade(appearance.icon_color);
+        }
+    }
+    // The text colors are shared between states, they follow the state they are shown in.
+    if let Some(opacity) = active {
+        style.value_color = fade(style.value_color, opacity);
+        style.placeholder_color = fade(style.placeholder_color, opacity);
+        style.selection_color = fade(style.selection_color, opacity);
+    }
+    if let Some(opacity) = disabled {
+        style.disabled_color = fade(style.disabled_color, opacity);
+    }
+    Ok(style.active())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Property {
+    Background(Color),
+    Text(Color),
+    Fill(Color),
+    BorderWidth(f32),
+    BorderColor(Color),
+    Radius(f32),
+    Opacity(f32),
+}
+
+struct Utility<'a> {
+    variant: Option<&'a str>,
+    property: Property,
+    name: &'a str,
+    span: Span,
+}
+
+impl Utility<'_> {
+    fn unsupported(&self, on: &str) -> Diagnostic {
+        Diagnostic::new(
+            format!("`{}` is not supported on {on}", self.name),
+            self.span,
+        )
+    }
+
+    fn unsupported_variant(&self, variant: &str, on: &str) -> Diagnostic {
+        Diagnostic::new(
+            format!("the `{variant}:` variant is not supported on {on}"),
+            self.span,
+        )
+    }
+}
+
+/// Splits `classes` at whitespace, with utilities that have a variant sorted last.
+fn parse(classes: &str) -> Result<Vec<Utility<'_>>, Diagnostic> {
+    let mut utilities = Vec::new();
+    let mut start = 0;
+    for class in classes.split(|c: char| c.is_ascii_whitespace()) {
+        let span = Span::new(start, start + class.len());
+        start += class.len() + 1;
+        if class.is_empty() {
+            continue;
+        }
+
+        let (variant, name) = match class.split_once(':') {
+            Some((variant, name)) => (Some(variant), name),
+            None => (None, class),
+        };
+        if name.contains(':') {
+            return Err(Diagnostic::new(
+                format!("stacked variants are not supported in `{class}`"),
+                span,
+            ));
+        }
+        let property = parse_property(name)
+            .ok_or_else(|| Diagnostic::new(format!("unknown utility `{name}`"), span))?;
+        utilities.push(Utility {
+            variant,
+            property,
+            name,
+            span,
+        });
+    }
+    utilities.sort_by_key(|utility| utility.variant.is_some());
+    Ok(utilities)
+}
+
+fn parse_property(name: &str) -> Option<Property> {
+    if let Some(color) = name.strip_prefix("bg-") {
+        return parse_color(color).map(Property::Background);
+    }
+    if let Some(color) = name.strip_prefix("text-") {
+        return parse_color(color).map(Property::Text);
+    }
+    if let Some(color) = name.strip_prefix("fill-") {
+        return parse_color(color).map(Property::Fill);
+    }
+    if let Some(percent) = name.strip_prefix("opacity-") {
+        return parse_percent(percent).map(Property::Opacity);
+    }
+    match name {
+        "border" => return Some(Property::BorderWidth(1.0)),
+        "rounded" => return Some(Property::Radius(4.0)),
+        _ => {}
+    }
+    if let Some(value) = name.strip_prefix("border-") {
+        return match value {
+            "0" => Some(Property::BorderWidth(0.0)),
+            "2" => Some(Property::BorderWidth(2.0)),
+            "4" => Some(Property::BorderWidth(4.0)),
+            "8" => Some(Property::BorderWidth(8.0)),
+            color => parse_color(color).map(Property::BorderColor),
+        };
+    }
+    let radius = match name.strip_prefix("rounded-")? {
+        "none" => 0.0,
+        "sm" => 2.0,
+        "md" => 6.0,
+        "lg" => 8.0,
+        "xl" => 12.0,
+        "2xl" => 16.0,
+        "3xl" => 24.0,
+        "full" => 9999.0,
+        _ => return None,
+    };
+    Some(Property::Radius(radius))
+}
+
+/// Parses `black`, `white`, `transparent` or a `family-shade` from the palette, optionally
+/// followed by `/alpha`, or any CSS color in brackets.
+fn parse_color(value: &str) -> Option<Color> {
+    if let Some(arbitrary) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        return css_color::parse(&arbitrary.replace('_', " ")).ok();
+    }
+    let (name, alpha) = match value.split_once('/') {
+        Some((name, alpha)) => (name, parse_percent(alpha)?),
+        None => (value, 1.0),
+    };
+    let color = match name {
+        "black" => Color::BLACK,
+        "white" => Color::WHITE,
+        "transparent" => Color::TRANSPARENT,
+        _ => {
+            let (family, shade) = name.rsplit_once('-')?;
+            let shade = SHADES.iter().position(|s| s.to_string() == shade)?;
+            let (_, shades) = COLORS.iter().find(|(name, _)| *name == family)?;
+            rgb(shades[shade])
+        }
+    };
+    Some(fade(color, alpha))
+}
+
+/// Parses a whole percentage from 0 to 100 into a fraction.
+fn parse_percent(value: &str) -> Option<f32> {
+    let percent: u8 = value.parse().ok().filter(|percent| *percent <= 100)?;
+    Some(f32::from(percent) / 100.0)
+}
+
+/// Sets the opacity of every state whose flag is in `states`, the flags being in the order of
+/// `opacity`.
+fn select(opacity: &mut [Option<f32>; 4], states: u8, value: f32) {
+    for (index, opacity) in opacity.iter_mut().enumerate() {
+        if states & (1 << index) != 0 {
+            *opacity = Some(value);
+        }
+    }
+}
+
+fn fade(color: Color, opacity: f32) -> Color {
+    Color {
+        a: color.a * opacity,
+        ..color
+    }
+}
+
+const SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// Tailwind CSS v3's default color palette.
+#[rustfmt::skip]
+const COLORS: [(&str, [u32; 11]); 22] = [
+    ("slate", [0xf8fafc, 0xf1f5f9, 0xe2e8f0, 0xcbd5e1, 0x94a3b8, 0x64748b, 0x475569, 0x334155, 0x1e293b, 0x0f172a, 0x020617]),
+    ("gray", [0xf9fafb, 0xf3f4f6, 0xe5e7eb, 0xd1d5db, 0x9ca3af, 0x6b7280, 0x4b5563, 0x374151, 0x1f2937, 0x111827, 0x030712]),
+    ("zinc", [0xfafafa, 0xf4f4f5, 0xe4e4e7, 0xd4d4d8, 0xa1a1aa, 0x71717a, 0x52525b, 0x3f3f46, 0x27272a, 0x18181b, 0x09090b]),
+    ("neutral", [0xfafafa, 0xf5f5f5, 0xe5e5e5, 0xd4d4d4, 0xa3a3a3, 0x737373, 0x525252, 0x404040, 0x262626, 0x171717, 0x0a0a0a]),
+    ("stone", [0xfafaf9, 0xf5f5f4, 0xe7e5e4, 0xd6d3d1, 0xa8a29e, 0x78716c, 0x57534e, 0x44403c, 0x292524, 0x1c1917, 0x0c0a09]),
+    ("red", [0xfef2f2, 0xfee2e2, 0xfecaca, 0xfca5a5, 0xf87171, 0xef4444, 0xdc2626, 0xb91c1c, 0x991b1b, 0x7f1d1d, 0x450a0a]),
+    ("orange", [0xfff7ed, 0xffedd5, 0xfed7aa, 0xfdba74, 0xfb923c, 0xf97316, 0xea580c, 0xc2410c, 0x9a3412, 0x7c2d12, 0x431407]),
+    ("amber", [0xfffbeb, 0xfef3c7, 0xfde68a, 0xfcd34d, 0xfbbf24, 0xf59e0b, 0xd97706, 0xb45309, 0x92400e, 0x78350f, 0x451a03]),
+    ("yellow", [0xfefce8, 0xfef9c3, 0xfef08a, 0xfde047, 0xfacc15, 0xeab308, 0xca8a04, 0xa16207, 0x854d0e, 0x713f12, 0x422006]),
+    ("lime", [0xf7fee7, 0xecfccb, 0xd9f99d, 0xbef264, 0xa3e635, 0x84cc16, 0x65a30d, 0x4d7c0f, 0x3f6212, 0x365314, 0x1a2e05]),
+    ("green", [0xf0fdf4, 0xdcfce7, 0xbbf7d0, 0x86efac, 0x4ade80, 0x22c55e, 0x16a34a, 0x15803d, 0x166534, 0x14532d, 0x052e16]),
+    ("emerald", [0xecfdf5, 0xd1fae5, 0xa7f3d0, 0x6ee7b7, 0x34d399, 0x10b981, 0x059669, 0x047857, 0x065f46, 0x064e3b, 0x022c22]),
+    ("teal", [0xf0fdfa, 0xccfbf1, 0x99f6e4, 0x5eead4, 0x2dd4bf, 0x14b8a6, 0x0d9488, 0x0f766e, 0x115e59, 0x134e4a, 0x042f2e]),
+    ("cyan", [0xecfeff, 0xcffafe, 0xa5f3fc, 0x67e8f9, 0x22d3ee, 0x06b6d4, 0x0891b2, 0x0e7490, 0x155e75, 0x164e63, 0x083344]),
+    ("sky", [0xf0f9ff, 0xe0f2fe, 0xbae6fd, 0x7dd3fc, 0x38bdf8, 0x0ea5e9, 0x0284c7, 0x0369a1, 0x075985, 0x0c4a6e, 0x082f49]),
+    ("blue", [0xeff6ff, 0xdbeafe, 0xbfdbfe, 0x93c5fd, 0x60a5fa, 0x3b82f6, 0x2563eb, 0x1d4ed8, 0x1e40af, 0x1e3a8a, 0x172554]),
+    ("indigo", [0xeef2ff, 0xe0e7ff, 0xc7d2fe, 0xa5b4fc, 0x818cf8, 0x6366f1, 0x4f46e5, 0x4338ca, 0x3730a3, 0x312e81, 0x1e1b4b]),
+    ("violet", [0xf5f3ff, 0xede9fe, 0xddd6fe, 0xc4b5fd, 0xa78bfa, 0x8b5cf6, 0x7c3aed, 0x6d28d9, 0x5b21b6, 0x4c1d95, 0x2e1065]),
+    ("purple", [0xfaf5ff, 0xf3e8ff, 0xe9d5ff, 0xd8b4fe, 0xc084fc, 0xa855f7, 0x9333ea, 0x7e22ce, 0x6b21a8, 0x581c87, 0x3b0764]),
+    ("fuchsia", [0xfdf4ff, 0xfae8ff, 0xf5d0fe, 0xf0abfc, 0xe879f9, 0xd946ef, 0xc026d3, 0xa21caf, 0x86198f, 0x701a75, 0x4a044e]),
+    ("pink", [0xfdf2f8, 0xfce7f3, 0xfbcfe8, 0xf9a8d4, 0xf472b6, 0xec4899, 0xdb2777, 0xbe185d, 0x9d174d, 0x831843, 0x500724]),
+    ("rose", [0xfff1f2, 0xffe4e6, 0xfecdd3, 0xfda4af, 0xfb7185, 0xf43f5e, 0xe11d48, 0xbe123c, 0x9f1239, 0x881337, 0x4c0519]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::Background;
+
+    const BLUE_500: Color = rgb(0x3b82f6);
+    const BLUE_600: Color = rgb(0x2563eb);
+
+    #[test]
+    fn variants_apply_after_the_base_utilities() {
+        let style = button("hover:bg-blue-600 bg-blue-500 text-white rounded-lg border-2").unwrap();
+
+        assert_eq!(style.active.background, Some(Background::Color(BLUE_500)));
+        assert_eq!(style.pressed.background, Some(Background::Color(BLUE_500)));
+        assert_eq!(style.hovered.background, Some(Background::Color(BLUE_600)));
+        assert_eq!(style.hovered.text_color, Color::WHITE);
+        assert_eq!(<[f32; 4]>::from(style.disabled.border_radius), [8.0; 4]);
+        assert_eq!(style.disabled.border_width, 2.0);
+        assert_eq!(style.current_state, ButtonState::ACTIVE);
+    }
+
+    #[test]
+    fn colors_take_an_alpha_and_opacity_fades_a_state() {
+        let style = button("bg-blue-500/50 text-black disabled:opacity-50").unwrap();
+
+        assert_eq!(
+            style.active.background,
+            Some(Background::Color(Color { a: 0.5, ..BLUE_500 }))
+        );
+        assert_eq!(
+            style.disabled.background,
+            Some(Background::Color(Color {
+                a: 0.25,
+                ..BLUE_500
+            }))
+        );
+        assert_eq!(
+            style.disabled.text_color,
+            Color {
+                a: 0.5,
+                ..Color::BLACK
+            }
+        );
+        assert_eq!(style.hovered.text_color, Color::BLACK);
+    }
+
+    #[test]
+    fn text_input_variants_reach_their_state() {
+        let style = text_input(
+            "border border-slate-300 focus:border-blue-500 disabled:text-slate-400 \
+             placeholder:text-slate-500 selection:bg-[rgb(30_30_46)] fill-white \
+             disabled:opacity-40",
+        )
+        .unwrap();
+
+        assert_eq!(style.active.border_width, 1.0);
+        assert_eq!(style.active.border_color, rgb(0xcbd5e1));
+        assert_eq!(style.focused.border_color, BLUE_500);
+        assert_eq!(style.hovered.border_color, rgb(0xcbd5e1));
+        assert_eq!(
+            style.disabled_color,
+            Color {
+                a: 0.4,
+                ..rgb(0x94a3b8)
+            }
+        );
+        assert_eq!(style.placeholder_color, rgb(0x64748b));
+        assert_eq!(style.selection_color, rgb(0x1e1e2e));
+        assert_eq!(style.focused.icon_color, Color::WHITE);
+        assert_eq!(
+            style.disabled.border_color,
+            Color {
+                a: 0.4,
+                ..rgb(0xcbd5e1)
+            }
+        );
+    }
+
+    #[test]
+    fn containers_fade_every_color() {
+        let style = container("bg-white border border-black opacity-50").unwrap();
+
+        assert_eq!(
+            style.appearance.background,
+            Some(Background::Color(Color {
+                a: 0.5,
+                ..Color::WHITE
+            }))
+        );
+        assert_eq!(
+            style.appearance.border_color,
+            Color {
+                a: 0.5,
+                ..Color::BLACK
+            }
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_class() {
+        let error = |classes: &str| button(classes).unwrap_err();
+
+        let unknown = error("bg-blue-500 bg-blue-550");
+        assert_eq!(unknown.message, "unknown utility `bg-blue-550`");
+        assert_eq!(unknown.span, Span::new(12, 23));
+
+        let alpha = error("text-red-500/101");
+        assert_eq!(alpha.message, "unknown utility `text-red-500/101`");
+
+        let variant = error("rounded focus:bg-white");
+        assert_eq!(
+            variant.message,
+            "the `focus:` variant is not supported on buttons"
+        );
+        assert_eq!(variant.span, Span::new(8, 22));
+
+        let stacked = error("md:hover:bg-white");
+        assert_eq!(
+            stacked.message,
+            "stacked variants are not supported in `md:hover:bg-white`"
+        );
+
+        let fill = error("fill-white");
+        assert_eq!(fill.message, "`fill-white` is not supported on buttons");
+
+        let disabled = text_input("hover:text-white").unwrap_err();
+        assert_eq!(
+            disabled.message,
+            "the text color of text inputs only changes when disabled"
+        );
+        assert!(container("hover:bg-white").is_err());
+    }
+}
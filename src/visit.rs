@@ -0,0 +1,162 @@
+use iced::{Background, Color};
+
+pub fn map_background(background: Background, f: &mut impl FnMut(Color) -> Color) -> Background {
+    match background {
+        Background::Color(color) => Background::Color(f(color)),
+        Background::Gradient(iced::Gradient::Linear(mut linear)) => {
+            for stop in linear.stops.iter_mut().flatten() {
+                stop.color = f(stop.color);
+            }
+            Background::Gradient(iced::Gradient::Linear(linear))
+        }
+    }
+}
+
+/// Pushes the colors of `background` under `path`, suffixing gradient stops with their index.
+pub fn background_colors(path: String, background: Background, colors: &mut Vec<(String, Color)>) {
+    match background {
+        Background::Color(color) => colors.push((path, color)),
+        Background::Gradient(iced::Gradient::Linear(linear)) => {
+            for (index, stop) in linear.stops.iter().enumerate() {
+                if let Some(stop) = stop {
+                    colors.push((format!("{path}.stops[{index}]"), stop.color));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::button::CustomButtonStyle;
+    use crate::styles::container::CustomContainerStyle;
+    use crate::styles::scrollable::CustomScrollableStyle;
+    use crate::styles::text_input::CustomTextInputStyle;
+    use iced::gradient::{ColorStop, Linear};
+
+    /// Black to white with a gap, so stop indices are not positions in the list.
+    fn gradient() -> Background {
+        let mut stops = [None; 8];
+        stops[0] = Some(ColorStop {
+            offset: 0.0,
+            color: Color::BLACK,
+        });
+        stops[2] = Some(ColorStop {
+            offset: 1.0,
+            color: Color::WHITE,
+        });
+        Background::Gradient(iced::Gradient::Linear(Linear {
+            angle: iced::Radians(0.0),
+            stops,
+        }))
+    }
+
+    /// Replaces every color with a numbered one, in visiting order.
+    fn numbering() -> impl FnMut(Color) -> Color {
+        let mut next = 0;
+        move |_| {
+            next += 1;
+            Color::from_rgb8(next, 0, 0)
+        }
+    }
+
+    /// Asserts that `colors` lists every color `map_colors` visited, each once.
+    fn assert_numbered(colors: impl Iterator<Item = (String, Color)>) {
+        let mut numbers: Vec<u8> = colors.map(|(_, color)| color.into_rgba8()[0]).collect();
+        numbers.sort_unstable();
+        let expected: Vec<u8> = (1..=numbers.len() as u8).collect();
+        assert_eq!(numbers, expected);
+    }
+
+    fn paths(colors: impl Iterator<Item = (String, Color)>) -> Vec<String> {
+        colors.map(|(path, _)| path).collect()
+    }
+
+    #[test]
+    fn gradient_stops_keep_their_index() {
+        let mut colors = Vec::new();
+        background_colors(String::from("active.background"), gradient(), &mut colors);
+
+        assert_eq!(
+            colors,
+            [
+                (String::from("active.background.stops[0]"), Color::BLACK),
+                (String::from("active.background.stops[2]"), Color::WHITE),
+            ]
+        );
+    }
+
+    #[test]
+    fn map_background_reaches_every_stop() {
+        let Background::Gradient(iced::Gradient::Linear(linear)) =
+            map_background(gradient(), &mut numbering())
+        else {
+            panic!("gradients stay gradients");
+        };
+        let stops: Vec<_> = linear.stops.iter().map(|stop| stop.is_some()).collect();
+
+        assert_eq!(stops[..3], [true, false, true]);
+        assert_eq!(linear.stops[2].unwrap().color, Color::from_rgb8(2, 0, 0));
+    }
+
+    #[test]
+    fn button_colors_are_all_visited() {
+        let style = CustomButtonStyle::new()
+            .all()
+            .background(Some(gradient()))
+            .map_colors(numbering());
+
+        assert_numbered(style.colors());
+        let paths = paths(style.colors());
+        assert!(paths.contains(&String::from("pressed.background.stops[2]")));
+        assert!(paths.contains(&String::from("disabled.text_color")));
+    }
+
+    #[test]
+    fn text_input_colors_are_all_visited() {
+        let style = CustomTextInputStyle::new()
+            .hovered()
+            .background(gradient())
+            .map_colors(numbering());
+
+        assert_numbered(style.colors());
+        let paths = paths(style.colors());
+        assert!(paths.contains(&String::from("hovered.background.stops[0]")));
+        assert!(paths.contains(&String::from("focused.icon_color")));
+        assert!(paths.contains(&String::from("selection_color")));
+    }
+
+    #[test]
+    fn scrollable_colors_are_all_visited() {
+        let style = CustomScrollableStyle::new()
+            .all()
+            .background(Some(gradient()))
+            .map_colors(numbering());
+
+        assert_numbered(style.colors());
+        let paths = paths(style.colors());
+        // Background stops, border, scroller and scroller border for each of the eight states.
+        assert_eq!(paths.len(), 8 * 5);
+        for path in [
+            "hovered.scroller.color",
+            "dragging.scroller.border_color",
+            "active_horizontal.background.stops[2]",
+            "hovered_horizontal_over_scrollbar.scroller.color",
+            "dragging_horizontal.scroller.border_color",
+        ] {
+            assert!(paths.contains(&String::from(path)), "{path}");
+        }
+    }
+
+    #[test]
+    fn container_colors_are_all_visited() {
+        let style = CustomContainerStyle::new()
+            .background(Some(gradient()))
+            .text_color(Some(Color::BLACK))
+            .map_colors(numbering());
+
+        assert_numbered(style.colors());
+        assert!(paths(style.colors()).contains(&String::from("background.stops[0]")));
+    }
+}
@@ -0,0 +1,111 @@
+//! Runs `theme-tool` on the fixtures in `tests/fixtures` and checks its exit status, which is
+//! what CI pipelines gating theme changes rely on.
+
+use iced_custom_theme_helpers::import::json;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// Runs `theme-tool <command> <fixture> <options>`.
+fn theme_tool(command: &str, name: &str, options: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_theme-tool"))
+        .arg(command)
+        .arg(fixture(name))
+        .args(options)
+        .output()
+        .expect("theme-tool runs")
+}
+
+/// The exit code of `theme-tool <command> <fixture>`.
+fn run(command: &str, fixture: &str) -> Option<i32> {
+    theme_tool(command, fixture, &[]).status.code()
+}
+
+#[test]
+fn validate_accepts_clean_files() {
+    for fixture in [
+        "gruvbox-light.css",
+        "low-contrast.css",
+        "base16-gruvbox-dark.yaml",
+        "alacritty.yml",
+        "kdeglobals",
+    ] {
+        assert_eq!(run("validate", fixture), Some(0), "{fixture}");
+    }
+}
+
+#[test]
+fn validate_rejects_unsupported_declarations() {
+    assert_eq!(run("validate", "unknown-property.css"), Some(1));
+}
+
+#[test]
+fn lint_accepts_a_readable_theme() {
+    assert_eq!(run("lint", "gruvbox-light.css"), Some(0));
+}
+
+#[test]
+fn lint_rejects_unreadable_text() {
+    assert_eq!(run("lint", "low-contrast.css"), Some(1));
+}
+
+#[test]
+fn usage_errors_exit_with_two() {
+    assert_eq!(run("check", "gruvbox-light.css"), Some(2));
+    assert_eq!(run("validate", "missing.css"), Some(2));
+}
+
+#[test]
+fn convert_writes_design_tokens() {
+    let output = theme_tool("convert", "gruvbox-light.css", &["--to", "design-tokens"]);
+    assert_eq!(output.status.code(), Some(0));
+
+    let tokens = String::from_utf8(output.stdout).expect("the tokens are UTF-8");
+    let tokens = json::parse(&tokens).expect("the tokens are JSON").value;
+    let bg = ["button", "primary", "hover", "bg"]
+        .iter()
+        .try_fold(&tokens, |json, key| json.get(key).map(|value| &value.value))
+        .expect("every button state has a background token");
+    assert_eq!(
+        bg.get("$type").and_then(|kind| kind.value.as_str()),
+        Some("color")
+    );
+}
+
+#[test]
+fn preview_writes_a_png_per_state() {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("preview");
+    let _ = std::fs::remove_dir_all(&directory);
+
+    let output = theme_tool(
+        "preview",
+        "gruvbox-light.css",
+        &[
+            "-o",
+            directory.to_str().expect("the target directory is UTF-8"),
+        ],
+    );
+    assert_eq!(output.status.code(), Some(0));
+
+    let written: Vec<_> = std::fs::read_dir(&directory)
+        .expect("preview creates the directory")
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    // Four states of five buttons, four text input states and eight scrollbars.
+    assert_eq!(written.len(), 5 * 4 + 4 + 8);
+    for name in [
+        "button-primary-hovered.png",
+        "button-destructive-disabled.png",
+        "text-input-focused.png",
+        "scrollable-dragging_horizontal.png",
+    ] {
+        assert!(written.iter().any(|file| file == name), "{name}");
+        let png = std::fs::read(directory.join(name)).unwrap();
+        assert!(png.starts_with(b"\x89PNG"), "{name}");
+    }
+}
@@ -0,0 +1,58 @@
+//! Imports the theme files in `tests/fixtures` and checks where their colors end up.
+
+use iced::{Background, Color};
+use iced_custom_theme_helpers::import::{kde, terminal};
+use std::path::Path;
+
+fn fixture(name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn kde_decorations_mark_focus_and_hover() {
+    let imported = kde::load(fixture("kdeglobals")).unwrap();
+    assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+
+    let focus = Color::from_rgb8(61, 174, 233);
+    let hover = Color::from_rgb8(147, 206, 233);
+
+    let input = imported.styles.text_input;
+    assert_eq!(input.focused.border_color, focus);
+    assert_eq!(input.hovered.border_color, hover);
+    assert_eq!(input.active.border_color, Color::from_rgb8(161, 169, 177));
+
+    let button = imported.styles.secondary_button;
+    assert_eq!(button.hovered.border_color, hover);
+    assert_eq!(button.hovered.border_width, 1.0);
+    assert_eq!(button.pressed.border_color, focus);
+    assert_eq!(
+        button.active.background,
+        Some(Background::Color(Color::from_rgb8(49, 54, 59)))
+    );
+
+    assert_eq!(imported.palette.background, Color::from_rgb8(32, 35, 38));
+    assert_eq!(imported.palette.primary, focus);
+}
+
+#[test]
+fn alacritty_toml_configs_skip_everything_but_colors() {
+    let imported = terminal::load(fixture("alacritty.toml")).unwrap();
+    assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+
+    assert_eq!(
+        imported.palette.background,
+        Color::from_rgb8(0x1a, 0x1b, 0x26)
+    );
+    assert_eq!(imported.palette.text, Color::from_rgb8(0xc0, 0xca, 0xf5));
+    assert_eq!(imported.palette.primary, Color::from_rgb8(0x7a, 0xa2, 0xf7));
+    assert_eq!(imported.palette.danger, Color::from_rgb8(0xf7, 0x76, 0x8e));
+
+    let input = imported.styles.text_input;
+    assert_eq!(input.selection_color, Color::from_rgb8(0x28, 0x34, 0x57));
+    assert_eq!(
+        input.focused.border_color,
+        Color::from_rgb8(0xc0, 0xca, 0xf5)
+    );
+}
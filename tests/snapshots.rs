@@ -0,0 +1,78 @@
+//! Renders every widget state of the default styles on the built-in themes and of a dark and a
+//! light preset, and compares them against the golden images in `tests/snapshots`. Run with
+//! `UPDATE_SNAPSHOTS=1` to accept intended changes.
+
+use iced::Color;
+use iced_custom_theme_helpers::presets::Preset;
+use iced_custom_theme_helpers::snapshot::{self, Pixmap, Tolerance};
+use iced_custom_theme_helpers::style_set::StyleSet;
+use iced_custom_theme_helpers::styles::scrollable::CustomScrollableStyle;
+use iced_custom_theme_helpers::styles::text_input::CustomTextInputStyle;
+use std::path::Path;
+
+type Renderings = Vec<(&'static str, Vec<(&'static str, Pixmap)>)>;
+
+fn compare(slug: &str, renderings: Renderings) -> Vec<String> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let mut failures = Vec::new();
+    for (widget, states) in renderings {
+        for (state, pixmap) in states {
+            let golden = directory.join(format!("{slug}-{widget}-{state}.png"));
+            if let Err(error) = snapshot::compare(&pixmap, &golden, Tolerance::DEFAULT) {
+                failures.push(error.to_string());
+            }
+        }
+    }
+    failures
+}
+
+fn render(styles: &StyleSet, canvas: Color) -> Renderings {
+    vec![
+        ("button", snapshot::button(&styles.primary_button, canvas)),
+        (
+            "text_input",
+            snapshot::text_input(&styles.text_input, canvas),
+        ),
+        (
+            "scrollable",
+            snapshot::scrollable(&styles.scrollable, canvas),
+        ),
+    ]
+}
+
+#[test]
+fn default_styles_match_golden_images() {
+    let mut failures = Vec::new();
+
+    for (slug, theme) in [("light", iced::Theme::Light), ("dark", iced::Theme::Dark)] {
+        let canvas = theme.palette().background;
+        let button = StyleSet::from_theme(&theme).primary_button;
+        let renderings = vec![
+            ("button", snapshot::button(&button, canvas)),
+            (
+                "text_input",
+                snapshot::text_input(&CustomTextInputStyle::default(&theme), canvas),
+            ),
+            (
+                "scrollable",
+                snapshot::scrollable(&CustomScrollableStyle::default(&theme), canvas),
+            ),
+        ];
+        failures.extend(compare(&format!("default-{slug}"), renderings));
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn preset_states_match_golden_images() {
+    let mut failures = Vec::new();
+
+    for preset in [Preset::Nord, Preset::SolarizedLight] {
+        let slug = preset.name().to_lowercase().replace(' ', "-");
+        let renderings = render(&preset.styles(), preset.palette().background);
+        failures.extend(compare(&slug, renderings));
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}